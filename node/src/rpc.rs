@@ -22,7 +22,7 @@ use std::sync::Arc;
 
 use common_runtime::{
 	opaque::Block,
-	types::{AccountId, Balance, Nonce, TokenId},
+	types::{AccountId, Balance, BlockNumber, Nonce, TokenId},
 };
 
 use metamask_signature_rpc::MetamaskSignatureApiServer;
@@ -64,12 +64,24 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: xyk_rpc::XykRuntimeApi<Block, Balance, TokenId, AccountId>,
 	C::Api: proof_of_stake_rpc::ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>,
+	C::Api: fee_lock_rpc::FeeLockRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>,
+	C::Api: bootstrap_rpc::BootstrapRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>,
+	C::Api: multipurpose_liquidity_rpc::MultipurposeLiquidityRuntimeApi<
+		Block,
+		Balance,
+		TokenId,
+		AccountId,
+		BlockNumber,
+	>,
 	C::Api: metamask_signature_rpc::MetamaskSignatureRuntimeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: VerNonceApi<Block, AccountId>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use bootstrap_rpc::{Bootstrap, BootstrapApiServer};
+	use fee_lock_rpc::{FeeLock, FeeLockApiServer};
 	use metamask_signature_rpc::MetamaskSignature;
+	use multipurpose_liquidity_rpc::{MultipurposeLiquidity, MultipurposeLiquidityApiServer};
 	use pallet_transaction_payment_mangata_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use proof_of_stake_rpc::{ProofOfStake, ProofOfStakeApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
@@ -82,6 +94,9 @@ where
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(Xyk::new(client.clone()).into_rpc())?;
 	module.merge(ProofOfStake::new(client.clone()).into_rpc())?;
+	module.merge(FeeLock::new(client.clone()).into_rpc())?;
+	module.merge(Bootstrap::new(client.clone()).into_rpc())?;
+	module.merge(MultipurposeLiquidity::new(client.clone()).into_rpc())?;
 	module.merge(MetamaskSignature::new(client).into_rpc())?;
 
 	Ok(module)
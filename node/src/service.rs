@@ -108,7 +108,15 @@ pub trait RuntimeApiCollection:
 	+ ver_api::VerNonceApi<Block, AccountId>
 	+ xyk_rpc::XykRuntimeApi<Block, Balance, TokenId, AccountId>
 	+ proof_of_stake_rpc::ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>
-	+ metamask_signature_rpc::MetamaskSignatureRuntimeApi<Block>
+	+ fee_lock_rpc::FeeLockRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>
+	+ bootstrap_rpc::BootstrapRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>
+	+ multipurpose_liquidity_rpc::MultipurposeLiquidityRuntimeApi<
+		Block,
+		Balance,
+		TokenId,
+		AccountId,
+		BlockNumber,
+	> + metamask_signature_rpc::MetamaskSignatureRuntimeApi<Block>
 {
 }
 
@@ -127,7 +135,15 @@ impl<Api> RuntimeApiCollection for Api where
 		+ ver_api::VerNonceApi<Block, AccountId>
 		+ xyk_rpc::XykRuntimeApi<Block, Balance, TokenId, AccountId>
 		+ proof_of_stake_rpc::ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>
-		+ metamask_signature_rpc::MetamaskSignatureRuntimeApi<Block>
+		+ fee_lock_rpc::FeeLockRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>
+		+ bootstrap_rpc::BootstrapRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>
+		+ multipurpose_liquidity_rpc::MultipurposeLiquidityRuntimeApi<
+			Block,
+			Balance,
+			TokenId,
+			AccountId,
+			BlockNumber,
+		> + metamask_signature_rpc::MetamaskSignatureRuntimeApi<Block>
 {
 }
 
@@ -0,0 +1,197 @@
+// Copyright (C) 2021 Mangata team
+
+pub use bootstrap_runtime_api::BootstrapApi as BootstrapRuntimeApi;
+use bootstrap_runtime_api::{RpcArchivedBootstrap, RpcBootstrapAccountInfo, RpcBootstrapState};
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait BootstrapApi<BlockHash, Balance, TokenId, AccountId, BlockNumber> {
+	/// Returns state of given bootstrap, including its phases, valuations and implied price
+	///
+	/// * `bootstrap_id` - id of the bootstrap
+	/// * `at` - optional block hash
+	#[method(name = "bootstrap_get_bootstrap_state")]
+	fn get_bootstrap_state(
+		&self,
+		bootstrap_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcBootstrapState<NumberOrHex, TokenId, BlockNumber>>>;
+
+	/// Returns provisions of given account and liquidity tokens it can claim or has already
+	/// claimed
+	///
+	/// * `bootstrap_id` - id of the bootstrap
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "bootstrap_get_account_info")]
+	fn get_account_info(
+		&self,
+		bootstrap_id: u32,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcBootstrapAccountInfo<NumberOrHex>>;
+
+	/// Returns schedules of already finalized bootstraps
+	///
+	/// * `at` - optional block hash
+	#[method(name = "bootstrap_get_archived_bootstraps")]
+	fn get_archived_bootstraps(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcArchivedBootstrap<NumberOrHex, BlockNumber>>>;
+
+	/// Returns valuation (expressed in second token of bootstrapped pair) that account can
+	/// still provision into given bootstrap
+	///
+	/// * `bootstrap_id` - id of the bootstrap
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "bootstrap_get_remaining_provision_allowance")]
+	fn get_remaining_provision_allowance(
+		&self,
+		bootstrap_id: u32,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+}
+
+pub struct Bootstrap<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, P> Bootstrap<C, P> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn into_rpc_error(e: impl std::fmt::Debug) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		1,
+		"Unable to serve the request",
+		Some(format!("{:?}", e)),
+	)))
+}
+
+#[async_trait]
+impl<C, Block, Balance, TokenId, AccountId, BlockNumber>
+	BootstrapApiServer<<Block as BlockT>::Hash, Balance, TokenId, AccountId, BlockNumber>
+	for Bootstrap<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: BootstrapRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + Into<NumberOrHex>,
+	TokenId: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeDisplay + MaybeFromStr,
+	BlockNumber: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_bootstrap_state(
+		&self,
+		bootstrap_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcBootstrapState<NumberOrHex, TokenId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_bootstrap_state(at, bootstrap_id)
+			.map(|state| {
+				state.map(|state| RpcBootstrapState {
+					phase: state.phase,
+					first_token_id: state.first_token_id,
+					second_token_id: state.second_token_id,
+					whitelist_start: state.whitelist_start,
+					public_start: state.public_start,
+					finish: state.finish,
+					first_token_valuation: state.first_token_valuation.into(),
+					second_token_valuation: state.second_token_valuation.into(),
+					max_first_to_second_ratio: (
+						state.max_first_to_second_ratio.0.into(),
+						state.max_first_to_second_ratio.1.into(),
+					),
+					price: state.price.map(Into::into),
+					promote_bootstrap_pool: state.promote_bootstrap_pool,
+					soft_cap: state.soft_cap.map(|(first, second)| (first.into(), second.into())),
+					minted_liquidity: state
+						.minted_liquidity
+						.map(|(liq_token_id, issuance)| (liq_token_id, issuance.into())),
+				})
+			})
+			.map_err(into_rpc_error)
+	}
+
+	fn get_account_info(
+		&self,
+		bootstrap_id: u32,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcBootstrapAccountInfo<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_account_info(at, bootstrap_id, account)
+			.map(|info| RpcBootstrapAccountInfo {
+				first_token_provision: info.first_token_provision.into(),
+				second_token_provision: info.second_token_provision.into(),
+				first_token_vested_provision: info.first_token_vested_provision.into(),
+				second_token_vested_provision: info.second_token_vested_provision.into(),
+				claimable_liquidity: info.claimable_liquidity.into(),
+				claimable_vested_liquidity: info.claimable_vested_liquidity.into(),
+				claimed_liquidity: info.claimed_liquidity.into(),
+				remaining_provision_allowance: info.remaining_provision_allowance.map(Into::into),
+			})
+			.map_err(into_rpc_error)
+	}
+
+	fn get_archived_bootstraps(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcArchivedBootstrap<NumberOrHex, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_archived_bootstraps(at)
+			.map(|archived| {
+				archived
+					.into_iter()
+					.map(|bootstrap| RpcArchivedBootstrap {
+						whitelist_start: bootstrap.whitelist_start,
+						public_start: bootstrap.public_start,
+						finish: bootstrap.finish,
+						max_first_to_second_ratio: (
+							bootstrap.max_first_to_second_ratio.0.into(),
+							bootstrap.max_first_to_second_ratio.1.into(),
+						),
+					})
+					.collect()
+			})
+			.map_err(into_rpc_error)
+	}
+
+	fn get_remaining_provision_allowance(
+		&self,
+		bootstrap_id: u32,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_remaining_provision_allowance(at, bootstrap_id, account)
+			.map(|allowance| allowance.map(Into::into))
+			.map_err(into_rpc_error)
+	}
+}
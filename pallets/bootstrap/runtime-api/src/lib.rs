@@ -0,0 +1,105 @@
+// Copyright (C) 2021 Mangata team
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::vec::Vec;
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RpcBootstrapPhase {
+	BeforeStart,
+	Whitelist,
+	Public,
+	Finished,
+	Failed,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcBootstrapState<Balance, TokenId, BlockNumber> {
+	pub phase: RpcBootstrapPhase,
+	pub first_token_id: TokenId,
+	pub second_token_id: TokenId,
+	/// first block of whitelist phase
+	pub whitelist_start: BlockNumber,
+	/// first block of public phase, whitelist phase ends one block earlier
+	pub public_start: BlockNumber,
+	/// block at which bootstrap finishes, public phase ends one block earlier
+	pub finish: BlockNumber,
+	pub first_token_valuation: Balance,
+	pub second_token_valuation: Balance,
+	/// represented as (numerator, denominator)
+	pub max_first_to_second_ratio: (Balance, Balance),
+	/// price of first token expressed in second token, multiplied by 10^18; none if first token
+	/// has not been provisioned yet
+	pub price: Option<Balance>,
+	pub promote_bootstrap_pool: bool,
+	/// minimal (first, second) token valuations, none if not set
+	pub soft_cap: Option<(Balance, Balance)>,
+	/// liquidity token minted when bootstrap finished and its issuance
+	pub minted_liquidity: Option<(TokenId, Balance)>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcBootstrapAccountInfo<Balance> {
+	pub first_token_provision: Balance,
+	pub second_token_provision: Balance,
+	pub first_token_vested_provision: Balance,
+	pub second_token_vested_provision: Balance,
+	/// liquidity tokens that account can claim at the moment, excluding vested ones
+	pub claimable_liquidity: Balance,
+	/// vested liquidity tokens that account can claim at the moment
+	pub claimable_vested_liquidity: Balance,
+	/// liquidity tokens (both liquid and vested) already claimed by account
+	pub claimed_liquidity: Balance,
+	/// valuation that account can still provision, none if its provisions are not limited
+	pub remaining_provision_allowance: Option<Balance>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcArchivedBootstrap<Balance, BlockNumber> {
+	pub whitelist_start: BlockNumber,
+	pub public_start: BlockNumber,
+	pub finish: BlockNumber,
+	/// represented as (numerator, denominator)
+	pub max_first_to_second_ratio: (Balance, Balance),
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait BootstrapApi<Balance, TokenId, AccountId, BlockNumber> where
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+		TokenId: Codec + MaybeDisplay + MaybeFromStr,
+		AccountId: Codec + MaybeDisplay + MaybeFromStr,
+		BlockNumber: Codec + MaybeDisplay + MaybeFromStr,{
+
+		/// state of given bootstrap, none if it is not scheduled
+		fn get_bootstrap_state(
+			bootstrap_id: u32,
+		) -> Option<RpcBootstrapState<Balance, TokenId, BlockNumber>>;
+
+		/// provisions and liquidity tokens of account in given bootstrap
+		fn get_account_info(
+			bootstrap_id: u32,
+			account: AccountId,
+		) -> RpcBootstrapAccountInfo<Balance>;
+
+		/// schedules of already finalized bootstraps
+		fn get_archived_bootstraps() -> Vec<RpcArchivedBootstrap<Balance, BlockNumber>>;
+
+		/// valuation (expressed in second token of bootstrapped pair) that account can still
+		/// provision into given bootstrap, none if its provisions are not limited
+		fn get_remaining_provision_allowance(
+			bootstrap_id: u32,
+			account: AccountId,
+		) -> Option<Balance>;
+	}
+}
@@ -35,17 +35,50 @@ fn default_ratio<T: Config>() -> (BalanceOf<T>, BalanceOf<T>) {
 	(DEFAULT_RATIO.0.into(), DEFAULT_RATIO.1.into())
 }
 
+fn balance<T: Config>(amount: u128) -> BalanceOf<T> {
+	amount.try_into().ok().expect("should fit")
+}
+
+/// Configures provision limits and auction of the default bootstrap, so provisions take their most
+/// expensive path
+fn set_limits_and_auction<T: Config>(sale_amount: u128) {
+	let limits = ProvisionLimits {
+		per_account: Some(balance::<T>(MILION)),
+		whitelist_phase: Some(balance::<T>(MILION)),
+		public_phase: Some(balance::<T>(MILION)),
+	};
+	BootstrapPallet::<T>::set_provision_limits(
+		RawOrigin::Root.into(),
+		DEFAULT_BOOTSTRAP_ID,
+		Some(limits),
+	)
+	.unwrap();
+
+	let price = balance::<T>(PRICE_PRECISION * DEFAULT_RATIO.1 as u128 / DEFAULT_RATIO.0 as u128);
+	let params = AuctionParams {
+		sale_amount: balance::<T>(sale_amount),
+		start_price: price,
+		reserve_price: price,
+	};
+	BootstrapPallet::<T>::set_auction_params(
+		RawOrigin::Root.into(),
+		DEFAULT_BOOTSTRAP_ID,
+		Some(params),
+	)
+	.unwrap();
+}
+
 benchmarks! {
 
 	schedule_bootstrap {
 		frame_system::Pallet::<T>::set_block_number(1_u32.into());
-		assert!(crate::BootstrapSchedule::<T>::get().is_none());
+		assert!(crate::BootstrapSchedule::<T>::get(DEFAULT_BOOTSTRAP_ID).is_none());
 		let caller: T::AccountId = whitelisted_caller();
 		let first_token_id = <T as Config>::Currency::create(&caller, MILION.try_into().ok().expect("should fit")).expect("Token creation failed");
 		let second_token_id = <T as Config>::Currency::create(&caller, MILION.try_into().ok().expect("should fit")).expect("Token creation failed");
 	}: schedule_bootstrap(RawOrigin::Root, first_token_id, second_token_id, 123_456_789_u32.into(), Some(100_000_u32), 100_000_u32, Some(default_ratio::<T>()), true)
 	verify {
-		assert!(crate::BootstrapSchedule::<T>::get().is_some());
+		assert!(crate::BootstrapSchedule::<T>::get(DEFAULT_BOOTSTRAP_ID).is_some());
 	}
 
 	provision {
@@ -57,13 +90,39 @@ benchmarks! {
 		let mga_provision_amount = ksm_provision_amount * DEFAULT_RATIO.1 / DEFAULT_RATIO.0;
 
 		BootstrapPallet::<T>::schedule_bootstrap(RawOrigin::Root.into(), first_token_id, second_token_id, 10_u32.into(), Some(10_u32), 10_u32, Some(default_ratio::<T>()), false).unwrap();
+		set_limits_and_auction::<T>(ksm_provision_amount.into());
 		// jump to public phase
 		BootstrapPallet::<T>::on_initialize(20_u32.into());
 		BootstrapPallet::<T>::provision(RawOrigin::Signed(caller.clone().into()).into(), second_token_id, mga_provision_amount.into()).unwrap();
 
 	}: provision(RawOrigin::Signed(caller.clone().into()), first_token_id, ksm_provision_amount.into())
 	verify {
-		assert_eq!(BootstrapPallet::<T>::provisions(caller, first_token_id), ksm_provision_amount.into());
+		assert_eq!(BootstrapPallet::<T>::provisions((DEFAULT_BOOTSTRAP_ID, caller.clone(), first_token_id)), ksm_provision_amount.into());
+	}
+
+	provision_with_proof {
+		let p in 0..<<T as Config>::MaxWhitelistProofLength as Get<u32>>::get();
+		frame_system::Pallet::<T>::set_block_number(1_u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let first_token_id = <T as Config>::Currency::create(&caller, MILION.try_into().ok().expect("should fit")).expect("Token creation failed");
+		let second_token_id = <T as Config>::Currency::create(&caller, MILION.try_into().ok().expect("should fit")).expect("Token creation failed");
+		let ksm_provision_amount = 100_000_u32;
+		let mga_provision_amount = ksm_provision_amount * DEFAULT_RATIO.1 / DEFAULT_RATIO.0;
+
+		BootstrapPallet::<T>::schedule_bootstrap(RawOrigin::Root.into(), first_token_id, second_token_id, 10_u32.into(), Some(10_u32), 10_u32, Some(default_ratio::<T>()), false).unwrap();
+		set_limits_and_auction::<T>(ksm_provision_amount.into());
+		// jump to whitelist phase
+		BootstrapPallet::<T>::on_initialize(15_u32.into());
+		BootstrapPallet::<T>::provision(RawOrigin::Signed(caller.clone().into()).into(), second_token_id, mga_provision_amount.into()).unwrap();
+
+		let cap: Option<BalanceOf<T>> = Some(ksm_provision_amount.into());
+		let proof = (0..p).map(|i| H256::repeat_byte(i as u8)).collect::<Vec<_>>();
+		let root = BootstrapPallet::<T>::compute_whitelist_root(BootstrapPallet::<T>::whitelist_leaf(&caller, cap), &proof);
+		BootstrapPallet::<T>::set_whitelist_root(RawOrigin::Root.into(), DEFAULT_BOOTSTRAP_ID, Some(root)).unwrap();
+
+	}: provision_with_proof(RawOrigin::Signed(caller.clone().into()), DEFAULT_BOOTSTRAP_ID, first_token_id, ksm_provision_amount.into(), cap, proof)
+	verify {
+		assert_eq!(BootstrapPallet::<T>::provisions((DEFAULT_BOOTSTRAP_ID, caller.clone(), first_token_id)), ksm_provision_amount.into());
 	}
 
 	// provision_vested {
@@ -120,12 +179,12 @@ benchmarks! {
 		// BootstrapPallet::<T>::provision_vested(RawOrigin::Signed(caller.clone().into()).into(), first_token_id, ksm_vested_provision_amount).unwrap();
 		BootstrapPallet::<T>::on_initialize(30_u32.into());
 
-		assert_eq!(BootstrapPallet::<T>::phase(), BootstrapPhase::Finished);
-		assert_eq!(BootstrapPallet::<T>::claimed_rewards(caller.clone(), first_token_id), 0_u32.into());
-		assert_eq!(BootstrapPallet::<T>::claimed_rewards(caller.clone(), second_token_id), 0_u32.into());
-		assert_eq!(BootstrapPallet::<T>::valuations(), (total_mga_provision, total_ksm_provision));
-		assert_eq!(BootstrapPallet::<T>::provisions(caller.clone(), first_token_id), (ksm_provision_amount.into()));
-		assert_eq!(BootstrapPallet::<T>::provisions(caller.clone(), second_token_id), (mga_provision_amount.into()));
+		assert_eq!(BootstrapPallet::<T>::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Finished);
+		assert_eq!(BootstrapPallet::<T>::claimed_rewards((DEFAULT_BOOTSTRAP_ID, caller.clone(), first_token_id)), 0_u32.into());
+		assert_eq!(BootstrapPallet::<T>::claimed_rewards((DEFAULT_BOOTSTRAP_ID, caller.clone(), second_token_id)), 0_u32.into());
+		assert_eq!(BootstrapPallet::<T>::valuations(DEFAULT_BOOTSTRAP_ID), (total_mga_provision, total_ksm_provision));
+		assert_eq!(BootstrapPallet::<T>::provisions((DEFAULT_BOOTSTRAP_ID, caller.clone(), first_token_id)), (ksm_provision_amount.into()));
+		assert_eq!(BootstrapPallet::<T>::provisions((DEFAULT_BOOTSTRAP_ID, caller.clone(), second_token_id)), (mga_provision_amount.into()));
 		// assert_eq!(BootstrapPallet::<T>::vested_provisions(caller.clone(), first_token_id), (ksm_vested_provision_amount, 1, lock + 1));
 		// assert_eq!(BootstrapPallet::<T>::vested_provisions(caller.clone(), second_token_id), (mga_vested_provision_amount, 1, lock + 1));
 
@@ -133,14 +192,14 @@ benchmarks! {
 		T::RewardsApi::enable(liquidity_asset_id, 1_u8);
 	}: claim_and_activate_liquidity_tokens(RawOrigin::Signed(caller.clone().into()))
 	verify {
-		let (total_mga_provision, total_ksm_provision) = BootstrapPallet::<T>::valuations();
+		let (total_mga_provision, total_ksm_provision) = BootstrapPallet::<T>::valuations(DEFAULT_BOOTSTRAP_ID);
 		let ksm_non_vested_rewards = total_provision / 4_u32.into() * ksm_provision_amount.into() / total_ksm_provision;
 		let ksm_vested_rewards = total_provision / 4_u32.into() * ksm_vested_provision_amount.into() / total_ksm_provision;
 		let mga_non_vested_rewards = total_provision / 4_u32.into() * mga_provision_amount.into() / total_mga_provision;
 		let mga_vested_rewards = total_provision / 4_u32.into() * mga_vested_provision_amount.into() / total_mga_provision;
 
-		assert_eq!(BootstrapPallet::<T>::claimed_rewards(caller.clone(), first_token_id), ksm_vested_rewards + ksm_non_vested_rewards);
-		assert_eq!(BootstrapPallet::<T>::claimed_rewards(caller.clone(), second_token_id), mga_vested_rewards + mga_non_vested_rewards);
+		assert_eq!(BootstrapPallet::<T>::claimed_rewards((DEFAULT_BOOTSTRAP_ID, caller.clone(), first_token_id)), ksm_vested_rewards + ksm_non_vested_rewards);
+		assert_eq!(BootstrapPallet::<T>::claimed_rewards((DEFAULT_BOOTSTRAP_ID, caller.clone(), second_token_id)), mga_vested_rewards + mga_non_vested_rewards);
 	}
 
 	finalize {
@@ -171,12 +230,12 @@ benchmarks! {
 		BootstrapPallet::<T>::on_initialize(30_u32.into());
 
 		BootstrapPallet::<T>::claim_liquidity_tokens(RawOrigin::Signed(caller.clone().into()).into()).unwrap();
-		assert_eq!(BootstrapPallet::<T>::phase(), BootstrapPhase::Finished);
+		assert_eq!(BootstrapPallet::<T>::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Finished);
 
 		assert_ok!(BootstrapPallet::<T>::pre_finalize(RawOrigin::Signed(caller.clone().into()).into()));
 	}: finalize(RawOrigin::Signed(caller.clone().into()))
 	verify {
-		assert_eq!(BootstrapPallet::<T>::phase(), BootstrapPhase::BeforeStart);
+		assert_eq!(BootstrapPallet::<T>::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::BeforeStart);
 	}
 
 	impl_benchmark_test_suite!(BootstrapPallet, crate::mock::new_test_ext(), crate::mock::Test)
@@ -13,6 +13,9 @@
 //! ### Features
 //!
 //! * Bootstrap pallet is reusable** - after bootstrap between tokens `X` and `Y` is finished the following one can be scheduled (with different pair of tokens).
+//! * Multiple bootstraps can run concurrently. Every bootstrap is identified by [`BootstrapId`] and has
+//!   its own schedule, whitelist, provisions and claims. Extrinsics that do not take `bootstrap_id`
+//!   operate on [`DEFAULT_BOOTSTRAP_ID`].
 //! * After bootstrap is finished new liquidity token (`Z`) is created and [`pallet_xyk`] can be used to:
 //!        * exchange/trade `X` and `Y` tokens
 //!        * mint/burn `Z` tokens
@@ -27,6 +30,8 @@
 //!
 //! 1.1 [**optional**] depending on fact if [`BootstrapPhase::Whitelist`] is enabled entity
 //!   with sudo privileges can whitelist particular users using [`Pallet::whitelist_accounts`]
+//!   or, for large lists of participants, commit only a merkle root of them using
+//!   [`Pallet::set_whitelist_root`]
 //!
 //! 1.2 [**optional**] [`Pallet::update_promote_bootstrap_pool`] can be used to enable or disable
 //!   automatic pool promotion of liquidity pool.
@@ -36,7 +41,8 @@
 //! 2. When blockchain reaches block that is scheduled as start od the bootstrap participation is
 //!    automatically enabled:
 //!    * in [`BootstrapPhase::Whitelist`] phase only whitelisted accounts [`Pallet::whitelist_accounts`]
-//!    can participate
+//!    can participate, accounts included in [`WhitelistRoot`] participate using
+//!    [`Pallet::provision_with_proof`]
 //!    * in [`BootstrapPhase::Public`] phase everyone can participate
 //!
 //! 3. When blockchain reaches block:
@@ -50,12 +56,39 @@
 //! storage item. All the liquidity token minted as a result of pool creation are now stored in
 //! bootstrap pallet account.
 //!
+//! If bootstrap was scheduled with a soft cap (see [`SoftCap`]) and valuation of any of the tokens
+//! has not reached it, no pool is created and bootstrap moves to [`BootstrapPhase::Failed`]
+//! instead. The same happens when any of the tokens was not provisioned at all or the pool can
+//! not be created for any other reason. Participants can then get their provisions back using
+//! [`Pallet::claim_refund`] (vested provisions are locked again with their original schedule)
+//! and bootstrap can be finalized as described in 5th point.
+//!
+//! ## Auction mode
+//!
+//! Instead of discovering price from the ratio of provisions, bootstrap can sell fixed amount of
+//! `first_token_id` in a descending price (Dutch) auction configured with
+//! [`Pallet::set_auction_params`] (see [`AuctionParams`]). Seller provisions `sale_amount` of
+//! `first_token_id` (usually during [`BootstrapPhase::Whitelist`]) while bidders provision
+//! `second_token_id`. Price stays at `start_price` during [`BootstrapPhase::Whitelist`] and
+//! decreases linearly to `reserve_price` during [`BootstrapPhase::Public`]. Auction is sold out
+//! as soon as provisions of `second_token_id` are worth `sale_amount` at current price, the part
+//! of the bid that exceeds it is not collected and following bids are rejected. Pool is created
+//! at the clearing price from all the collected tokens and participants claim their liquidity
+//! tokens as in regular bootstrap. If auction is not sold out by the end of public phase, or
+//! seller has not provisioned whole `sale_amount`, bootstrap moves to
+//! [`BootstrapPhase::Failed`] and all provisions can be refunded.
+//!
 //! 4. Accounts that participated in bootstrap can claim their liquidity pool share. Share is
 //!    calculated proportionally based on provisioned amount. One can use one of below extrinsics to
 //!    claim rewards:
 //!    * [`Pallet::claim_liquidity_tokens`]
 //!    * [`Pallet::claim_and_activate_liquidity_tokens`]
 //!
+//!    If bootstrap was scheduled with [`LiquidityVestingPeriod`] claimed liquidity tokens are
+//!    locked and vested linearly, starting from the block at which bootstrap finished. Such tokens
+//!    can still be activated, as they are moved to multipurpose liquidity reserves (see
+//!    [`VestedLiquidityReserveApi`]) before activation.
+//!
 //! 5. When every participant of the bootstrap has claimed their liquidity tokens entity with sudo
 //!    rights can [`Pallet::finalize`] whole bootstrap event. If there are some accounts that still
 //!    hasnt claim their tokens [`Pallet::claim_liquidity_tokens_for_account`] can be used to do
@@ -70,7 +103,9 @@
 //! BeforeStart --> Public
 //! Whitelist --> Public
 //! Public --> Finished
+//! Public --> Failed
 //! Finished --> BeforeStart
+//! Failed --> BeforeStart
 //! @enduml
 //! ```
 //!
@@ -78,12 +113,16 @@
 //!
 //! ## Runtime Storage Entries
 //!
+//! All entries except [`ArchivedBootstrap`] are keyed by [`BootstrapId`].
+//!
 //! - [`Provisions`] - stores information about who provisioned what (non vested tokens)
 //!
 //! - [`VestedProvisions`] - stores information about who provisioned what (vested tokens)
 //!
 //! - [`WhitelistedAccount`] - list of accounts allowed to participate in [`BootstrapPhase::Whitelist`]
 //!
+//! - [`WhitelistRoot`] - merkle root of (account, cap) pairs allowed to participate in [`BootstrapPhase::Whitelist`]
+//!
 //! - [`Phase`] - current state of bootstrap
 //!
 //! - [`Valuations`] - sum of all provisions in active bootstrap
@@ -109,6 +148,22 @@
 //!
 //! - [`ActivePair`] - bootstraped pair of tokens
 //!
+//! - [`SoftCap`] - minimal valuations of both tokens required for pool to be created
+//!
+//! - [`LiquidityVestingPeriod`] - length of linear vesting of claimed liquidity tokens
+//!
+//! - [`ProvisionLimit`] - optional per account and per phase limits of provisions, see [`ProvisionLimits`]
+//!
+//! - [`AccountProvisionValuation`] - valuation of provisions made by particular accounts
+//!
+//! - [`PhaseProvisionValuation`] - valuation of provisions made during whitelist and public phases
+//!
+//! - [`ValuationSource`] - how provisions are valued, see [`PriceSource`]
+//!
+//! - [`BootstrapRewards`] - 3rd party rewards scheduled for the pool once bootstrap finishes
+//!
+//! - [`Auction`] - parameters of bootstraps running in auction mode
+//!
 //! ## Extrinsics
 //!
 //! * [`Pallet::schedule_bootstrap`]
@@ -121,6 +176,18 @@
 //! * [`Pallet::claim_and_activate_liquidity_tokens`]
 //! * [`Pallet::finalize`]
 //!
+//! Each of the above has a `*_by_id` counterpart (i.e. [`Pallet::provision_by_id`]) that takes
+//! `bootstrap_id` as its first argument.
+//!
+//! * [`Pallet::claim_refund`]
+//! * [`Pallet::claim_refund_for_account`]
+//! * [`Pallet::set_whitelist_root`]
+//! * [`Pallet::provision_with_proof`]
+//! * [`Pallet::set_provision_limits`]
+//! * [`Pallet::set_valuation_source`]
+//! * [`Pallet::fund_bootstrap_rewards`]
+//! * [`Pallet::set_auction_params`]
+//!
 //! for more details see [click](#how-to-bootstrap)
 //!
 //!
@@ -128,6 +195,7 @@ use frame_support::pallet_prelude::*;
 
 use codec::{Decode, Encode};
 use frame_support::{
+	storage::with_storage_layer,
 	traits::{
 		Contains, ExistenceRequirement, Get, MultiTokenCurrency, MultiTokenVestingLocks,
 		StorageVersion,
@@ -144,8 +212,8 @@ use mangata_types::multipurpose_liquidity::ActivateKind;
 use orml_tokens::{MultiTokenCurrencyExtended, MultiTokenReservableCurrency};
 use scale_info::TypeInfo;
 use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, per_things::Rounding};
-use sp_core::U256;
-use sp_io::KillStorageResult;
+use sp_core::{H256, U256};
+use sp_io::{hashing::blake2_256, KillStorageResult};
 use sp_runtime::traits::{
 	AccountIdConversion, Bounded, CheckedAdd, One, SaturatedConversion, Saturating, Zero,
 };
@@ -156,6 +224,8 @@ mod mock;
 
 mod benchmarking;
 
+pub mod migration;
+
 #[cfg(test)]
 mod tests;
 
@@ -185,11 +255,21 @@ type CurrencyIdOf<T> = <<T as Config>::Currency as MultiTokenCurrency<
 
 type BlockNrAsBalance<T> = BalanceOf<T>;
 
+/// Identifies one of the concurrently running bootstraps
+pub type BootstrapId = u32;
+
+/// Bootstrap used by extrinsics that do not take `bootstrap_id` explicitly, it also holds the
+/// state of the bootstrap that was active before bootstraps were keyed by id
+pub const DEFAULT_BOOTSTRAP_ID: BootstrapId = 0;
+
+/// Precision of the price returned by [`Pallet::implied_price`]
+pub const PRICE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
@@ -199,76 +279,11 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-			let phase = Phase::<T>::get(); // R:1
-			if phase == BootstrapPhase::Finished {
-				return T::DbWeight::get().reads(1)
-			}
-
-			if let Some((start, whitelist_length, public_length, _)) = BootstrapSchedule::<T>::get()
-			{
-				// R:1
-				// NOTE: arythmetics protected by invariant check in Bootstrap::start_ido
-				let whitelist_start = start;
-				let public_start = start + whitelist_length.into();
-				let finished = start + whitelist_length.into() + public_length.into();
-
-				if n >= finished {
-					Phase::<T>::put(BootstrapPhase::Finished); // 1 WRINTE
-					log!(info, "bootstrap event finished");
-					let (second_token_valuation, first_token_valuation) = Valuations::<T>::get();
-
-					// one updated takes R:2, W:2; and multiply for two assets
-					if !T::AssetRegistryApi::enable_pool_creation((
-						Self::first_token_id(),
-						Self::second_token_id(),
-					)) {
-						log!(error, "cannot modify asset registry!");
-					}
-					// XykFunctionsTrait R: 11 W:12
-					// PoolCreateApi::pool_create R:2  +
-					// ---------------------------------
-					// R: 13 W 12
-					if let Some((liq_asset_id, issuance)) = T::PoolCreateApi::pool_create(
-						Self::vault_address(),
-						Self::first_token_id(),
-						first_token_valuation,
-						Self::second_token_id(),
-						second_token_valuation,
-					) {
-						MintedLiquidity::<T>::put((liq_asset_id, issuance)); // W:1
-						if PromoteBootstrapPool::<T>::get() {
-							T::RewardsApi::enable(
-								liq_asset_id,
-								T::DefaultBootstrapPromotedPoolWeight::get(),
-							);
-						}
-					} else {
-						log!(error, "cannot create pool!");
-					}
-					// TODO: include cost of pool_create call
-					T::DbWeight::get().reads_writes(21, 18)
-				} else if n >= public_start {
-					if phase != BootstrapPhase::Public {
-						Phase::<T>::put(BootstrapPhase::Public);
-						log!(info, "starting public phase");
-						T::DbWeight::get().reads_writes(2, 1)
-					} else {
-						T::DbWeight::get().reads(2)
-					}
-				} else if n >= whitelist_start {
-					if phase != BootstrapPhase::Whitelist {
-						log!(info, "starting whitelist phase");
-						Phase::<T>::put(BootstrapPhase::Whitelist);
-						T::DbWeight::get().reads_writes(2, 1)
-					} else {
-						T::DbWeight::get().reads(2)
-					}
-				} else {
-					T::DbWeight::get().reads(2)
-				}
-			} else {
-				T::DbWeight::get().reads(2)
+			let mut weight = T::DbWeight::get().reads(1);
+			for (bootstrap_id, schedule) in BootstrapSchedule::<T>::iter() {
+				weight = weight.saturating_add(Self::update_phase(bootstrap_id, schedule, n));
 			}
+			weight
 		}
 	}
 
@@ -309,6 +324,15 @@ pub mod pallet {
 
 		type ClearStorageLimit: Get<u32>;
 
+		/// Maximal number of nodes of merkle proof accepted by [`Pallet::provision_with_proof`]
+		#[pallet::constant]
+		type MaxWhitelistProofLength: Get<u32>;
+
+		/// Maximal number of bootstraps that can be scheduled at the same time, bounds the work
+		/// done in `on_initialize`
+		#[pallet::constant]
+		type MaxConcurrentBootstraps: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 
 		type RewardsApi: ProofOfStakeRewardsApi<
@@ -318,90 +342,186 @@ pub mod pallet {
 		>;
 
 		type AssetRegistryApi: AssetRegistryApi<CurrencyIdOf<Self>>;
+
+		type VestedLiquidityReserveApi: VestedLiquidityReserveApi<
+			Self::AccountId,
+			BalanceOf<Self>,
+			CurrencyIdOf<Self>,
+		>;
+
+		/// Values provisions of bootstraps that use [`PriceSource::External`]
+		type ExternalPriceSource: ProvisionPriceSource<CurrencyIdOf<Self>, BalanceOf<Self>>;
+
+		type ThirdPartyRewardsApi: ThirdPartyRewardsApi<
+			Self::AccountId,
+			BalanceOf<Self>,
+			CurrencyIdOf<Self>,
+		>;
 	}
 
-	/// maps ([`frame_system::Config::AccountId`], [`CurrencyId`]) -> [`Balance`] - identifies how much tokens did account provisioned in active bootstrap
+	/// maps ([`BootstrapId`], [`frame_system::Config::AccountId`], [`CurrencyId`]) -> [`Balance`] - identifies how much tokens did account provisioned in given bootstrap
 	#[pallet::storage]
 	#[pallet::getter(fn provisions)]
-	pub type Provisions<T: Config> = StorageDoubleMap<
+	pub type Provisions<T: Config> = StorageNMap<
 		_,
-		Twox64Concat,
-		T::AccountId,
-		Twox64Concat,
-		CurrencyIdOf<T>,
+		(
+			NMapKey<Twox64Concat, BootstrapId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CurrencyIdOf<T>>,
+		),
 		BalanceOf<T>,
 		ValueQuery,
 	>;
 
-	/// maps ([`frame_system::Config::AccountId`], [`CurrencyId`]) -> [`Balance`] - identifies how much vested tokens did account provisioned in active bootstrap
+	/// maps ([`BootstrapId`], [`frame_system::Config::AccountId`], [`CurrencyId`]) -> [`Balance`] - identifies how much vested tokens did account provisioned in given bootstrap
 	#[pallet::storage]
 	#[pallet::getter(fn vested_provisions)]
-	pub type VestedProvisions<T: Config> = StorageDoubleMap<
+	pub type VestedProvisions<T: Config> = StorageNMap<
 		_,
-		Twox64Concat,
-		T::AccountId,
-		Twox64Concat,
-		CurrencyIdOf<T>,
+		(
+			NMapKey<Twox64Concat, BootstrapId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CurrencyIdOf<T>>,
+		),
 		(BalanceOf<T>, BlockNrAsBalance<T>, BlockNrAsBalance<T>),
 		ValueQuery,
 	>;
 
-	/// list ([`Vec<AccountId>`]) of whitelisted accounts allowed to participate in [`BootstrapPhase::Whitelist`] phase
+	/// list ([`Vec<AccountId>`]) of whitelisted accounts allowed to participate in [`BootstrapPhase::Whitelist`] phase of given bootstrap
 	#[pallet::storage]
 	#[pallet::getter(fn whitelisted_accounts)]
 	pub type WhitelistedAccount<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, (), ValueQuery>;
+		StorageDoubleMap<_, Twox64Concat, BootstrapId, Twox64Concat, T::AccountId, (), ValueQuery>;
+
+	/// Merkle root of (`account`, `cap`) pairs allowed to participate in [`BootstrapPhase::Whitelist`]
+	/// phase of given bootstrap using [`Pallet::provision_with_proof`]
+	#[pallet::storage]
+	#[pallet::getter(fn whitelist_root)]
+	pub type WhitelistRoot<T: Config> = StorageMap<_, Twox64Concat, BootstrapId, H256, OptionQuery>;
 
 	/// Current state of bootstrap as [`BootstrapPhase`]
 	#[pallet::storage]
 	#[pallet::getter(fn phase)]
-	pub type Phase<T: Config> = StorageValue<_, BootstrapPhase, ValueQuery>;
+	pub type Phase<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, BootstrapPhase, ValueQuery>;
 
-	/// Total sum of provisions of `first` and `second` token in active bootstrap
+	/// Total sum of provisions of `first` and `second` token in given bootstrap
 	#[pallet::storage]
 	#[pallet::getter(fn valuations)]
-	pub type Valuations<T: Config> = StorageValue<_, (BalanceOf<T>, BalanceOf<T>), ValueQuery>;
+	pub type Valuations<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, (BalanceOf<T>, BalanceOf<T>), ValueQuery>;
 
-	/// Active bootstrap parameters
+	/// Scheduled bootstraps parameters
 	#[pallet::storage]
 	#[pallet::getter(fn config)]
-	pub type BootstrapSchedule<T: Config> =
-		StorageValue<_, (BlockNumberFor<T>, u32, u32, (BalanceOf<T>, BalanceOf<T>)), OptionQuery>;
+	pub type BootstrapSchedule<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BootstrapId,
+		(BlockNumberFor<T>, u32, u32, (BalanceOf<T>, BalanceOf<T>)),
+		OptionQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn minted_liquidity)]
 	pub type MintedLiquidity<T: Config> =
-		StorageValue<_, (CurrencyIdOf<T>, BalanceOf<T>), ValueQuery>;
+		StorageMap<_, Twox64Concat, BootstrapId, (CurrencyIdOf<T>, BalanceOf<T>), ValueQuery>;
 
-	///  Maps ([`frame_system::Config::AccountId`], [`CurrencyId`] ) -> [`Balance`] - where [`CurrencyId`] is id of the token that user participated with. This storage item is used to identify how much liquidity tokens has been claim by the user. If user participated with 2 tokens there are two entries associated with given account (`Address`, `first_token_id`) and (`Address`, `second_token_id`)
+	///  Maps ([`BootstrapId`], [`frame_system::Config::AccountId`], [`CurrencyId`] ) -> [`Balance`] - where [`CurrencyId`] is id of the token that user participated with. This storage item is used to identify how much liquidity tokens has been claim by the user. If user participated with 2 tokens there are two entries associated with given account (`Address`, `first_token_id`) and (`Address`, `second_token_id`)
 	#[pallet::storage]
 	#[pallet::getter(fn claimed_rewards)]
-	pub type ClaimedRewards<T: Config> = StorageDoubleMap<
+	pub type ClaimedRewards<T: Config> = StorageNMap<
 		_,
-		Twox64Concat,
-		T::AccountId,
-		Twox64Concat,
-		CurrencyIdOf<T>,
+		(
+			NMapKey<Twox64Concat, BootstrapId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, CurrencyIdOf<T>>,
+		),
 		BalanceOf<T>,
 		ValueQuery,
 	>;
 
-	/// List of accounts that provisioned funds to bootstrap and has not claimed liquidity tokens yet
+	/// List of accounts that provisioned funds to given bootstrap and has not claimed liquidity tokens yet
 	#[pallet::storage]
 	#[pallet::getter(fn provision_accounts)]
 	pub type ProvisionAccounts<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+		StorageDoubleMap<_, Twox64Concat, BootstrapId, Twox64Concat, T::AccountId, (), OptionQuery>;
 
-	/// Currently bootstraped pair of tokens representaed as [ `first_token_id`, `second_token_id`]
+	/// Bootstraped pair of tokens representaed as [ `first_token_id`, `second_token_id`]
 	#[pallet::storage]
 	#[pallet::getter(fn pair)]
 	pub type ActivePair<T: Config> =
-		StorageValue<_, (CurrencyIdOf<T>, CurrencyIdOf<T>), OptionQuery>;
+		StorageMap<_, Twox64Concat, BootstrapId, (CurrencyIdOf<T>, CurrencyIdOf<T>), OptionQuery>;
 
 	/// Wheter to automatically promote the pool after [`BootstrapPhase::PublicPhase`] or not.
 	#[pallet::storage]
 	#[pallet::getter(fn get_promote_bootstrap_pool)]
-	pub type PromoteBootstrapPool<T: Config> = StorageValue<_, bool, ValueQuery>;
+	pub type PromoteBootstrapPool<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, bool, ValueQuery>;
+
+	/// Minimal valuations of (`first_token_id`, `second_token_id`) that have to be reached by the
+	/// end of [`BootstrapPhase::Public`], otherwise bootstrap moves to [`BootstrapPhase::Failed`]
+	#[pallet::storage]
+	#[pallet::getter(fn soft_cap)]
+	pub type SoftCap<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, (BalanceOf<T>, BalanceOf<T>), OptionQuery>;
+
+	/// Length (in blocks) of linear vesting of liquidity tokens claimed from given bootstrap,
+	/// vesting starts at the block at which bootstrap finished
+	#[pallet::storage]
+	#[pallet::getter(fn liquidity_vesting_period)]
+	pub type LiquidityVestingPeriod<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, u32, OptionQuery>;
+
+	/// Source of prices used to value provisions in given bootstrap
+	#[pallet::storage]
+	#[pallet::getter(fn valuation_source)]
+	pub type ValuationSource<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, PriceSource, ValueQuery>;
+
+	/// 3rd party rewards (sponsor, reward token, amount, number of sessions) funded for the pool
+	/// created by given bootstrap
+	#[pallet::storage]
+	#[pallet::getter(fn bootstrap_rewards)]
+	pub type BootstrapRewards<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BootstrapId,
+		(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>, u32),
+		OptionQuery,
+	>;
+
+	/// Parameters of the Dutch auction used by given bootstrap instead of ratio based price discovery
+	#[pallet::storage]
+	#[pallet::getter(fn auction_params)]
+	pub type Auction<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, AuctionParams<BalanceOf<T>>, OptionQuery>;
+
+	/// Optional limits of provisions in given bootstrap
+	#[pallet::storage]
+	#[pallet::getter(fn provision_limits)]
+	pub type ProvisionLimit<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, ProvisionLimits<BalanceOf<T>>, OptionQuery>;
+
+	/// Valuation (expressed in `second_token_id`) of all provisions made by account in given bootstrap
+	#[pallet::storage]
+	#[pallet::getter(fn account_provision_valuation)]
+	pub type AccountProvisionValuation<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BootstrapId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Valuation (expressed in `second_token_id`) of all provisions made during
+	/// ([`BootstrapPhase::Whitelist`], [`BootstrapPhase::Public`]) phases of given bootstrap
+	#[pallet::storage]
+	#[pallet::getter(fn phase_provision_valuation)]
+	pub type PhaseProvisionValuation<T: Config> =
+		StorageMap<_, Twox64Concat, BootstrapId, (BalanceOf<T>, BalanceOf<T>), ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn archived)]
@@ -456,24 +576,14 @@ pub mod pallet {
 		///  - `token_id` - id of the token to provision (should be one of the currently bootstraped pair([`ActivePair`]))
 		///  - `amount` - amount of the token to provision
 		#[pallet::call_index(0)]
-		#[pallet::weight(<<T as Config>::WeightInfo>::provision())]
+		#[pallet::weight(<<T as Config>::WeightInfo>::provision().saturating_add(T::ExternalPriceSource::valuate_weight()))]
 		#[transactional]
 		pub fn provision(
 			origin: OriginFor<T>,
 			token_id: CurrencyIdOf<T>,
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-
-			ensure!(
-				!T::MaintenanceStatusProvider::is_maintenance(),
-				Error::<T>::ProvisioningBlockedByMaintenanceMode
-			);
-
-			Self::do_provision(&sender, token_id, amount)?;
-			ProvisionAccounts::<T>::insert(&sender, ());
-			Self::deposit_event(Event::Provisioned(token_id, amount));
-			Ok(())
+			Self::provision_by_id(origin, DEFAULT_BOOTSTRAP_ID, token_id, amount)
 		}
 
 		/// Allows for whitelisting accounts, so they can participate in during whitelist phase. The list of
@@ -485,12 +595,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			accounts: Vec<T::AccountId>,
 		) -> DispatchResult {
-			ensure_root(origin)?;
-			for account in accounts {
-				WhitelistedAccount::<T>::insert(&account, ());
-			}
-			Self::deposit_event(Event::AccountsWhitelisted);
-			Ok(())
+			Self::whitelist_accounts_by_id(origin, DEFAULT_BOOTSTRAP_ID, accounts)
 		}
 
 		/// Used for starting/scheduling new bootstrap
@@ -559,17 +664,189 @@ pub mod pallet {
 			public_phase_length: u32,
 			max_first_to_second_ratio: Option<(BalanceOf<T>, BalanceOf<T>)>,
 			promote_bootstrap_pool: bool,
+		) -> DispatchResult {
+			Self::schedule_bootstrap_by_id(
+				origin,
+				DEFAULT_BOOTSTRAP_ID,
+				first_token_id,
+				second_token_id,
+				ido_start,
+				whitelist_phase_length,
+				public_phase_length,
+				max_first_to_second_ratio,
+				promote_bootstrap_pool,
+				None,
+				None,
+			)
+		}
+
+		/// Used to cancel active bootstrap. Can only be called before bootstrap is actually started
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 4).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn cancel_bootstrap(origin: OriginFor<T>) -> DispatchResult {
+			Self::cancel_bootstrap_by_id(origin, DEFAULT_BOOTSTRAP_ID)
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		// can be used to enable or disable automatic pool promotion of liquidity pool. Updates [`PromoteBootstrapPool`]
+		pub fn update_promote_bootstrap_pool(
+			origin: OriginFor<T>,
+			promote_bootstrap_pool: bool,
+		) -> DispatchResult {
+			Self::update_promote_bootstrap_pool_by_id(
+				origin,
+				DEFAULT_BOOTSTRAP_ID,
+				promote_bootstrap_pool,
+			)
+		}
+
+		/// When bootstrap is in [`BootstrapPhase::Finished`] state user can claim his part of liquidity tokens.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
+		#[transactional]
+		pub fn claim_liquidity_tokens(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_claim_liquidity_tokens(&sender, DEFAULT_BOOTSTRAP_ID, false)
+		}
+
+		/// When bootstrap is in [`BootstrapPhase::Finished`] state user can claim his part of liquidity tokens comparing to `claim_liquidity_tokens` when calling `claim_and_activate_liquidity_tokens` tokens will be automatically activated.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
+		#[transactional]
+		pub fn claim_and_activate_liquidity_tokens(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_claim_liquidity_tokens(&sender, DEFAULT_BOOTSTRAP_ID, true)
+		}
+
+		/// Used to reset Bootstrap state of large storages and prepare it for running another bootstrap.
+		/// It should be called multiple times until it produces [`Event::BootstrapReadyToBeFinalized`] event.
+		///
+		/// **!!! Cleaning up storage is complex operation and pruning all storage items related to particular
+		/// bootstrap might not fit in a single block. As a result tx can be rejected !!!**
+		#[pallet::call_index(7)]
+		#[pallet::weight(Weight::from_parts(40_000_000, 0)
+							.saturating_add(T::DbWeight::get().reads_writes(6, 0)
+								.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(Into::<u64>::into(T::ClearStorageLimit::get())))))]
+		#[transactional]
+		pub fn pre_finalize(origin: OriginFor<T>) -> DispatchResult {
+			Self::pre_finalize_by_id(origin, DEFAULT_BOOTSTRAP_ID)
+		}
+
+		/// Used to complete resetting Bootstrap state and prepare it for running another bootstrap.
+		/// It should be called after pre_finalize has produced the [`Event::BootstrapReadyToBeFinalized`] event.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::finalize())]
+		#[transactional]
+		pub fn finalize(origin: OriginFor<T>) -> DispatchResult {
+			Self::finalize_by_id(origin, DEFAULT_BOOTSTRAP_ID)
+		}
+
+		/// Allows claiming rewards for some account that haven't done that yet. The only difference between
+		/// calling [`Pallet::claim_liquidity_tokens_for_account`] by some other account and calling [`Pallet::claim_liquidity_tokens`] directly by that account is account that will be charged for transaction fee.
+		/// # Args:
+		/// - `other` - account in behalf of which liquidity tokens should be claimed
+		#[pallet::call_index(9)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
+		#[transactional]
+		pub fn claim_liquidity_tokens_for_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			activate_rewards: bool,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_claim_liquidity_tokens(&account, DEFAULT_BOOTSTRAP_ID, activate_rewards)
+		}
+
+		/// Same as [`Pallet::provision`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(10)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::provision().saturating_add(T::ExternalPriceSource::valuate_weight()))]
+		#[transactional]
+		pub fn provision_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			token_id: CurrencyIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				!T::MaintenanceStatusProvider::is_maintenance(),
+				Error::<T>::ProvisioningBlockedByMaintenanceMode
+			);
+
+			let is_whitelisted = Self::is_whitelisted(bootstrap_id, &sender);
+			let provisioned =
+				Self::do_provision(&sender, bootstrap_id, token_id, amount, is_whitelisted)?;
+			ProvisionAccounts::<T>::insert(bootstrap_id, &sender, ());
+			Self::deposit_event(Event::Provisioned(bootstrap_id, token_id, provisioned));
+			Ok(())
+		}
+
+		/// Same as [`Pallet::whitelist_accounts`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::DbWeight::get().writes(1) * (accounts.len() as u64))]
+		#[transactional]
+		pub fn whitelist_accounts_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			for account in accounts {
+				WhitelistedAccount::<T>::insert(bootstrap_id, &account, ());
+			}
+			Self::deposit_event(Event::AccountsWhitelisted(bootstrap_id));
+			Ok(())
+		}
+
+		/// Same as [`Pallet::schedule_bootstrap`] but for bootstrap identified by `bootstrap_id`.
+		/// The same pair of tokens cannot be bootstrapped by two bootstraps at the same time.
+		///
+		/// - `soft_cap` - optional minimal valuations represented as (`first_token_id` valuation,
+		///   `second_token_id` valuation). If any of them is not reached when public phase ends
+		///   bootstrap moves to [`BootstrapPhase::Failed`] and participants can claim refunds
+		///   using [`Pallet::claim_refund`]
+		/// - `liquidity_vesting_period` - optional length (in blocks) of linear vesting of all
+		///   liquidity tokens claimed from the bootstrap, it starts when bootstrap finishes
+		#[pallet::call_index(12)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::schedule_bootstrap())]
+		#[transactional]
+		pub fn schedule_bootstrap_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			first_token_id: CurrencyIdOf<T>,
+			second_token_id: CurrencyIdOf<T>,
+			ido_start: BlockNumberFor<T>,
+			whitelist_phase_length: Option<u32>,
+			public_phase_length: u32,
+			max_first_to_second_ratio: Option<(BalanceOf<T>, BalanceOf<T>)>,
+			promote_bootstrap_pool: bool,
+			soft_cap: Option<(BalanceOf<T>, BalanceOf<T>)>,
+			liquidity_vesting_period: Option<u32>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			ensure!(Phase::<T>::get() == BootstrapPhase::BeforeStart, Error::<T>::AlreadyStarted);
+			ensure!(
+				Phase::<T>::get(bootstrap_id) == BootstrapPhase::BeforeStart,
+				Error::<T>::AlreadyStarted
+			);
 
-			if let Some((scheduled_ido_start, _, _, _)) = BootstrapSchedule::<T>::get() {
+			if let Some((scheduled_ido_start, _, _, _)) = BootstrapSchedule::<T>::get(bootstrap_id)
+			{
 				let now = <frame_system::Pallet<T>>::block_number();
 				ensure!(
 					now.saturating_add(T::BootstrapUpdateBuffer::get()) < scheduled_ido_start,
 					Error::<T>::TooLateToUpdateBootstrap
 				);
+			} else {
+				ensure!(
+					(BootstrapSchedule::<T>::iter_keys().count() as u32) <
+						T::MaxConcurrentBootstraps::get(),
+					Error::<T>::TooManyBootstraps
+				);
 			}
 
 			ensure!(first_token_id != second_token_id, Error::<T>::SameToken);
@@ -592,6 +869,8 @@ pub mod pallet {
 
 			ensure!(public_phase_length > 0, Error::<T>::PhaseLengthCannotBeZero);
 
+			ensure!(liquidity_vesting_period != Some(0), Error::<T>::VestingPeriodCannotBeZero);
+
 			ensure!(
 				ido_start
 					.checked_add(&whitelist_phase_length.into())
@@ -611,53 +890,76 @@ pub mod pallet {
 				Error::<T>::PoolAlreadyExists
 			);
 
-			ActivePair::<T>::put((first_token_id, second_token_id));
-			BootstrapSchedule::<T>::put((
-				ido_start,
-				whitelist_phase_length,
-				public_phase_length,
-				max_first_to_second_ratio,
-			));
+			ensure!(
+				!ActivePair::<T>::iter().any(|(id, (first, second))| {
+					id != bootstrap_id &&
+						((first, second) == (first_token_id, second_token_id) ||
+							(second, first) == (first_token_id, second_token_id))
+				}),
+				Error::<T>::PairAlreadyBootstrapped
+			);
+
+			ActivePair::<T>::insert(bootstrap_id, (first_token_id, second_token_id));
+			BootstrapSchedule::<T>::insert(
+				bootstrap_id,
+				(ido_start, whitelist_phase_length, public_phase_length, max_first_to_second_ratio),
+			);
 
-			PromoteBootstrapPool::<T>::put(promote_bootstrap_pool);
+			PromoteBootstrapPool::<T>::insert(bootstrap_id, promote_bootstrap_pool);
+			SoftCap::<T>::set(bootstrap_id, soft_cap);
+			LiquidityVestingPeriod::<T>::set(bootstrap_id, liquidity_vesting_period);
 
 			Ok(())
 		}
 
-		/// Used to cancel active bootstrap. Can only be called before bootstrap is actually started
-		#[pallet::call_index(3)]
+		/// Same as [`Pallet::cancel_bootstrap`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(13)]
 		#[pallet::weight(T::DbWeight::get().reads_writes(3, 4).saturating_add(Weight::from_parts(1_000_000, 0)))]
 		#[transactional]
-		pub fn cancel_bootstrap(origin: OriginFor<T>) -> DispatchResult {
+		pub fn cancel_bootstrap_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+		) -> DispatchResult {
 			ensure_root(origin)?;
 
 			// BootstrapSchedule should exist but not after BootstrapUpdateBuffer blocks before start
 
 			let now = <frame_system::Pallet<T>>::block_number();
-			let (ido_start, _, _, _) =
-				BootstrapSchedule::<T>::get().ok_or(Error::<T>::BootstrapNotSchduled)?;
-			ensure!(Phase::<T>::get() == BootstrapPhase::BeforeStart, Error::<T>::AlreadyStarted);
+			let (ido_start, _, _, _) = BootstrapSchedule::<T>::get(bootstrap_id)
+				.ok_or(Error::<T>::BootstrapNotSchduled)?;
+			ensure!(
+				Phase::<T>::get(bootstrap_id) == BootstrapPhase::BeforeStart,
+				Error::<T>::AlreadyStarted
+			);
 
 			ensure!(
 				now.saturating_add(T::BootstrapUpdateBuffer::get()) < ido_start,
 				Error::<T>::TooLateToUpdateBootstrap
 			);
 
-			ActivePair::<T>::kill();
-			BootstrapSchedule::<T>::kill();
-			PromoteBootstrapPool::<T>::kill();
+			ActivePair::<T>::remove(bootstrap_id);
+			BootstrapSchedule::<T>::remove(bootstrap_id);
+			PromoteBootstrapPool::<T>::remove(bootstrap_id);
+			SoftCap::<T>::remove(bootstrap_id);
+			LiquidityVestingPeriod::<T>::remove(bootstrap_id);
+			WhitelistRoot::<T>::remove(bootstrap_id);
+			ProvisionLimit::<T>::remove(bootstrap_id);
+			ValuationSource::<T>::remove(bootstrap_id);
+			Auction::<T>::remove(bootstrap_id);
+			Self::refund_bootstrap_rewards(bootstrap_id)?;
 			// Unnecessary
-			Phase::<T>::put(BootstrapPhase::BeforeStart);
+			Phase::<T>::remove(bootstrap_id);
 
 			Ok(())
 		}
 
-		#[pallet::call_index(4)]
+		/// Same as [`Pallet::update_promote_bootstrap_pool`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(14)]
 		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
 		#[transactional]
-		// can be used to enable or disable automatic pool promotion of liquidity pool. Updates [`PromoteBootstrapPool`]
-		pub fn update_promote_bootstrap_pool(
+		pub fn update_promote_bootstrap_pool_by_id(
 			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
 			promote_bootstrap_pool: bool,
 		) -> DispatchResult {
 			ensure_root(origin)?;
@@ -665,167 +967,464 @@ pub mod pallet {
 			// BootstrapSchedule should exist but not finalized
 			// we allow this to go thru if the BootstrapSchedule exists and the phase is before finalized
 
-			ensure!(BootstrapSchedule::<T>::get().is_some(), Error::<T>::BootstrapNotSchduled);
-			ensure!(Phase::<T>::get() != BootstrapPhase::Finished, Error::<T>::BootstrapFinished);
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			let phase = Phase::<T>::get(bootstrap_id);
+			ensure!(
+				phase != BootstrapPhase::Finished && phase != BootstrapPhase::Failed,
+				Error::<T>::BootstrapFinished
+			);
 
-			PromoteBootstrapPool::<T>::put(promote_bootstrap_pool);
+			PromoteBootstrapPool::<T>::insert(bootstrap_id, promote_bootstrap_pool);
 
 			Ok(())
 		}
 
-		/// When bootstrap is in [`BootstrapPhase::Finished`] state user can claim his part of liquidity tokens.
-		#[pallet::call_index(5)]
+		/// Same as [`Pallet::claim_liquidity_tokens`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(15)]
 		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
 		#[transactional]
-		pub fn claim_liquidity_tokens(origin: OriginFor<T>) -> DispatchResult {
+		pub fn claim_liquidity_tokens_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::do_claim_liquidity_tokens(&sender, false)
+			Self::do_claim_liquidity_tokens(&sender, bootstrap_id, false)
 		}
 
-		/// When bootstrap is in [`BootstrapPhase::Finished`] state user can claim his part of liquidity tokens comparing to `claim_liquidity_tokens` when calling `claim_and_activate_liquidity_tokens` tokens will be automatically activated.
-		#[pallet::call_index(6)]
+		/// Same as [`Pallet::claim_and_activate_liquidity_tokens`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(16)]
 		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
 		#[transactional]
-		pub fn claim_and_activate_liquidity_tokens(origin: OriginFor<T>) -> DispatchResult {
+		pub fn claim_and_activate_liquidity_tokens_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::do_claim_liquidity_tokens(&sender, true)
+			Self::do_claim_liquidity_tokens(&sender, bootstrap_id, true)
 		}
 
-		/// Used to reset Bootstrap state of large storages and prepare it for running another bootstrap.
-		/// It should be called multiple times until it produces [`Event::BootstrapReadyToBeFinalized`] event.
-		///
-		/// **!!! Cleaning up storage is complex operation and pruning all storage items related to particular
-		/// bootstrap might not fit in a single block. As a result tx can be rejected !!!**
-		#[pallet::call_index(7)]
+		/// Same as [`Pallet::pre_finalize`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(17)]
 		#[pallet::weight(Weight::from_parts(40_000_000, 0)
 							.saturating_add(T::DbWeight::get().reads_writes(6, 0)
 								.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(Into::<u64>::into(T::ClearStorageLimit::get())))))]
 		#[transactional]
-		pub fn pre_finalize(origin: OriginFor<T>) -> DispatchResult {
+		pub fn pre_finalize_by_id(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
-			ensure!(Self::phase() == BootstrapPhase::Finished, Error::<T>::NotFinishedYet);
+			ensure!(Self::is_concluded(bootstrap_id), Error::<T>::NotFinishedYet);
 
 			ensure!(
-				ProvisionAccounts::<T>::iter_keys().next().is_none(),
+				ProvisionAccounts::<T>::iter_key_prefix(bootstrap_id).next().is_none(),
 				Error::<T>::BootstrapNotReadyToBeFinished
 			);
 
 			let mut limit = T::ClearStorageLimit::get();
 
-			match VestedProvisions::<T>::clear(limit, None).into() {
+			match VestedProvisions::<T>::clear_prefix((bootstrap_id,), limit, None).into() {
+				KillStorageResult::AllRemoved(num_iter) => limit = limit.saturating_sub(num_iter),
+				KillStorageResult::SomeRemaining(_) => {
+					Self::deposit_event(Event::BootstrapParitallyPreFinalized(bootstrap_id));
+					return Ok(())
+				},
+			}
+
+			match WhitelistedAccount::<T>::clear_prefix(bootstrap_id, limit, None).into() {
 				KillStorageResult::AllRemoved(num_iter) => limit = limit.saturating_sub(num_iter),
 				KillStorageResult::SomeRemaining(_) => {
-					Self::deposit_event(Event::BootstrapParitallyPreFinalized);
+					Self::deposit_event(Event::BootstrapParitallyPreFinalized(bootstrap_id));
 					return Ok(())
 				},
 			}
 
-			match WhitelistedAccount::<T>::clear(limit, None).into() {
+			match ClaimedRewards::<T>::clear_prefix((bootstrap_id,), limit, None).into() {
 				KillStorageResult::AllRemoved(num_iter) => limit = limit.saturating_sub(num_iter),
 				KillStorageResult::SomeRemaining(_) => {
-					Self::deposit_event(Event::BootstrapParitallyPreFinalized);
+					Self::deposit_event(Event::BootstrapParitallyPreFinalized(bootstrap_id));
 					return Ok(())
 				},
 			}
 
-			match ClaimedRewards::<T>::clear(limit, None).into() {
+			match Provisions::<T>::clear_prefix((bootstrap_id,), limit, None).into() {
 				KillStorageResult::AllRemoved(num_iter) => limit = limit.saturating_sub(num_iter),
 				KillStorageResult::SomeRemaining(_) => {
-					Self::deposit_event(Event::BootstrapParitallyPreFinalized);
+					Self::deposit_event(Event::BootstrapParitallyPreFinalized(bootstrap_id));
 					return Ok(())
 				},
 			}
 
-			match Provisions::<T>::clear(limit, None).into() {
+			match AccountProvisionValuation::<T>::clear_prefix(bootstrap_id, limit, None).into() {
 				KillStorageResult::AllRemoved(num_iter) => limit = limit.saturating_sub(num_iter),
 				KillStorageResult::SomeRemaining(_) => {
-					Self::deposit_event(Event::BootstrapParitallyPreFinalized);
+					Self::deposit_event(Event::BootstrapParitallyPreFinalized(bootstrap_id));
 					return Ok(())
 				},
 			}
 
-			Self::deposit_event(Event::BootstrapReadyToBeFinalized);
+			Self::deposit_event(Event::BootstrapReadyToBeFinalized(bootstrap_id));
 
 			Ok(())
 		}
 
-		/// Used to complete resetting Bootstrap state and prepare it for running another bootstrap.
-		/// It should be called after pre_finalize has produced the [`Event::BootstrapReadyToBeFinalized`] event.
-		#[pallet::call_index(8)]
+		/// Same as [`Pallet::finalize`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(18)]
 		#[pallet::weight(<<T as Config>::WeightInfo>::finalize())]
 		#[transactional]
-		pub fn finalize(origin: OriginFor<T>) -> DispatchResult {
+		pub fn finalize_by_id(origin: OriginFor<T>, bootstrap_id: BootstrapId) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
-			ensure!(Self::phase() == BootstrapPhase::Finished, Error::<T>::NotFinishedYet);
+			ensure!(Self::is_concluded(bootstrap_id), Error::<T>::NotFinishedYet);
 
 			ensure!(
-				ProvisionAccounts::<T>::iter_keys().next().is_none(),
+				ProvisionAccounts::<T>::iter_key_prefix(bootstrap_id).next().is_none(),
 				Error::<T>::BootstrapNotReadyToBeFinished
 			);
 
 			ensure!(
-				VestedProvisions::<T>::iter_keys().next().is_none(),
+				VestedProvisions::<T>::iter_key_prefix((bootstrap_id,)).next().is_none(),
 				Error::<T>::BootstrapMustBePreFinalized
 			);
 
 			ensure!(
-				WhitelistedAccount::<T>::iter_keys().next().is_none(),
+				WhitelistedAccount::<T>::iter_key_prefix(bootstrap_id).next().is_none(),
 				Error::<T>::BootstrapMustBePreFinalized
 			);
 
 			ensure!(
-				ClaimedRewards::<T>::iter_keys().next().is_none(),
+				ClaimedRewards::<T>::iter_key_prefix((bootstrap_id,)).next().is_none(),
 				Error::<T>::BootstrapMustBePreFinalized
 			);
 
 			ensure!(
-				Provisions::<T>::iter_keys().next().is_none(),
+				Provisions::<T>::iter_key_prefix((bootstrap_id,)).next().is_none(),
 				Error::<T>::BootstrapMustBePreFinalized
 			);
 
-			Phase::<T>::put(BootstrapPhase::BeforeStart);
-			let (liq_token_id, _) = MintedLiquidity::<T>::take();
-			let balance = T::Currency::free_balance(liq_token_id.into(), &Self::vault_address());
-			if balance > 0_u32.into() {
-				T::Currency::transfer(
-					liq_token_id.into(),
-					&Self::vault_address(),
-					&T::TreasuryPalletId::get().into_account_truncating(),
-					balance,
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
-			Valuations::<T>::kill();
-			ActivePair::<T>::kill();
-			PromoteBootstrapPool::<T>::kill();
+			ensure!(
+				AccountProvisionValuation::<T>::iter_key_prefix(bootstrap_id).next().is_none(),
+				Error::<T>::BootstrapMustBePreFinalized
+			);
 
-			if let Some(bootstrap) = BootstrapSchedule::<T>::take() {
+			Phase::<T>::remove(bootstrap_id);
+			// vault is shared by all bootstraps, so only tokens of the pool created by this
+			// bootstrap can be moved to the treasury
+			if let Ok((liq_token_id, _)) = MintedLiquidity::<T>::try_get(bootstrap_id) {
+				MintedLiquidity::<T>::remove(bootstrap_id);
+				let balance =
+					T::Currency::free_balance(liq_token_id.into(), &Self::vault_address());
+				if balance > 0_u32.into() {
+					T::Currency::transfer(
+						liq_token_id.into(),
+						&Self::vault_address(),
+						&T::TreasuryPalletId::get().into_account_truncating(),
+						balance,
+						ExistenceRequirement::AllowDeath,
+					)?;
+				}
+			}
+			Valuations::<T>::remove(bootstrap_id);
+			ActivePair::<T>::remove(bootstrap_id);
+			PromoteBootstrapPool::<T>::remove(bootstrap_id);
+			SoftCap::<T>::remove(bootstrap_id);
+			LiquidityVestingPeriod::<T>::remove(bootstrap_id);
+			WhitelistRoot::<T>::remove(bootstrap_id);
+			ProvisionLimit::<T>::remove(bootstrap_id);
+			PhaseProvisionValuation::<T>::remove(bootstrap_id);
+			ValuationSource::<T>::remove(bootstrap_id);
+			Auction::<T>::remove(bootstrap_id);
+			Self::refund_bootstrap_rewards(bootstrap_id)?;
+
+			if let Some(bootstrap) = BootstrapSchedule::<T>::take(bootstrap_id) {
 				ArchivedBootstrap::<T>::mutate(|v| {
 					v.push(bootstrap);
 				});
 			}
 
-			Self::deposit_event(Event::BootstrapFinalized);
+			Self::deposit_event(Event::BootstrapFinalized(bootstrap_id));
 
 			Ok(())
 		}
 
-		/// Allows claiming rewards for some account that haven't done that yet. The only difference between
-		/// calling [`Pallet::claim_liquidity_tokens_for_account`] by some other account and calling [`Pallet::claim_liquidity_tokens`] directly by that account is account that will be charged for transaction fee.
-		/// # Args:
-		/// - `other` - account in behalf of which liquidity tokens should be claimed
-		#[pallet::call_index(9)]
+		/// Same as [`Pallet::claim_liquidity_tokens_for_account`] but for bootstrap identified by `bootstrap_id`
+		#[pallet::call_index(19)]
 		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
 		#[transactional]
-		pub fn claim_liquidity_tokens_for_account(
+		pub fn claim_liquidity_tokens_for_account_by_id(
 			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
 			account: T::AccountId,
 			activate_rewards: bool,
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
-			Self::do_claim_liquidity_tokens(&account, activate_rewards)
+			Self::do_claim_liquidity_tokens(&account, bootstrap_id, activate_rewards)
+		}
+
+		/// When bootstrap is in [`BootstrapPhase::Failed`] state user can claim back tokens that he
+		/// provisioned. Vested provisions are locked again with their original schedule.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
+		#[transactional]
+		pub fn claim_refund(origin: OriginFor<T>, bootstrap_id: BootstrapId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_claim_refund(&sender, bootstrap_id)
+		}
+
+		/// Allows claiming refund for some account that haven't done that yet, so failed bootstrap
+		/// can be finalized.
+		/// # Args:
+		/// - `account` - account in behalf of which refund should be claimed
+		#[pallet::call_index(21)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_and_activate_liquidity_tokens())]
+		#[transactional]
+		pub fn claim_refund_for_account(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_claim_refund(&account, bootstrap_id)
+		}
+
+		/// Sets merkle root of (`account`, `cap`) pairs that are allowed to participate in
+		/// [`BootstrapPhase::Whitelist`] phase using [`Pallet::provision_with_proof`]. Passing
+		/// `None` removes the root. Accounts whitelisted with [`Pallet::whitelist_accounts_by_id`]
+		/// are not affected.
+		///
+		/// Leaves are `blake2_256(SCALE(account, cap))` where `cap` is `Option<Balance>`, and inner
+		/// nodes are `blake2_256` of concatenated children sorted in ascending order.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn set_whitelist_root(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			root: Option<H256>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			ensure!(!Self::is_concluded(bootstrap_id), Error::<T>::BootstrapFinished);
+
+			WhitelistRoot::<T>::set(bootstrap_id, root);
+			Self::deposit_event(Event::WhitelistRootUpdated(bootstrap_id, root));
+			Ok(())
+		}
+
+		/// Same as [`Pallet::provision_by_id`] but during [`BootstrapPhase::Whitelist`] phase the
+		/// account proves that it is whitelisted with merkle proof against [`WhitelistRoot`].
+		///
+		/// # Args:
+		///  - `token_id` - id of the token to provision
+		///  - `amount` - amount of the token to provision
+		///  - `cap` - cap that account has been whitelisted with, if set it limits total amount of
+		///    `first_token_id` that account can provision during [`BootstrapPhase::Whitelist`] phase
+		///  - `proof` - merkle proof of (`sender`, `cap`) leaf, up to
+		///    [`Config::MaxWhitelistProofLength`] nodes
+		#[pallet::call_index(23)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::provision_with_proof(proof.len() as u32)
+			.saturating_add(T::ExternalPriceSource::valuate_weight()))]
+		#[transactional]
+		pub fn provision_with_proof(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			token_id: CurrencyIdOf<T>,
+			amount: BalanceOf<T>,
+			cap: Option<BalanceOf<T>>,
+			proof: Vec<H256>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				!T::MaintenanceStatusProvider::is_maintenance(),
+				Error::<T>::ProvisioningBlockedByMaintenanceMode
+			);
+
+			ensure!(
+				proof.len() <= T::MaxWhitelistProofLength::get() as usize,
+				Error::<T>::WhitelistProofTooLong
+			);
+
+			let root =
+				WhitelistRoot::<T>::get(bootstrap_id).ok_or(Error::<T>::InvalidWhitelistProof)?;
+			ensure!(
+				Self::compute_whitelist_root(Self::whitelist_leaf(&sender, cap), &proof) == root,
+				Error::<T>::InvalidWhitelistProof
+			);
+
+			let provisioned = Self::do_provision(&sender, bootstrap_id, token_id, amount, true)?;
+
+			if let Some(cap) = cap {
+				if Phase::<T>::get(bootstrap_id) == BootstrapPhase::Whitelist &&
+					token_id == Self::first_token_id(bootstrap_id)
+				{
+					ensure!(
+						Provisions::<T>::get((bootstrap_id, sender.clone(), token_id)) <= cap,
+						Error::<T>::WhitelistCapExceeded
+					);
+				}
+			}
+
+			ProvisionAccounts::<T>::insert(bootstrap_id, &sender, ());
+			Self::deposit_event(Event::Provisioned(bootstrap_id, token_id, provisioned));
+			Ok(())
+		}
+
+		/// Sets limits of provisions in given bootstrap, passing `None` removes them. Limits are
+		/// expressed as valuations in `second_token_id`, provisions of `first_token_id` are converted
+		/// using current valuations of the bootstrap. Limits only affect following provisions.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn set_provision_limits(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			limits: Option<ProvisionLimits<BalanceOf<T>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			ensure!(!Self::is_concluded(bootstrap_id), Error::<T>::BootstrapFinished);
+
+			ProvisionLimit::<T>::set(bootstrap_id, limits);
+			Self::deposit_event(Event::ProvisionLimitsUpdated(bootstrap_id));
+			Ok(())
+		}
+
+		/// Sets source of prices used to value provisions in given bootstrap. With
+		/// [`PriceSource::External`] provisions are valued by [`Config::ExternalPriceSource`], so
+		/// neither token of the pair has to be provisioned first. Can only be changed before
+		/// bootstrap starts.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn set_valuation_source(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			source: PriceSource,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			ensure!(
+				Phase::<T>::get(bootstrap_id) == BootstrapPhase::BeforeStart,
+				Error::<T>::AlreadyStarted
+			);
+
+			ValuationSource::<T>::insert(bootstrap_id, source);
+			Self::deposit_event(Event::ValuationSourceUpdated(bootstrap_id, source));
+			Ok(())
+		}
+
+		/// Funds 3rd party rewards for the pool created by given bootstrap. Tokens are moved to the
+		/// bootstrap vault and, once bootstrap finishes and the pool is created, they are scheduled
+		/// for liquidity providers over `rewards_sessions` sessions using
+		/// [`Config::ThirdPartyRewardsApi`]. Only the account that funded the rewards first can top
+		/// them up. Funds are returned to the sponsor if the bootstrap is cancelled, fails or the
+		/// rewards cannot be scheduled.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn fund_bootstrap_rewards(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			reward_token_id: CurrencyIdOf<T>,
+			amount: BalanceOf<T>,
+			rewards_sessions: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			ensure!(!Self::is_concluded(bootstrap_id), Error::<T>::BootstrapFinished);
+			ensure!(rewards_sessions > 0, Error::<T>::RewardsSessionsCannotBeZero);
+
+			let total_amount = match BootstrapRewards::<T>::get(bootstrap_id) {
+				Some((sponsor, token_id, funded, _)) => {
+					ensure!(
+						sponsor == sender && token_id == reward_token_id,
+						Error::<T>::BootstrapRewardsAlreadyFunded
+					);
+					funded.checked_add(&amount).ok_or(Error::<T>::MathOverflow)?
+				},
+				None => amount,
+			};
+
+			<T as Config>::Currency::transfer(
+				reward_token_id.into(),
+				&sender,
+				&Self::vault_address(),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)
+			.or(Err(Error::<T>::NotEnoughAssets))?;
+
+			BootstrapRewards::<T>::insert(
+				bootstrap_id,
+				(sender.clone(), reward_token_id, total_amount, rewards_sessions),
+			);
+			Self::deposit_event(Event::BootstrapRewardsFunded(
+				bootstrap_id,
+				sender,
+				reward_token_id,
+				amount,
+			));
+			Ok(())
+		}
+
+		/// Switches given bootstrap to auction mode (see [`AuctionParams`]), passing `None` brings
+		/// back ratio based price discovery. Can only be changed before bootstrap starts.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1).saturating_add(Weight::from_parts(1_000_000, 0)))]
+		#[transactional]
+		pub fn set_auction_params(
+			origin: OriginFor<T>,
+			bootstrap_id: BootstrapId,
+			params: Option<AuctionParams<BalanceOf<T>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				BootstrapSchedule::<T>::contains_key(bootstrap_id),
+				Error::<T>::BootstrapNotSchduled
+			);
+			ensure!(
+				Phase::<T>::get(bootstrap_id) == BootstrapPhase::BeforeStart,
+				Error::<T>::AlreadyStarted
+			);
+
+			if let Some(params) = &params {
+				ensure!(
+					params.sale_amount > BalanceOf::<T>::zero() &&
+						params.reserve_price > BalanceOf::<T>::zero() &&
+						params.start_price >= params.reserve_price,
+					Error::<T>::InvalidAuctionParams
+				);
+				ensure!(
+					Self::auction_required_amount(params, params.start_price).is_some(),
+					Error::<T>::MathOverflow
+				);
+			}
+
+			Auction::<T>::set(bootstrap_id, params);
+			Self::deposit_event(Event::AuctionParamsUpdated(bootstrap_id));
+			Ok(())
 		}
 	}
 
@@ -879,27 +1478,75 @@ pub mod pallet {
 		ProvisioningBlockedByMaintenanceMode,
 		/// Bootstrap must be pre finalized before it can be finalized
 		BootstrapMustBePreFinalized,
+		/// Pair of tokens is already bootstrapped by another bootstrap
+		PairAlreadyBootstrapped,
+		/// Refunds can only be claimed when bootstrap has failed
+		BootstrapNotFailed,
+		/// Merkle proof does not match whitelist root of the bootstrap
+		InvalidWhitelistProof,
+		/// Merkle proof has more nodes than allowed
+		WhitelistProofTooLong,
+		/// Provision exceeds cap that account has been whitelisted with
+		WhitelistCapExceeded,
+		/// Provision exceeds maximal valuation that single account can provision
+		AccountProvisionCapExceeded,
+		/// Provision exceeds maximal valuation that can be provisioned during current phase
+		PhaseProvisionCapExceeded,
+		/// Vesting period of liquidity tokens cannot last 0 blocks
+		VestingPeriodCannotBeZero,
+		/// Price source was not able to value provisioned token
+		TokenNotValuated,
+		/// Rewards of the bootstrap have already been funded by another account or in another token
+		BootstrapRewardsAlreadyFunded,
+		/// Rewards cannot be distributed over 0 sessions
+		RewardsSessionsCannotBeZero,
+		/// Auction needs non zero sale amount and reserve price not higher than start price
+		InvalidAuctionParams,
+		/// Auction has been sold out, no more bids are accepted
+		AuctionSoldOut,
+		/// Provision exceeds amount of tokens sold in the auction
+		AuctionSaleAmountExceeded,
+		/// Maximal number of concurrently scheduled bootstraps has been reached
+		TooManyBootstraps,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Funds provisioned
-		Provisioned(CurrencyIdOf<T>, BalanceOf<T>),
+		Provisioned(BootstrapId, CurrencyIdOf<T>, BalanceOf<T>),
 		/// Funds provisioned using vested tokens
-		VestedProvisioned(CurrencyIdOf<T>, BalanceOf<T>),
+		VestedProvisioned(BootstrapId, CurrencyIdOf<T>, BalanceOf<T>),
 		/// The activation of the rewards liquidity tokens failed
-		RewardsLiquidityAcitvationFailed(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+		RewardsLiquidityAcitvationFailed(BootstrapId, T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
 		/// Rewards claimed
-		RewardsClaimed(CurrencyIdOf<T>, BalanceOf<T>),
+		RewardsClaimed(BootstrapId, CurrencyIdOf<T>, BalanceOf<T>),
 		/// account whitelisted
-		AccountsWhitelisted,
+		AccountsWhitelisted(BootstrapId),
 		/// bootstrap pre finalization has completed partially
-		BootstrapParitallyPreFinalized,
+		BootstrapParitallyPreFinalized(BootstrapId),
 		/// bootstrap pre finalization has completed, and the bootstrap can now be finalized
-		BootstrapReadyToBeFinalized,
+		BootstrapReadyToBeFinalized(BootstrapId),
 		/// finalization process finished
-		BootstrapFinalized,
+		BootstrapFinalized(BootstrapId),
+		/// bootstrap has not reached its soft cap, no pool was created
+		BootstrapFailed(BootstrapId),
+		/// provisioned tokens returned to the account after bootstrap has failed
+		ProvisionRefunded(BootstrapId, T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+		/// merkle root of whitelisted accounts updated
+		WhitelistRootUpdated(BootstrapId, Option<H256>),
+		/// provision limits updated
+		ProvisionLimitsUpdated(BootstrapId),
+		/// source of prices used to value provisions updated
+		ValuationSourceUpdated(BootstrapId, PriceSource),
+		/// 3rd party rewards for the bootstrapped pool funded by the account
+		BootstrapRewardsFunded(BootstrapId, T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+		/// 3rd party rewards scheduled for the bootstrapped pool
+		BootstrapRewardsScheduled(BootstrapId, CurrencyIdOf<T>, BalanceOf<T>),
+		/// 3rd party rewards returned to the sponsor
+		BootstrapRewardsRefunded(BootstrapId, T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+		/// auction parameters updated
+		AuctionParamsUpdated(BootstrapId),
 	}
 }
 
@@ -913,6 +1560,8 @@ pub enum BootstrapPhase {
 	Public,
 	/// Bootstrap has finished. At this phase users that participated in bootstrap during previous phases can claim their share of minted `liquidity tokens`. `Bootstrap::finalize` can be call to reset pallet state schedule following bootstrap again.
 	Finished,
+	/// Bootstrap has not reached its soft cap. At this phase users that participated in bootstrap during previous phases can claim their provisions back. `Bootstrap::finalize` can be call to reset pallet state schedule following bootstrap again.
+	Failed,
 }
 
 impl Default for BootstrapPhase {
@@ -921,23 +1570,346 @@ impl Default for BootstrapPhase {
 	}
 }
 
+/// Defines how provisions of a bootstrap are valued when enforcing [`ProvisionLimits`]
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Debug, Clone, Copy)]
+pub enum PriceSource {
+	/// Provisions are valued in `second_token_id` using ratio of provisions made so far, so
+	/// `second_token_id` has to be provisioned first
+	Provisions,
+	/// Provisions are valued using [`Config::ExternalPriceSource`], so tokens can be
+	/// provisioned in any order
+	External,
+}
+
+impl Default for PriceSource {
+	fn default() -> Self {
+		PriceSource::Provisions
+	}
+}
+
+/// Parameters of a descending price (Dutch) auction in which bootstrap sells `first_token_id` for
+/// `second_token_id`. Prices are expressed in `second_token_id` per `first_token_id` and multiplied
+/// by [`PRICE_PRECISION`].
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Debug, Clone)]
+pub struct AuctionParams<Balance> {
+	/// amount of `first_token_id` sold in the auction
+	pub sale_amount: Balance,
+	/// price during [`BootstrapPhase::Whitelist`] and at the beginning of [`BootstrapPhase::Public`]
+	pub start_price: Balance,
+	/// price at the end of [`BootstrapPhase::Public`], auction fails if not sold out at it
+	pub reserve_price: Balance,
+}
+
+/// Values tokens in a common unit, so provisions of any pair of tokens can be compared
+pub trait ProvisionPriceSource<CurrencyId, Balance> {
+	/// Valuation of `amount` of `token_id`, `None` if the token cannot be valued
+	fn valuate(token_id: CurrencyId, amount: Balance) -> Option<Balance>;
+	/// Worst case weight of [`ProvisionPriceSource::valuate`]
+	fn valuate_weight() -> Weight;
+}
+
+impl<CurrencyId, Balance> ProvisionPriceSource<CurrencyId, Balance> for () {
+	fn valuate(_token_id: CurrencyId, _amount: Balance) -> Option<Balance> {
+		None
+	}
+
+	fn valuate_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Schedules 3rd party rewards for liquidity providers of a pool
+pub trait ThirdPartyRewardsApi<AccountId, Balance, CurrencyId> {
+	/// Distributes `amount` of `token_id` owned by `sender` among liquidity providers of `pool`
+	/// during following `sessions` sessions
+	fn reward_pool(
+		sender: AccountId,
+		pool: (CurrencyId, CurrencyId),
+		token_id: CurrencyId,
+		amount: Balance,
+		sessions: u32,
+	) -> DispatchResult;
+	/// Weight of [`ThirdPartyRewardsApi::reward_pool`]
+	fn reward_pool_weight() -> Weight;
+}
+
+impl<AccountId, Balance, CurrencyId> ThirdPartyRewardsApi<AccountId, Balance, CurrencyId> for () {
+	fn reward_pool(
+		_sender: AccountId,
+		_pool: (CurrencyId, CurrencyId),
+		_token_id: CurrencyId,
+		_amount: Balance,
+		_sessions: u32,
+	) -> DispatchResult {
+		Err(DispatchError::Other("3rd party rewards are not supported"))
+	}
+
+	fn reward_pool_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Moves vested tokens to multipurpose liquidity reserves (preserving their unlock schedule), so
+/// they can be activated for liquidity mining using [`ActivateKind::UnspentReserves`]
+pub trait VestedLiquidityReserveApi<AccountId, Balance, CurrencyId> {
+	fn reserve_vested_liquidity(
+		who: &AccountId,
+		liquidity_token_id: CurrencyId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance, CurrencyId> VestedLiquidityReserveApi<AccountId, Balance, CurrencyId>
+	for ()
+{
+	fn reserve_vested_liquidity(
+		_who: &AccountId,
+		_liquidity_token_id: CurrencyId,
+		_amount: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("vested liquidity reserves are not supported"))
+	}
+}
+
+/// Limits of provisions in single bootstrap, all of them are expressed as valuations in
+/// `second_token_id` (or in units of [`Config::ExternalPriceSource`] when
+/// [`PriceSource::External`] is used). `None` means that given limit is not enforced.
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo, Debug, Clone, Default)]
+pub struct ProvisionLimits<Balance> {
+	/// maximal valuation that single account can provision during whole bootstrap
+	pub per_account: Option<Balance>,
+	/// maximal valuation that all accounts can provision during [`BootstrapPhase::Whitelist`]
+	pub whitelist_phase: Option<Balance>,
+	/// maximal valuation that all accounts can provision during [`BootstrapPhase::Public`]
+	pub public_phase: Option<Balance>,
+}
+
 impl<T: Config> Pallet<T> {
-	fn is_whitelisted(account: &T::AccountId) -> bool {
-		WhitelistedAccount::<T>::try_get(account).is_ok()
+	fn update_phase(
+		bootstrap_id: BootstrapId,
+		(start, whitelist_length, public_length, _): (
+			BlockNumberFor<T>,
+			u32,
+			u32,
+			(BalanceOf<T>, BalanceOf<T>),
+		),
+		n: BlockNumberFor<T>,
+	) -> Weight {
+		let phase = Phase::<T>::get(bootstrap_id); // R:1
+		if phase == BootstrapPhase::Finished || phase == BootstrapPhase::Failed {
+			return T::DbWeight::get().reads(1)
+		}
+
+		// NOTE: arythmetics protected by invariant check in Bootstrap::start_ido
+		let whitelist_start = start;
+		let public_start = start + whitelist_length.into();
+		let finished = start + whitelist_length.into() + public_length.into();
+
+		if n >= finished {
+			let (second_token_valuation, first_token_valuation) =
+				Valuations::<T>::get(bootstrap_id);
+
+			let soft_cap_reached = SoftCap::<T>::get(bootstrap_id).map_or(
+				true,
+				|(first_token_soft_cap, second_token_soft_cap)| {
+					first_token_valuation >= first_token_soft_cap &&
+						second_token_valuation >= second_token_soft_cap
+				},
+			);
+			let auction_sold_out = Auction::<T>::get(bootstrap_id).map_or(true, |params| {
+				first_token_valuation == params.sale_amount &&
+					Self::auction_required_amount(&params, params.reserve_price)
+						.map_or(false, |required| second_token_valuation >= required)
+			});
+
+			if !soft_cap_reached || !auction_sold_out {
+				log!(
+					info,
+					"bootstrap {} has not reached soft cap or has not sold out auction",
+					bootstrap_id
+				);
+				Self::fail_bootstrap(bootstrap_id);
+				return T::DbWeight::get().reads_writes(8, 5)
+			}
+
+			// pool can not be created without both tokens, e.g. when provisions are valued by
+			// external price source and only one of them was provisioned
+			if first_token_valuation.is_zero() || second_token_valuation.is_zero() {
+				log!(info, "bootstrap {} has not collected both tokens", bootstrap_id);
+				Self::fail_bootstrap(bootstrap_id);
+				return T::DbWeight::get().reads_writes(8, 5)
+			}
+
+			Phase::<T>::insert(bootstrap_id, BootstrapPhase::Finished); // 1 WRINTE
+			log!(info, "bootstrap {} event finished", bootstrap_id);
+
+			// one updated takes R:2, W:2; and multiply for two assets
+			if !T::AssetRegistryApi::enable_pool_creation((
+				Self::first_token_id(bootstrap_id),
+				Self::second_token_id(bootstrap_id),
+			)) {
+				log!(error, "cannot modify asset registry!");
+			}
+			// XykFunctionsTrait R: 11 W:12
+			// PoolCreateApi::pool_create R:2  +
+			// ---------------------------------
+			// R: 13 W 12
+			let mut rewards_weight = Weight::zero();
+			if let Some((liq_asset_id, issuance)) = T::PoolCreateApi::pool_create(
+				Self::vault_address(),
+				Self::first_token_id(bootstrap_id),
+				first_token_valuation,
+				Self::second_token_id(bootstrap_id),
+				second_token_valuation,
+			) {
+				MintedLiquidity::<T>::insert(bootstrap_id, (liq_asset_id, issuance)); // W:1
+				if PromoteBootstrapPool::<T>::get(bootstrap_id) {
+					T::RewardsApi::enable(
+						liq_asset_id,
+						T::DefaultBootstrapPromotedPoolWeight::get(),
+					);
+				}
+				rewards_weight = Self::schedule_bootstrap_rewards(bootstrap_id);
+			} else {
+				log!(error, "cannot create pool!");
+				Self::fail_bootstrap(bootstrap_id);
+			}
+			T::DbWeight::get().reads_writes(27, 21).saturating_add(rewards_weight)
+		} else if n >= public_start {
+			if phase != BootstrapPhase::Public {
+				Phase::<T>::insert(bootstrap_id, BootstrapPhase::Public);
+				log!(info, "starting public phase of bootstrap {}", bootstrap_id);
+				T::DbWeight::get().reads_writes(2, 1)
+			} else {
+				T::DbWeight::get().reads(2)
+			}
+		} else if n >= whitelist_start {
+			if phase != BootstrapPhase::Whitelist {
+				log!(info, "starting whitelist phase of bootstrap {}", bootstrap_id);
+				Phase::<T>::insert(bootstrap_id, BootstrapPhase::Whitelist);
+				T::DbWeight::get().reads_writes(2, 1)
+			} else {
+				T::DbWeight::get().reads(2)
+			}
+		} else {
+			T::DbWeight::get().reads(2)
+		}
+	}
+
+	/// Moves bootstrap to [`BootstrapPhase::Failed`] so provisions can be refunded, rewards
+	/// funded for the pool are returned to the sponsor
+	fn fail_bootstrap(bootstrap_id: BootstrapId) {
+		Phase::<T>::insert(bootstrap_id, BootstrapPhase::Failed);
+		Self::deposit_event(Event::BootstrapFailed(bootstrap_id));
+		if let Err(err) = Self::refund_bootstrap_rewards(bootstrap_id) {
+			log!(error, "cannot refund bootstrap rewards: {:?}", err);
+		}
+	}
+
+	fn is_concluded(bootstrap_id: BootstrapId) -> bool {
+		let phase = Self::phase(bootstrap_id);
+		phase == BootstrapPhase::Finished || phase == BootstrapPhase::Failed
+	}
+
+	fn is_whitelisted(bootstrap_id: BootstrapId, account: &T::AccountId) -> bool {
+		WhitelistedAccount::<T>::try_get(bootstrap_id, account).is_ok()
+	}
+
+	pub(crate) fn whitelist_leaf(account: &T::AccountId, cap: Option<BalanceOf<T>>) -> H256 {
+		H256(blake2_256(&(account, cap).encode()))
+	}
+
+	pub(crate) fn compute_whitelist_root(leaf: H256, proof: &[H256]) -> H256 {
+		proof.iter().fold(leaf, |node, sibling| {
+			let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+			H256(blake2_256(&[left.as_bytes(), right.as_bytes()].concat()))
+		})
 	}
 
 	fn vault_address() -> T::AccountId {
 		PALLET_ID.into_account_truncating()
 	}
 
+	/// Schedules 3rd party rewards funded for the pool created by the bootstrap, rewards that
+	/// cannot be scheduled are returned to the sponsor. Returns the consumed weight.
+	fn schedule_bootstrap_rewards(bootstrap_id: BootstrapId) -> Weight {
+		let (sponsor, reward_token_id, amount, sessions) =
+			match BootstrapRewards::<T>::get(bootstrap_id) {
+				Some(rewards) => rewards,
+				None => return T::DbWeight::get().reads(1),
+			};
+
+		let result = with_storage_layer(|| {
+			T::ThirdPartyRewardsApi::reward_pool(
+				Self::vault_address(),
+				(Self::first_token_id(bootstrap_id), Self::second_token_id(bootstrap_id)),
+				reward_token_id,
+				amount,
+				sessions,
+			)
+		});
+
+		match result {
+			Ok(()) => {
+				BootstrapRewards::<T>::remove(bootstrap_id);
+				Self::deposit_event(Event::BootstrapRewardsScheduled(
+					bootstrap_id,
+					reward_token_id,
+					amount,
+				));
+			},
+			Err(err) => {
+				log!(
+					error,
+					"cannot schedule rewards for bootstrap {} sponsored by {:?}: {:?}",
+					bootstrap_id,
+					sponsor,
+					err
+				);
+				if let Err(err) = Self::refund_bootstrap_rewards(bootstrap_id) {
+					log!(error, "cannot refund bootstrap rewards: {:?}", err);
+				}
+			},
+		}
+
+		// BootstrapRewards and the refund transfer to the sponsor in case of failure
+		T::DbWeight::get()
+			.reads_writes(3, 3)
+			.saturating_add(T::ThirdPartyRewardsApi::reward_pool_weight())
+	}
+
+	/// Returns not yet scheduled 3rd party rewards of the bootstrap to the sponsor
+	fn refund_bootstrap_rewards(bootstrap_id: BootstrapId) -> DispatchResult {
+		if let Some((sponsor, reward_token_id, amount, _)) =
+			BootstrapRewards::<T>::take(bootstrap_id)
+		{
+			T::Currency::transfer(
+				reward_token_id.into(),
+				&Self::vault_address(),
+				&sponsor,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::BootstrapRewardsRefunded(
+				bootstrap_id,
+				sponsor,
+				reward_token_id,
+				amount,
+			));
+		}
+		Ok(())
+	}
+
 	fn claim_liquidity_tokens_from_single_currency(
 		who: &T::AccountId,
+		bootstrap_id: BootstrapId,
 		provision_token_id: &CurrencyIdOf<T>,
 		rewards: BalanceOf<T>,
 		rewards_vested: BalanceOf<T>,
 		lock: (BlockNrAsBalance<T>, BlockNrAsBalance<T>),
+		liquidity_lock: Option<(BlockNumberFor<T>, BlockNrAsBalance<T>)>,
 	) -> DispatchResult {
-		let (liq_token_id, _) = Self::minted_liquidity();
+		let (liq_token_id, _) = Self::minted_liquidity(bootstrap_id);
 		let total_rewards = rewards.checked_add(&rewards_vested).ok_or(Error::<T>::MathOverflow)?;
 		if total_rewards == BalanceOf::<T>::zero() {
 			return Ok(())
@@ -951,14 +1923,17 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::KeepAlive,
 		)?;
 
-		ClaimedRewards::<T>::try_mutate(who, provision_token_id, |rewards| {
-			if let Some(val) = rewards.checked_add(&total_rewards) {
-				*rewards = val;
-				Ok(())
-			} else {
-				Err(Error::<T>::MathOverflow)
-			}
-		})?;
+		ClaimedRewards::<T>::try_mutate(
+			(bootstrap_id, who.clone(), *provision_token_id),
+			|rewards| {
+				if let Some(val) = rewards.checked_add(&total_rewards) {
+					*rewards = val;
+					Ok(())
+				} else {
+					Err(Error::<T>::MathOverflow)
+				}
+			},
+		)?;
 
 		if rewards_vested > BalanceOf::<T>::zero() {
 			T::VestingProvider::lock_tokens(
@@ -970,6 +1945,18 @@ impl<T: Config> Pallet<T> {
 			)?;
 		}
 
+		if let Some((lock_start, lock_end)) = liquidity_lock {
+			if rewards > BalanceOf::<T>::zero() {
+				T::VestingProvider::lock_tokens(
+					who,
+					liq_token_id,
+					rewards,
+					Some(lock_start),
+					lock_end,
+				)?;
+			}
+		}
+
 		Ok(())
 	}
 
@@ -983,8 +1970,12 @@ impl<T: Config> Pallet<T> {
 	/// actual_nominator * expected_denominator     expected_nominator * actual_denominator
 	/// ---------------------------------------- <= ----------------------------------------
 	/// actual_denominator * expected_denominator    expected_denominator * actual_nominator
-	fn is_ratio_kept(ratio_nominator: BalanceOf<T>, ratio_denominator: BalanceOf<T>) -> bool {
-		let (second_token_valuation, first_token_valuation) = Valuations::<T>::get();
+	fn is_ratio_kept(
+		bootstrap_id: BootstrapId,
+		ratio_nominator: BalanceOf<T>,
+		ratio_denominator: BalanceOf<T>,
+	) -> bool {
+		let (second_token_valuation, first_token_valuation) = Valuations::<T>::get(bootstrap_id);
 		let left = U256::from(first_token_valuation.into()) * U256::from(ratio_denominator.into());
 		let right = U256::from(ratio_nominator.into()) * U256::from(second_token_valuation.into());
 		left <= right
@@ -992,15 +1983,19 @@ impl<T: Config> Pallet<T> {
 
 	pub fn do_provision(
 		sender: &T::AccountId,
+		bootstrap_id: BootstrapId,
 		token_id: CurrencyIdOf<T>,
 		amount: BalanceOf<T>,
+		am_i_whitelisted: bool,
 		// is_vested: ProvisionKind,
-	) -> DispatchResult {
-		let is_first_token = token_id == Self::first_token_id();
-		let is_second_token = token_id == Self::second_token_id();
-		let is_public_phase = Phase::<T>::get() == BootstrapPhase::Public;
-		let is_whitelist_phase = Phase::<T>::get() == BootstrapPhase::Whitelist;
-		let am_i_whitelisted = Self::is_whitelisted(sender);
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let first_token_id = Self::first_token_id(bootstrap_id);
+		let second_token_id = Self::second_token_id(bootstrap_id);
+		let is_first_token = token_id == first_token_id;
+		let is_second_token = token_id == second_token_id;
+		let phase = Phase::<T>::get(bootstrap_id);
+		let is_public_phase = phase == BootstrapPhase::Public;
+		let is_whitelist_phase = phase == BootstrapPhase::Whitelist;
 
 		ensure!(is_first_token || is_second_token, Error::<T>::UnsupportedTokenId);
 
@@ -1009,9 +2004,22 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::Unauthorized
 		);
 
-		let schedule = BootstrapSchedule::<T>::get();
+		let schedule = BootstrapSchedule::<T>::get(bootstrap_id);
 		ensure!(schedule.is_some(), Error::<T>::Unauthorized);
-		let (_, _, _, (ratio_nominator, ratio_denominator)) = schedule.unwrap();
+		let schedule = schedule.unwrap();
+		let (_, _, _, (ratio_nominator, ratio_denominator)) = schedule;
+
+		let auction = Auction::<T>::get(bootstrap_id);
+		let amount = match &auction {
+			Some(params) => Self::accepted_auction_provision(
+				bootstrap_id,
+				params,
+				&schedule,
+				is_first_token,
+				amount,
+			)?,
+			None => amount,
+		};
 
 		<T as Config>::Currency::transfer(
 			token_id.into(),
@@ -1025,7 +2033,7 @@ impl<T: Config> Pallet<T> {
 		// match is_vested {
 		// 	ProvisionKind::Regular => {
 		ensure!(
-			Provisions::<T>::try_mutate(sender, token_id, |provision| {
+			Provisions::<T>::try_mutate((bootstrap_id, sender.clone(), token_id), |provision| {
 				if let Some(val) = provision.checked_add(&amount) {
 					*provision = val;
 					Ok(())
@@ -1060,21 +2068,27 @@ impl<T: Config> Pallet<T> {
 					},
 			}
 		*/
-		let (pre_second_token_valuation, _) = Valuations::<T>::get();
+		let (pre_second_token_valuation, _) = Valuations::<T>::get(bootstrap_id);
 		ensure!(
-			token_id != Self::first_token_id() ||
-				pre_second_token_valuation != BalanceOf::<T>::zero(),
+			token_id != first_token_id ||
+				pre_second_token_valuation != BalanceOf::<T>::zero() ||
+				auction.is_some() ||
+				ValuationSource::<T>::get(bootstrap_id) == PriceSource::External,
 			Error::<T>::FirstProvisionInSecondTokenId
 		);
 
+		// NOTE: provision is valuated before it affects the valuations
+		let valuation = Self::provision_valuation(bootstrap_id, token_id, amount)?;
+
 		ensure!(
 			Valuations::<T>::try_mutate(
+				bootstrap_id,
 				|(second_token_valuation, first_token_valuation)| -> Result<(), ()> {
-					if token_id == Self::second_token_id() {
+					if token_id == second_token_id {
 						*second_token_valuation =
 							second_token_valuation.checked_add(&amount).ok_or(())?;
 					}
-					if token_id == Self::first_token_id() {
+					if token_id == first_token_id {
 						*first_token_valuation =
 							first_token_valuation.checked_add(&amount).ok_or(())?;
 					}
@@ -1085,20 +2099,284 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::MathOverflow
 		);
 
-		if token_id == Self::first_token_id() {
+		if token_id == first_token_id &&
+			pre_second_token_valuation != BalanceOf::<T>::zero() &&
+			auction.is_none()
+		{
 			ensure!(
-				Self::is_ratio_kept(ratio_nominator, ratio_denominator),
+				Self::is_ratio_kept(bootstrap_id, ratio_nominator, ratio_denominator),
 				Error::<T>::ValuationRatio
 			);
 		}
+
+		Self::ensure_provision_limits(sender, bootstrap_id, is_whitelist_phase, valuation)?;
+		Ok(amount)
+	}
+
+	/// Part of the provision that is accepted by the auction. Provisions of `first_token_id` are
+	/// limited by `sale_amount`, while bids in `second_token_id` are trimmed to the amount that
+	/// sells out the auction at current price.
+	fn accepted_auction_provision(
+		bootstrap_id: BootstrapId,
+		params: &AuctionParams<BalanceOf<T>>,
+		schedule: &(BlockNumberFor<T>, u32, u32, (BalanceOf<T>, BalanceOf<T>)),
+		is_first_token: bool,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		let (second_token_valuation, first_token_valuation) = Valuations::<T>::get(bootstrap_id);
+
+		if is_first_token {
+			let total =
+				first_token_valuation.checked_add(&amount).ok_or(Error::<T>::MathOverflow)?;
+			ensure!(total <= params.sale_amount, Error::<T>::AuctionSaleAmountExceeded);
+			return Ok(amount)
+		}
+
+		let price =
+			Self::auction_price_at(params, schedule, <frame_system::Pallet<T>>::block_number());
+		let required =
+			Self::auction_required_amount(params, price).ok_or(Error::<T>::MathOverflow)?;
+		ensure!(second_token_valuation < required, Error::<T>::AuctionSoldOut);
+		Ok(amount.min(required - second_token_valuation))
+	}
+
+	/// Amount of `second_token_id` needed to buy whole `sale_amount` at given `price`
+	fn auction_required_amount(
+		params: &AuctionParams<BalanceOf<T>>,
+		price: BalanceOf<T>,
+	) -> Option<BalanceOf<T>> {
+		multiply_by_rational_with_rounding(
+			params.sale_amount.into(),
+			price.into(),
+			PRICE_PRECISION,
+			Rounding::Up,
+		)?
+		.try_into()
+		.ok()
+	}
+
+	fn auction_price_at(
+		params: &AuctionParams<BalanceOf<T>>,
+		schedule: &(BlockNumberFor<T>, u32, u32, (BalanceOf<T>, BalanceOf<T>)),
+		n: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		let (_, public_start, finished) = Self::phase_blocks(schedule);
+		if n <= public_start {
+			return params.start_price
+		}
+		if n >= finished {
+			return params.reserve_price
+		}
+
+		let elapsed: u128 = (n - public_start).saturated_into();
+		let length: u128 = (finished - public_start).saturated_into();
+		let discount: BalanceOf<T> = multiply_by_rational_with_rounding(
+			(params.start_price - params.reserve_price).into(),
+			elapsed,
+			length,
+			Rounding::Down,
+		)
+		.and_then(|discount| discount.try_into().ok())
+		.unwrap_or_default();
+		params.start_price.saturating_sub(discount)
+	}
+
+	/// Current price of the auction run by given bootstrap (see [`AuctionParams`]), `None` if the
+	/// bootstrap is not in auction mode
+	pub fn auction_price(bootstrap_id: BootstrapId) -> Option<BalanceOf<T>> {
+		let params = Auction::<T>::get(bootstrap_id)?;
+		let schedule = BootstrapSchedule::<T>::get(bootstrap_id)?;
+		Some(Self::auction_price_at(&params, &schedule, <frame_system::Pallet<T>>::block_number()))
+	}
+
+	/// Expresses `amount` of provisioned token in `second_token_id` using current valuations, or
+	/// using [`Config::ExternalPriceSource`] if bootstrap uses [`PriceSource::External`]. The
+	/// first provision of `first_token_id` is worth all of the provisioned `second_token_id`.
+	fn provision_valuation(
+		bootstrap_id: BootstrapId,
+		token_id: CurrencyIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, Error<T>> {
+		if ValuationSource::<T>::get(bootstrap_id) == PriceSource::External {
+			return T::ExternalPriceSource::valuate(token_id, amount)
+				.ok_or(Error::<T>::TokenNotValuated)
+		}
+
+		if token_id == Self::second_token_id(bootstrap_id) {
+			return Ok(amount)
+		}
+
+		let (second_token_valuation, first_token_valuation) = Valuations::<T>::get(bootstrap_id);
+		if first_token_valuation == BalanceOf::<T>::zero() {
+			return Ok(second_token_valuation)
+		}
+
+		multiply_by_rational_with_rounding(
+			amount.into(),
+			second_token_valuation.into(),
+			first_token_valuation.into(),
+			Rounding::Up,
+		)
+		.ok_or(Error::<T>::MathOverflow)?
+		.try_into()
+		.map_err(|_| Error::<T>::MathOverflow)
+	}
+
+	/// Accounts `valuation` of the provision made by `sender` and assures that it does not exceed
+	/// [`ProvisionLimit`] of the bootstrap
+	fn ensure_provision_limits(
+		sender: &T::AccountId,
+		bootstrap_id: BootstrapId,
+		is_whitelist_phase: bool,
+		valuation: BalanceOf<T>,
+	) -> DispatchResult {
+		let limits = ProvisionLimit::<T>::get(bootstrap_id).unwrap_or_default();
+
+		let account_valuation = AccountProvisionValuation::<T>::get(bootstrap_id, sender)
+			.checked_add(&valuation)
+			.ok_or(Error::<T>::MathOverflow)?;
+		ensure!(
+			limits.per_account.map_or(true, |cap| account_valuation <= cap),
+			Error::<T>::AccountProvisionCapExceeded
+		);
+
+		let (whitelist_valuation, public_valuation) =
+			PhaseProvisionValuation::<T>::get(bootstrap_id);
+		let (phase_valuation, phase_cap) = if is_whitelist_phase {
+			(whitelist_valuation, limits.whitelist_phase)
+		} else {
+			(public_valuation, limits.public_phase)
+		};
+		let phase_valuation =
+			phase_valuation.checked_add(&valuation).ok_or(Error::<T>::MathOverflow)?;
+		ensure!(
+			phase_cap.map_or(true, |cap| phase_valuation <= cap),
+			Error::<T>::PhaseProvisionCapExceeded
+		);
+
+		AccountProvisionValuation::<T>::insert(bootstrap_id, sender, account_valuation);
+		if is_whitelist_phase {
+			PhaseProvisionValuation::<T>::insert(bootstrap_id, (phase_valuation, public_valuation));
+		} else {
+			PhaseProvisionValuation::<T>::insert(
+				bootstrap_id,
+				(whitelist_valuation, phase_valuation),
+			);
+		}
 		Ok(())
 	}
 
-	fn get_valuation(token_id: &CurrencyIdOf<T>) -> BalanceOf<T> {
-		if *token_id == Self::first_token_id() {
-			Self::valuations().1
-		} else if *token_id == Self::second_token_id() {
-			Self::valuations().0
+	/// Valuation (expressed in `second_token_id`) that account can still provision into given
+	/// bootstrap, `None` if its provisions are not limited
+	pub fn get_remaining_provision_allowance(
+		bootstrap_id: BootstrapId,
+		account: &T::AccountId,
+	) -> Option<BalanceOf<T>> {
+		let limits = ProvisionLimit::<T>::get(bootstrap_id)?;
+
+		let account_allowance = limits.per_account.map(|cap| {
+			cap.saturating_sub(AccountProvisionValuation::<T>::get(bootstrap_id, account))
+		});
+
+		let (whitelist_valuation, public_valuation) =
+			PhaseProvisionValuation::<T>::get(bootstrap_id);
+		let phase_allowance = match Phase::<T>::get(bootstrap_id) {
+			BootstrapPhase::Whitelist =>
+				limits.whitelist_phase.map(|cap| cap.saturating_sub(whitelist_valuation)),
+			BootstrapPhase::Public =>
+				limits.public_phase.map(|cap| cap.saturating_sub(public_valuation)),
+			_ => None,
+		};
+
+		match (account_allowance, phase_allowance) {
+			(Some(account_allowance), Some(phase_allowance)) =>
+				Some(account_allowance.min(phase_allowance)),
+			(account_allowance, phase_allowance) => account_allowance.or(phase_allowance),
+		}
+	}
+
+	/// Blocks at which [`BootstrapPhase::Whitelist`], [`BootstrapPhase::Public`] and
+	/// [`BootstrapPhase::Finished`] phases of bootstrap with given schedule start
+	pub fn phase_blocks(
+		(start, whitelist_length, public_length, _): &(
+			BlockNumberFor<T>,
+			u32,
+			u32,
+			(BalanceOf<T>, BalanceOf<T>),
+		),
+	) -> (BlockNumberFor<T>, BlockNumberFor<T>, BlockNumberFor<T>) {
+		let public_start = start.saturating_add((*whitelist_length).into());
+		(*start, public_start, public_start.saturating_add((*public_length).into()))
+	}
+
+	/// Price of `first_token_id` expressed in `second_token_id` and multiplied by
+	/// [`PRICE_PRECISION`], `None` if nothing has been provisioned in `first_token_id` yet
+	pub fn implied_price(bootstrap_id: BootstrapId) -> Option<BalanceOf<T>> {
+		let (second_token_valuation, first_token_valuation) = Valuations::<T>::get(bootstrap_id);
+		if first_token_valuation == BalanceOf::<T>::zero() {
+			return None
+		}
+
+		multiply_by_rational_with_rounding(
+			second_token_valuation.into(),
+			PRICE_PRECISION,
+			first_token_valuation.into(),
+			Rounding::Down,
+		)?
+		.try_into()
+		.ok()
+	}
+
+	/// Liquidity tokens (non vested, vested) that account can claim from given bootstrap. All of
+	/// them are vested until [`LiquidityVestingPeriod`] of the bootstrap ends.
+	pub fn get_claimable_liquidity(
+		bootstrap_id: BootstrapId,
+		who: &T::AccountId,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		if Self::phase(bootstrap_id) != BootstrapPhase::Finished ||
+			!Self::has_unclaimed_liquidity(bootstrap_id, who)
+		{
+			return (BalanceOf::<T>::zero(), BalanceOf::<T>::zero())
+		}
+
+		let (rewards, vested_rewards) = match (
+			Self::calculate_rewards(who, bootstrap_id, &Self::first_token_id(bootstrap_id)),
+			Self::calculate_rewards(who, bootstrap_id, &Self::second_token_id(bootstrap_id)),
+		) {
+			(
+				Ok((first_token_rewards, first_token_rewards_vested, _)),
+				Ok((second_token_rewards, second_token_rewards_vested, _)),
+			) => (
+				first_token_rewards.saturating_add(second_token_rewards),
+				first_token_rewards_vested.saturating_add(second_token_rewards_vested),
+			),
+			_ => (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+		};
+
+		// NOTE: claimed non vested rewards are locked by `liquidity_vesting_lock`
+		if Self::liquidity_vesting_lock(bootstrap_id).is_some() {
+			(BalanceOf::<T>::zero(), rewards.saturating_add(vested_rewards))
+		} else {
+			(rewards, vested_rewards)
+		}
+	}
+
+	/// Liquidity tokens (both non vested and vested) already claimed by account from given
+	/// bootstrap
+	pub fn get_claimed_liquidity(bootstrap_id: BootstrapId, who: &T::AccountId) -> BalanceOf<T> {
+		ClaimedRewards::<T>::get((bootstrap_id, who.clone(), Self::first_token_id(bootstrap_id)))
+			.saturating_add(ClaimedRewards::<T>::get((
+				bootstrap_id,
+				who.clone(),
+				Self::second_token_id(bootstrap_id),
+			)))
+	}
+
+	fn get_valuation(bootstrap_id: BootstrapId, token_id: &CurrencyIdOf<T>) -> BalanceOf<T> {
+		if *token_id == Self::first_token_id(bootstrap_id) {
+			Self::valuations(bootstrap_id).1
+		} else if *token_id == Self::second_token_id(bootstrap_id) {
+			Self::valuations(bootstrap_id).0
 		} else {
 			BalanceOf::<T>::zero()
 		}
@@ -1106,13 +2384,15 @@ impl<T: Config> Pallet<T> {
 
 	fn calculate_rewards(
 		who: &T::AccountId,
+		bootstrap_id: BootstrapId,
 		token_id: &CurrencyIdOf<T>,
 	) -> Result<(BalanceOf<T>, BalanceOf<T>, (BlockNrAsBalance<T>, BlockNrAsBalance<T>)), Error<T>>
 	{
-		let valuation = Self::get_valuation(token_id);
-		let provision = Self::provisions(who, token_id);
-		let (vested_provision, lock_start, lock_end) = Self::vested_provisions(who, token_id);
-		let (_, liquidity) = Self::minted_liquidity();
+		let valuation = Self::get_valuation(bootstrap_id, token_id);
+		let provision = Self::provisions((bootstrap_id, who.clone(), *token_id));
+		let (vested_provision, lock_start, lock_end) =
+			Self::vested_provisions((bootstrap_id, who.clone(), *token_id));
+		let (_, liquidity) = Self::minted_liquidity(bootstrap_id);
 		let rewards = multiply_by_rational_with_rounding(
 			liquidity.into() / 2,
 			provision.into(),
@@ -1136,29 +2416,55 @@ impl<T: Config> Pallet<T> {
 		Ok((rewards, vested_rewards, (lock_start, lock_end)))
 	}
 
-	fn do_claim_liquidity_tokens(who: &T::AccountId, activate_rewards: bool) -> DispatchResult {
-		ensure!(Self::phase() == BootstrapPhase::Finished, Error::<T>::NotFinishedYet);
-
-		let (liq_token_id, _) = Self::minted_liquidity();
+	/// Vesting schedule (starting block, ending block) of liquidity tokens claimed from given
+	/// bootstrap, `None` if they are not vested or vesting has already ended
+	fn liquidity_vesting_lock(
+		bootstrap_id: BootstrapId,
+	) -> Option<(BlockNumberFor<T>, BlockNrAsBalance<T>)> {
+		let vesting_period = LiquidityVestingPeriod::<T>::get(bootstrap_id)?;
+		let (_, _, finished) = Self::phase_blocks(&BootstrapSchedule::<T>::get(bootstrap_id)?);
+		let vesting_end = finished.saturating_add(vesting_period.into());
+		if <frame_system::Pallet<T>>::block_number() >= vesting_end {
+			return None
+		}
+		Some((finished, vesting_end.saturated_into::<u128>().saturated_into()))
+	}
 
+	fn has_unclaimed_liquidity(bootstrap_id: BootstrapId, who: &T::AccountId) -> bool {
 		// for backward compatibility
 		if !Self::archived().is_empty() {
-			ensure!(ProvisionAccounts::<T>::get(who).is_some(), Error::<T>::NothingToClaim);
+			ProvisionAccounts::<T>::get(bootstrap_id, who).is_some()
 		} else {
-			ensure!(
-				!ClaimedRewards::<T>::contains_key(&who, &Self::first_token_id()),
-				Error::<T>::NothingToClaim
-			);
-			ensure!(
-				!ClaimedRewards::<T>::contains_key(&who, &Self::second_token_id()),
-				Error::<T>::NothingToClaim
-			);
+			!ClaimedRewards::<T>::contains_key((
+				bootstrap_id,
+				who.clone(),
+				Self::first_token_id(bootstrap_id),
+			)) && !ClaimedRewards::<T>::contains_key((
+				bootstrap_id,
+				who.clone(),
+				Self::second_token_id(bootstrap_id),
+			))
 		}
+	}
+
+	fn do_claim_liquidity_tokens(
+		who: &T::AccountId,
+		bootstrap_id: BootstrapId,
+		activate_rewards: bool,
+	) -> DispatchResult {
+		ensure!(Self::phase(bootstrap_id) == BootstrapPhase::Finished, Error::<T>::NotFinishedYet);
+
+		let (liq_token_id, _) = Self::minted_liquidity(bootstrap_id);
+		let first_token_id = Self::first_token_id(bootstrap_id);
+		let second_token_id = Self::second_token_id(bootstrap_id);
+		let liquidity_lock = Self::liquidity_vesting_lock(bootstrap_id);
+
+		ensure!(Self::has_unclaimed_liquidity(bootstrap_id, who), Error::<T>::NothingToClaim);
 
 		let (first_token_rewards, first_token_rewards_vested, first_token_lock) =
-			Self::calculate_rewards(who, &Self::first_token_id())?;
+			Self::calculate_rewards(who, bootstrap_id, &first_token_id)?;
 		let (second_token_rewards, second_token_rewards_vested, second_token_lock) =
-			Self::calculate_rewards(who, &Self::second_token_id())?;
+			Self::calculate_rewards(who, bootstrap_id, &second_token_id)?;
 
 		let total_rewards_claimed = second_token_rewards
 			.checked_add(&second_token_rewards_vested)
@@ -1170,10 +2476,12 @@ impl<T: Config> Pallet<T> {
 
 		Self::claim_liquidity_tokens_from_single_currency(
 			who,
-			&Self::second_token_id(),
+			bootstrap_id,
+			&second_token_id,
 			second_token_rewards,
 			second_token_rewards_vested,
 			second_token_lock,
+			liquidity_lock,
 		)?;
 		log!(
 			info,
@@ -1184,10 +2492,12 @@ impl<T: Config> Pallet<T> {
 
 		Self::claim_liquidity_tokens_from_single_currency(
 			who,
-			&Self::first_token_id(),
+			bootstrap_id,
+			&first_token_id,
 			first_token_rewards,
 			first_token_rewards_vested,
 			first_token_lock,
+			liquidity_lock,
 		)?;
 		log!(
 			info,
@@ -1196,19 +2506,36 @@ impl<T: Config> Pallet<T> {
 			first_token_rewards_vested,
 		);
 
-		ProvisionAccounts::<T>::remove(who);
+		ProvisionAccounts::<T>::remove(bootstrap_id, who);
 
 		if activate_rewards && <T as Config>::RewardsApi::is_enabled(liq_token_id) {
 			let non_vested_rewards = second_token_rewards
 				.checked_add(&first_token_rewards)
 				.ok_or(Error::<T>::MathOverflow)?;
 			if non_vested_rewards > BalanceOf::<T>::zero() {
-				let activate_result = <T as Config>::RewardsApi::activate_liquidity(
-					who.clone(),
-					liq_token_id,
-					non_vested_rewards,
-					Some(ActivateKind::AvailableBalance),
-				);
+				let activate_result = with_storage_layer(|| -> DispatchResult {
+					if liquidity_lock.is_some() {
+						// vested liquidity tokens can only be activated from reserves
+						T::VestedLiquidityReserveApi::reserve_vested_liquidity(
+							who,
+							liq_token_id,
+							non_vested_rewards,
+						)?;
+						<T as Config>::RewardsApi::activate_liquidity(
+							who.clone(),
+							liq_token_id,
+							non_vested_rewards,
+							Some(ActivateKind::UnspentReserves),
+						)
+					} else {
+						<T as Config>::RewardsApi::activate_liquidity(
+							who.clone(),
+							liq_token_id,
+							non_vested_rewards,
+							Some(ActivateKind::AvailableBalance),
+						)
+					}
+				});
 				if let Err(err) = activate_result {
 					log!(
 						error,
@@ -1220,6 +2547,7 @@ impl<T: Config> Pallet<T> {
 					);
 
 					Self::deposit_event(Event::RewardsLiquidityAcitvationFailed(
+						bootstrap_id,
 						who.clone(),
 						liq_token_id,
 						non_vested_rewards,
@@ -1228,23 +2556,84 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		Self::deposit_event(Event::RewardsClaimed(liq_token_id, total_rewards_claimed));
+		Self::deposit_event(Event::RewardsClaimed(
+			bootstrap_id,
+			liq_token_id,
+			total_rewards_claimed,
+		));
+
+		Ok(())
+	}
+
+	fn do_claim_refund(who: &T::AccountId, bootstrap_id: BootstrapId) -> DispatchResult {
+		ensure!(
+			Self::phase(bootstrap_id) == BootstrapPhase::Failed,
+			Error::<T>::BootstrapNotFailed
+		);
+		ensure!(
+			ProvisionAccounts::<T>::get(bootstrap_id, who).is_some(),
+			Error::<T>::NothingToClaim
+		);
+
+		for token_id in
+			[Self::first_token_id(bootstrap_id), Self::second_token_id(bootstrap_id)].iter()
+		{
+			let provision = Provisions::<T>::take((bootstrap_id, who.clone(), *token_id));
+			let (vested_provision, lock_start, lock_end) =
+				VestedProvisions::<T>::take((bootstrap_id, who.clone(), *token_id));
+			let refund =
+				provision.checked_add(&vested_provision).ok_or(Error::<T>::MathOverflow)?;
+			if refund == BalanceOf::<T>::zero() {
+				continue
+			}
+
+			T::Currency::transfer(
+				(*token_id).into(),
+				&Self::vault_address(),
+				who,
+				refund,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			if vested_provision > BalanceOf::<T>::zero() {
+				T::VestingProvider::lock_tokens(
+					who,
+					*token_id,
+					vested_provision,
+					Some(lock_start.into().saturated_into()),
+					lock_end,
+				)?;
+			}
+
+			Self::deposit_event(Event::ProvisionRefunded(
+				bootstrap_id,
+				who.clone(),
+				*token_id,
+				refund,
+			));
+		}
+
+		ProvisionAccounts::<T>::remove(bootstrap_id, who);
 
 		Ok(())
 	}
 
-	fn first_token_id() -> CurrencyIdOf<T> {
-		ActivePair::<T>::get().map(|(first, _)| first).unwrap_or(4_u32.into())
+	fn first_token_id(bootstrap_id: BootstrapId) -> CurrencyIdOf<T> {
+		ActivePair::<T>::get(bootstrap_id)
+			.map(|(first, _)| first)
+			.unwrap_or(4_u32.into())
 	}
 
-	fn second_token_id() -> CurrencyIdOf<T> {
-		ActivePair::<T>::get().map(|(_, second)| second).unwrap_or(0_u32.into())
+	fn second_token_id(bootstrap_id: BootstrapId) -> CurrencyIdOf<T> {
+		ActivePair::<T>::get(bootstrap_id)
+			.map(|(_, second)| second)
+			.unwrap_or(0_u32.into())
 	}
 }
 
 impl<T: Config> Contains<(CurrencyIdOf<T>, CurrencyIdOf<T>)> for Pallet<T> {
 	fn contains(pair: &(CurrencyIdOf<T>, CurrencyIdOf<T>)) -> bool {
-		pair == &(Self::first_token_id(), Self::second_token_id()) ||
-			pair == &(Self::second_token_id(), Self::first_token_id())
+		ActivePair::<T>::iter_values()
+			.any(|(first, second)| pair == &(first, second) || pair == &(second, first))
 	}
 }
@@ -0,0 +1,170 @@
+use super::*;
+use frame_support::{
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v3 {
+	use super::*;
+
+	#[storage_alias]
+	pub(crate) type Provisions<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type VestedProvisions<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		(BalanceOf<T>, BlockNrAsBalance<T>, BlockNrAsBalance<T>),
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type WhitelistedAccount<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, (), ValueQuery>;
+
+	#[storage_alias]
+	pub(crate) type Phase<T: Config> = StorageValue<Pallet<T>, BootstrapPhase, OptionQuery>;
+
+	#[storage_alias]
+	pub(crate) type Valuations<T: Config> =
+		StorageValue<Pallet<T>, (BalanceOf<T>, BalanceOf<T>), OptionQuery>;
+
+	#[storage_alias]
+	pub(crate) type BootstrapSchedule<T: Config> = StorageValue<
+		Pallet<T>,
+		(BlockNumberFor<T>, u32, u32, (BalanceOf<T>, BalanceOf<T>)),
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type MintedLiquidity<T: Config> =
+		StorageValue<Pallet<T>, (CurrencyIdOf<T>, BalanceOf<T>), OptionQuery>;
+
+	#[storage_alias]
+	pub(crate) type ClaimedRewards<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type ProvisionAccounts<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type ActivePair<T: Config> =
+		StorageValue<Pallet<T>, (CurrencyIdOf<T>, CurrencyIdOf<T>), OptionQuery>;
+
+	#[storage_alias]
+	pub(crate) type PromoteBootstrapPool<T: Config> = StorageValue<Pallet<T>, bool, OptionQuery>;
+
+	/// Moves the state of the singleton bootstrap under [`DEFAULT_BOOTSTRAP_ID`]. Old and new
+	/// storage items share the same prefixes, so every map is fully drained before the keyed
+	/// entries are written back.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 2 {
+				log::info!(
+					target: "bootstrap",
+					"Attempted to apply bootstrap id migration to bootstrap but failed because storage version is {:?}, and not 2",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let id = DEFAULT_BOOTSTRAP_ID;
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+
+			if let Some(phase) = Phase::<T>::take() {
+				crate::Phase::<T>::insert(id, phase);
+				writes = writes.saturating_add(2);
+			}
+			if let Some(valuations) = Valuations::<T>::take() {
+				crate::Valuations::<T>::insert(id, valuations);
+				writes = writes.saturating_add(2);
+			}
+			if let Some(schedule) = BootstrapSchedule::<T>::take() {
+				crate::BootstrapSchedule::<T>::insert(id, schedule);
+				writes = writes.saturating_add(2);
+			}
+			if let Some(minted) = MintedLiquidity::<T>::take() {
+				crate::MintedLiquidity::<T>::insert(id, minted);
+				writes = writes.saturating_add(2);
+			}
+			if let Some(pair) = ActivePair::<T>::take() {
+				crate::ActivePair::<T>::insert(id, pair);
+				writes = writes.saturating_add(2);
+			}
+			if let Some(promote) = PromoteBootstrapPool::<T>::take() {
+				crate::PromoteBootstrapPool::<T>::insert(id, promote);
+				writes = writes.saturating_add(2);
+			}
+			reads = reads.saturating_add(6);
+
+			let provisions = Provisions::<T>::drain().collect::<Vec<_>>();
+			let vested_provisions = VestedProvisions::<T>::drain().collect::<Vec<_>>();
+			let claimed_rewards = ClaimedRewards::<T>::drain().collect::<Vec<_>>();
+			let whitelisted = WhitelistedAccount::<T>::drain().collect::<Vec<_>>();
+			let provision_accounts = ProvisionAccounts::<T>::drain().collect::<Vec<_>>();
+
+			let migrated = provisions.len() +
+				vested_provisions.len() +
+				claimed_rewards.len() +
+				whitelisted.len() +
+				provision_accounts.len();
+			reads = reads.saturating_add(migrated as u64);
+			writes = writes.saturating_add(2 * migrated as u64);
+
+			for (who, token_id, provision) in provisions {
+				crate::Provisions::<T>::insert((id, who, token_id), provision);
+			}
+			for (who, token_id, provision) in vested_provisions {
+				crate::VestedProvisions::<T>::insert((id, who, token_id), provision);
+			}
+			for (who, token_id, claimed) in claimed_rewards {
+				crate::ClaimedRewards::<T>::insert((id, who, token_id), claimed);
+			}
+			for (who, _) in whitelisted {
+				crate::WhitelistedAccount::<T>::insert(id, who, ());
+			}
+			for (who, _) in provision_accounts {
+				crate::ProvisionAccounts::<T>::insert(id, who, ());
+			}
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(
+				target: "bootstrap",
+				"Migrated {} bootstrap entries to storage version 3",
+				migrated
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
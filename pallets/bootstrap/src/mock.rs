@@ -160,21 +160,28 @@ impl pallet_xyk::Config for Test {
 	type DisabledTokens = Nothing;
 	type VestingProvider = Vesting;
 	type AssetMetadataMutation = AssetMetadataMutation;
+	type MaxValuationPathLength = ConstU32<3>;
+	type FeeLockWeight = ();
 }
 
 impl pallet_proof_of_stake::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = TokensActivationPassthrough<Test>;
+	type ThirdPartyActivationReservesProvider = ();
 	type NativeCurrencyId = NativeCurrencyId;
 	type Currency = MultiTokenCurrencyAdapter<Test>;
 	type LiquidityMiningIssuanceVault = FakeLiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = ConstU32<10000>;
 	type WeightInfo = ();
 	type RewardsSchedulesLimit = ConstU32<10>;
+	type MaxRewardTokensPerPool = ConstU32<10>;
+	type MaxSchedulesChangesPerSession = ConstU32<10>;
+	type MaxActivatedPoolsPerUser = ConstU32<10>;
 	type Min3rdPartyRewardValutationPerSession = ConstU128<10>;
 	type Min3rdPartyRewardVolume = ConstU128<10>;
+	type RewardsHistoryLength = ConstU32<10>;
+	type MaxClaimReceipts = ConstU32<5>;
 	type ValuationApi = Xyk;
-	type SchedulesPerBlock = ConstU32<5>;
 }
 
 impl BootstrapBenchmarkingConfig for Test {}
@@ -311,6 +318,32 @@ mockall::mock! {
 	}
 }
 
+mockall::mock! {
+	pub VestedLiquidityReserveApi {}
+
+	impl VestedLiquidityReserveApi<AccountId, Balance, TokenId> for VestedLiquidityReserveApi {
+		fn reserve_vested_liquidity(who: &AccountId, liquidity_token_id: TokenId, amount: Balance) -> DispatchResult;
+	}
+}
+
+mockall::mock! {
+	pub ExternalPriceSource {}
+
+	impl ProvisionPriceSource<TokenId, Balance> for ExternalPriceSource {
+		fn valuate(token_id: TokenId, amount: Balance) -> Option<Balance>;
+		fn valuate_weight() -> Weight;
+	}
+}
+
+mockall::mock! {
+	pub ThirdPartyRewardsApi {}
+
+	impl ThirdPartyRewardsApi<AccountId, Balance, TokenId> for ThirdPartyRewardsApi {
+		fn reward_pool(sender: AccountId, pool: (TokenId, TokenId), token_id: TokenId, amount: Balance, sessions: u32) -> DispatchResult;
+		fn reward_pool_weight() -> Weight;
+	}
+}
+
 pub struct AssetRegistry;
 impl AssetRegistryApi<TokenId> for AssetRegistry {
 	fn enable_pool_creation(_assets: (TokenId, TokenId)) -> bool {
@@ -356,6 +389,8 @@ parameter_types! {
 	pub const BootstrapUpdateBuffer: BlockNumberFor<Test> = 10;
 	pub const DefaultBootstrapPromotedPoolWeight: u8 = 1u8;
 	pub const ClearStorageLimit: u32 = 10u32;
+	pub const MaxWhitelistProofLength: u32 = 20u32;
+	pub static MaxConcurrentBootstraps: u32 = 10u32;
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -370,8 +405,13 @@ impl pallet_bootstrap::Config for Test {
 	type VestingProvider = Vesting;
 	type RewardsApi = MockRewardsApi;
 	type ClearStorageLimit = ClearStorageLimit;
+	type MaxWhitelistProofLength = MaxWhitelistProofLength;
+	type MaxConcurrentBootstraps = MaxConcurrentBootstraps;
 	type WeightInfo = ();
 	type AssetRegistryApi = MockAssetRegistryApi;
+	type VestedLiquidityReserveApi = MockVestedLiquidityReserveApi;
+	type ExternalPriceSource = MockExternalPriceSource;
+	type ThirdPartyRewardsApi = MockThirdPartyRewardsApi;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -386,8 +426,13 @@ impl pallet_bootstrap::Config for Test {
 	type VestingProvider = Vesting;
 	type RewardsApi = ProofOfStake;
 	type ClearStorageLimit = ClearStorageLimit;
+	type MaxWhitelistProofLength = MaxWhitelistProofLength;
+	type MaxConcurrentBootstraps = MaxConcurrentBootstraps;
 	type WeightInfo = ();
 	type AssetRegistryApi = AssetRegistry;
+	type VestedLiquidityReserveApi = ();
+	type ExternalPriceSource = ();
+	type ThirdPartyRewardsApi = ();
 }
 
 parameter_types! {
@@ -50,7 +50,7 @@ fn jump_to_whitelist_phase() {
 	)
 	.unwrap();
 	Bootstrap::on_initialize(15_u32.into());
-	assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+	assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 }
 
 fn jump_to_public_phase() {
@@ -69,7 +69,7 @@ fn jump_to_public_phase() {
 	)
 	.unwrap();
 	Bootstrap::on_initialize(25_u32.into());
-	assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+	assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 }
 
 #[test]
@@ -107,6 +107,7 @@ fn test_event_is_published_after_successful_provision() {
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 1).unwrap();
 
 		let event = crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::Provisioned(
+			DEFAULT_BOOTSTRAP_ID,
 			MGAId::get(),
 			1,
 		));
@@ -124,8 +125,8 @@ fn test_dont_allow_for_ksm_donation_before_minimal_valuation_fro_mga_is_provided
 		jump_to_public_phase();
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 1).unwrap();
-		assert_eq!(1, Bootstrap::provisions(USER_ID, MGAId::get()));
-		assert_eq!((1, 0), Bootstrap::valuations());
+		assert_eq!(1, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())));
+		assert_eq!((1, 0), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
 
 		assert_err!(
 			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 1),
@@ -133,12 +134,12 @@ fn test_dont_allow_for_ksm_donation_before_minimal_valuation_fro_mga_is_provided
 		);
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 9999).unwrap();
-		assert_eq!(10000, Bootstrap::provisions(USER_ID, MGAId::get()));
-		assert_eq!((10000, 0), Bootstrap::valuations());
+		assert_eq!(10000, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())));
+		assert_eq!((10000, 0), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 1).unwrap();
-		assert_eq!(1, Bootstrap::provisions(USER_ID, KSMId::get()));
-		assert_eq!((10000, 1), Bootstrap::valuations());
+		assert_eq!(1, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())));
+		assert_eq!((10000, 1), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
 
 		assert_err!(
 			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 1),
@@ -155,16 +156,16 @@ fn test_donation_in_supported_tokens() {
 		jump_to_public_phase();
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 10000).unwrap();
-		assert_eq!(10000, Bootstrap::provisions(USER_ID, MGAId::get()));
+		assert_eq!(10000, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())));
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 1).unwrap();
-		assert_eq!(1, Bootstrap::provisions(USER_ID, KSMId::get()));
+		assert_eq!(1, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())));
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 20000).unwrap();
-		assert_eq!(30000, Bootstrap::provisions(USER_ID, MGAId::get()));
+		assert_eq!(30000, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())));
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 2).unwrap();
-		assert_eq!(3, Bootstrap::provisions(USER_ID, KSMId::get()));
+		assert_eq!(3, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())));
 	});
 }
 
@@ -287,7 +288,7 @@ fn test_prevent_provisions_in_finished_phase() {
 		)
 		.unwrap();
 
-		Phase::<Test>::put(BootstrapPhase::Finished);
+		Phase::<Test>::insert(DEFAULT_BOOTSTRAP_ID, BootstrapPhase::Finished);
 
 		assert_err!(
 			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), INITIAL_AMOUNT * 2),
@@ -304,7 +305,7 @@ fn test_prevent_non_whitelited_account_to_provision_in_whitelisted_phase() {
 
 		jump_to_whitelist_phase();
 
-		assert!(!Bootstrap::is_whitelisted(&USER_ID));
+		assert!(!Bootstrap::is_whitelisted(DEFAULT_BOOTSTRAP_ID, &USER_ID));
 		assert_err!(
 			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 1000),
 			Error::<Test>::Unauthorized
@@ -320,7 +321,7 @@ fn test_allow_non_whitelited_account_to_provision_in_whitelisted_phase_with_mga(
 
 		jump_to_whitelist_phase();
 
-		assert!(!Bootstrap::is_whitelisted(&USER_ID));
+		assert!(!Bootstrap::is_whitelisted(DEFAULT_BOOTSTRAP_ID, &USER_ID));
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 1000).unwrap();
 	});
 }
@@ -333,7 +334,9 @@ fn test_whitelist_account_deposit_event() {
 		Bootstrap::whitelist_accounts(RuntimeOrigin::root(), vec![USER_ID]).unwrap();
 
 		assert!(System::events().iter().any(|record| record.event ==
-			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::AccountsWhitelisted)));
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::AccountsWhitelisted(
+				DEFAULT_BOOTSTRAP_ID
+			))));
 	});
 }
 
@@ -677,7 +680,10 @@ fn test_bootstrap_can_be_modified_only_before_its_started() {
 			Error::<Test>::AlreadyStarted
 		);
 
-		assert_eq!(Some((100_u32.into(), 10_u32, 20_u32, DEFAULT_RATIO)), Bootstrap::config());
+		assert_eq!(
+			Some((100_u32.into(), 10_u32, 20_u32, DEFAULT_RATIO)),
+			Bootstrap::config(DEFAULT_BOOTSTRAP_ID)
+		);
 	});
 }
 
@@ -714,27 +720,27 @@ fn test_bootstrap_state_transitions() {
 
 		for i in 1..BOOTSTRAP_WHITELIST_START {
 			Bootstrap::on_initialize(i);
-			assert_eq!(Bootstrap::phase(), BootstrapPhase::BeforeStart);
+			assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::BeforeStart);
 		}
 
 		Bootstrap::on_initialize(BOOTSTRAP_WHITELIST_START);
-		assert_eq!(Bootstrap::phase(), BootstrapPhase::Whitelist);
+		assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Whitelist);
 
 		for i in BOOTSTRAP_WHITELIST_START..BOOTSTRAP_PUBLIC_START {
 			Bootstrap::on_initialize(i);
-			assert_eq!(Bootstrap::phase(), BootstrapPhase::Whitelist);
+			assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Whitelist);
 		}
 
 		Bootstrap::on_initialize(BOOTSTRAP_PUBLIC_START);
-		assert_eq!(Bootstrap::phase(), BootstrapPhase::Public);
+		assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Public);
 
 		for i in BOOTSTRAP_PUBLIC_START..BOOTSTRAP_FINISH {
 			Bootstrap::on_initialize(i);
-			assert_eq!(Bootstrap::phase(), BootstrapPhase::Public);
+			assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Public);
 		}
 
 		Bootstrap::on_initialize(BOOTSTRAP_FINISH);
-		assert_eq!(Bootstrap::phase(), BootstrapPhase::Finished);
+		assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Finished);
 	});
 }
 
@@ -768,9 +774,9 @@ fn test_bootstrap_state_transitions_when_on_initialized_is_not_called() {
 		)
 		.unwrap();
 
-		assert_eq!(Bootstrap::phase(), BootstrapPhase::BeforeStart);
+		assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::BeforeStart);
 		Bootstrap::on_initialize(200);
-		assert_eq!(Bootstrap::phase(), BootstrapPhase::Finished);
+		assert_eq!(Bootstrap::phase(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::Finished);
 	});
 }
 
@@ -917,10 +923,10 @@ fn test_cannot_claim_liquidity_tokens_when_bootstrap_is_not_finished() {
 		.unwrap();
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(110_u32.into());
-		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		assert_err!(
 			Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)),
@@ -971,18 +977,18 @@ fn test_rewards_are_distributed_properly_with_single_user() {
 		.unwrap();
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(110_u32.into());
-		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), MGA_PROVISON).unwrap();
 		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), KSM_PROVISON).unwrap();
 
 		Bootstrap::on_initialize(120_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
-		let (mga_valuation, ksm_valuation) = Bootstrap::valuations();
+		let (mga_valuation, ksm_valuation) = Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID);
 		let liquidity_token_id = *(liq_token_id.lock().unwrap());
 		let liquidity_token_amount = (mga_valuation + ksm_valuation) / 2;
 
@@ -990,13 +996,22 @@ fn test_rewards_are_distributed_properly_with_single_user() {
 			Bootstrap::balance(liquidity_token_id, Bootstrap::vault_address()),
 			liquidity_token_amount
 		);
-		assert_eq!(Bootstrap::minted_liquidity(), (liquidity_token_id, liquidity_token_amount));
+		assert_eq!(
+			Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID),
+			(liquidity_token_id, liquidity_token_amount)
+		);
 
 		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)).unwrap();
 
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, MGAId::get()), liquidity_token_amount / 2);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())),
+			liquidity_token_amount / 2
+		);
 
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, KSMId::get()), liquidity_token_amount / 2);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())),
+			liquidity_token_amount / 2
+		);
 
 		assert_eq!(
 			Bootstrap::balance(liquidity_token_id, USER_ID),
@@ -1014,11 +1029,19 @@ fn test_rewards_are_distributed_properly_with_multiple_user() {
 		set_up();
 
 		let provisioned_ev = |id, amount| {
-			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::Provisioned(id, amount))
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::Provisioned(
+				DEFAULT_BOOTSTRAP_ID,
+				id,
+				amount,
+			))
 		};
 
 		let rewards_claimed_ev = |id, amount| {
-			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::RewardsClaimed(id, amount))
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::RewardsClaimed(
+				DEFAULT_BOOTSTRAP_ID,
+				id,
+				amount,
+			))
 		};
 
 		const USER_KSM_PROVISON: Balance = 15;
@@ -1058,10 +1081,10 @@ fn test_rewards_are_distributed_properly_with_multiple_user() {
 		.unwrap();
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(110_u32.into());
-		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::transfer(MGAId::get(), USER_ID, ANOTHER_USER_ID, 500_000).unwrap();
 		Bootstrap::transfer(KSMId::get(), USER_ID, ANOTHER_USER_ID, 500_000).unwrap();
@@ -1098,9 +1121,9 @@ fn test_rewards_are_distributed_properly_with_multiple_user() {
 			.any(|record| record.event == provisioned_ev(KSMId::get(), ANOTHER_USER_KSM_PROVISON)));
 
 		Bootstrap::on_initialize(120_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
-		let (mga_valuation, ksm_valuation) = Bootstrap::valuations();
+		let (mga_valuation, ksm_valuation) = Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID);
 		assert_eq!(mga_valuation, 500_000);
 		assert_eq!(ksm_valuation, 35);
 		let liquidity_token_id = *(liq_token_id.lock().unwrap());
@@ -1110,12 +1133,21 @@ fn test_rewards_are_distributed_properly_with_multiple_user() {
 			Bootstrap::balance(liquidity_token_id, Bootstrap::vault_address()),
 			liquidity_token_amount
 		);
-		assert_eq!(Bootstrap::minted_liquidity(), (liquidity_token_id, liquidity_token_amount));
+		assert_eq!(
+			Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID),
+			(liquidity_token_id, liquidity_token_amount)
+		);
 
-		assert_eq!(Bootstrap::claimed_rewards(ANOTHER_USER_ID, MGAId::get()), 0);
-		assert_eq!(Bootstrap::claimed_rewards(ANOTHER_USER_ID, KSMId::get()), 0);
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, MGAId::get()), 0);
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, KSMId::get()), 0);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, MGAId::get())),
+			0
+		);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, KSMId::get())),
+			0
+		);
+		assert_eq!(Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())), 0);
+		assert_eq!(Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())), 0);
 
 		let user_expected_ksm_rewards =
 			liquidity_token_amount / 2 * USER_KSM_PROVISON / ksm_valuation;
@@ -1132,14 +1164,20 @@ fn test_rewards_are_distributed_properly_with_multiple_user() {
 		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)).unwrap();
 		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(ANOTHER_USER_ID)).unwrap();
 
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, MGAId::get()), user_expected_mga_rewards);
-		assert_eq!(Bootstrap::claimed_rewards(USER_ID, KSMId::get()), user_expected_ksm_rewards);
 		assert_eq!(
-			Bootstrap::claimed_rewards(ANOTHER_USER_ID, MGAId::get()),
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())),
+			user_expected_mga_rewards
+		);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())),
+			user_expected_ksm_rewards
+		);
+		assert_eq!(
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, MGAId::get())),
 			user2_expected_mga_rewards
 		);
 		assert_eq!(
-			Bootstrap::claimed_rewards(ANOTHER_USER_ID, KSMId::get()),
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, KSMId::get())),
 			user2_expected_ksm_rewards
 		);
 
@@ -1466,13 +1504,13 @@ fn multi_provisions_only_with_non_vested() {
 			(PROVISION_USER2_ID, MGAId::get(), 300_000),
 			(PROVISION_USER2_ID, KSMId::get(), 30),
 		]);
-		let (mga_valuation, ksm_valuation) = Bootstrap::valuations();
+		let (mga_valuation, ksm_valuation) = Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID);
 		let liq_token_minted = (mga_valuation + ksm_valuation) / 2;
 		assert_eq!(mga_valuation, 400_000);
 		assert_eq!(ksm_valuation, 40);
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID)).unwrap();
 		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER2_ID)).unwrap();
 
@@ -1748,7 +1786,7 @@ fn test_multi_provisions_only_with_non_vested(
 		provisions(provisions_list);
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		if user1_has_provisions {
 			Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID)).unwrap();
@@ -1759,7 +1797,7 @@ fn test_multi_provisions_only_with_non_vested(
 		}
 
 		// ASSERT
-		let (mga_valuation, ksm_valuation) = Bootstrap::valuations();
+		let (mga_valuation, ksm_valuation) = Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID);
 		assert_eq!(total_ksm_provision, ksm_valuation);
 		assert_eq!(total_mga_provision, mga_valuation);
 
@@ -1860,16 +1898,24 @@ fn test_restart_bootstrap() {
 
 		Bootstrap::pre_finalize(RuntimeOrigin::signed(YET_ANOTHER_USER_ID)).unwrap();
 		Bootstrap::finalize(RuntimeOrigin::signed(YET_ANOTHER_USER_ID)).unwrap();
-		assert!(Provisions::<Test>::iter_keys().next().is_none());
-		assert!(VestedProvisions::<Test>::iter_keys().next().is_none());
-		assert!(WhitelistedAccount::<Test>::iter_keys().next().is_none());
-		assert!(ClaimedRewards::<Test>::iter_keys().next().is_none());
-		assert!(ProvisionAccounts::<Test>::iter_keys().next().is_none());
-		assert_eq!(Valuations::<Test>::get(), (0, 0));
-		assert_eq!(Phase::<Test>::get(), BootstrapPhase::BeforeStart);
-		assert_eq!(BootstrapSchedule::<Test>::get(), None);
-		assert_eq!(MintedLiquidity::<Test>::get(), (0, 0));
-		assert_eq!(ActivePair::<Test>::get(), None);
+		assert!(Provisions::<Test>::iter_key_prefix((DEFAULT_BOOTSTRAP_ID,)).next().is_none());
+		assert!(VestedProvisions::<Test>::iter_key_prefix((DEFAULT_BOOTSTRAP_ID,))
+			.next()
+			.is_none());
+		assert!(WhitelistedAccount::<Test>::iter_key_prefix(DEFAULT_BOOTSTRAP_ID)
+			.next()
+			.is_none());
+		assert!(ClaimedRewards::<Test>::iter_key_prefix((DEFAULT_BOOTSTRAP_ID,))
+			.next()
+			.is_none());
+		assert!(ProvisionAccounts::<Test>::iter_key_prefix(DEFAULT_BOOTSTRAP_ID)
+			.next()
+			.is_none());
+		assert_eq!(Valuations::<Test>::get(DEFAULT_BOOTSTRAP_ID), (0, 0));
+		assert_eq!(Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID), BootstrapPhase::BeforeStart);
+		assert_eq!(BootstrapSchedule::<Test>::get(DEFAULT_BOOTSTRAP_ID), None);
+		assert_eq!(MintedLiquidity::<Test>::get(DEFAULT_BOOTSTRAP_ID), (0, 0));
+		assert_eq!(ActivePair::<Test>::get(DEFAULT_BOOTSTRAP_ID), None);
 
 		Bootstrap::schedule_bootstrap(
 			RuntimeOrigin::root(),
@@ -2072,7 +2118,7 @@ fn archive_previous_bootstrap_schedules() {
 		assert_eq!(0, Bootstrap::archived().len());
 		Bootstrap::pre_finalize(RuntimeOrigin::signed(YET_ANOTHER_USER_ID)).unwrap();
 		Bootstrap::finalize(RuntimeOrigin::signed(YET_ANOTHER_USER_ID)).unwrap();
-		assert_eq!(0, Bootstrap::provisions(USER_ID, KSMId::get()));
+		assert_eq!(0, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())));
 
 		assert_eq!(1, Bootstrap::archived().len());
 	})
@@ -2110,12 +2156,12 @@ fn test_activate_liq_tokens_is_called_with_all_liq_tokens_when_pool_is_promoted_
 			(PROVISION_USER1_ID, MGAId::get(), mga_provision),
 			(PROVISION_USER2_ID, KSMId::get(), ksm_provision),
 		]);
-		let (mga_valuation, ksm_valuation) = Bootstrap::valuations();
+		let (mga_valuation, ksm_valuation) = Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID);
 		assert_eq!(mga_valuation, 1_000_000);
 		assert_eq!(ksm_valuation, 100);
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::claim_and_activate_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID))
 			.unwrap();
@@ -2223,7 +2269,7 @@ fn test_dont_activate_liquidity_when_pool_is_not_promoted_and_provisions_are_not
 		]);
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::claim_and_activate_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID))
 			.unwrap();
@@ -2262,7 +2308,7 @@ fn test_claim_and_activate_doesnt_fail_when_tokens_activations_fails() {
 			(PROVISION_USER2_ID, KSMId::get(), ksm_provision),
 		]);
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		assert_ok!(Bootstrap::claim_and_activate_liquidity_tokens(RuntimeOrigin::signed(
 			PROVISION_USER1_ID
@@ -2313,13 +2359,13 @@ fn test_pool_is_promoted_if_scheduled_to() {
 		.unwrap();
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(110_u32.into());
-		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(120_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 	});
 }
 
@@ -2366,13 +2412,13 @@ fn test_pool_is_not_promoted_if_not_scheduled_to() {
 		.unwrap();
 
 		Bootstrap::on_initialize(100_u32.into());
-		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(110_u32.into());
-		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 
 		Bootstrap::on_initialize(120_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 	});
 }
 
@@ -2404,7 +2450,7 @@ fn test_pool_bootstrap_finalize_continues_if_asset_metadata_update_fails() {
 		.unwrap();
 
 		Bootstrap::on_initialize(120_u32.into());
-		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
 	});
 }
 
@@ -2442,3 +2488,1368 @@ fn provisions_not_allowed_in_maintenance_mode() {
 
 // 	});
 // }
+
+#[test]
+#[serial]
+fn test_multiple_bootstraps_are_processed_independently() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		const ANOTHER_BOOTSTRAP_ID: BootstrapId = 1;
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock.expect().times(2).returning(
+			move |addr, _, first_amount, _, second_amount| {
+				let issuance = (first_amount + second_amount) / 2;
+				let id = Bootstrap::create_new_token(&addr, issuance);
+				Some((id, issuance))
+			},
+		);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+		Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			ANOTHER_BOOTSTRAP_ID,
+			DUMMY_ID,
+			MGAId::get(),
+			110_u32.into(),
+			None,
+			20,
+			None,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		Bootstrap::on_initialize(110_u32.into());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(ANOTHER_BOOTSTRAP_ID));
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+		Bootstrap::provision_by_id(
+			RuntimeOrigin::signed(USER_ID),
+			ANOTHER_BOOTSTRAP_ID,
+			MGAId::get(),
+			50_000,
+		)
+		.unwrap();
+		Bootstrap::provision_by_id(
+			RuntimeOrigin::signed(USER_ID),
+			ANOTHER_BOOTSTRAP_ID,
+			DUMMY_ID,
+			5_000,
+		)
+		.unwrap();
+		assert_err!(
+			Bootstrap::provision_by_id(
+				RuntimeOrigin::signed(USER_ID),
+				ANOTHER_BOOTSTRAP_ID,
+				KSMId::get(),
+				1
+			),
+			Error::<Test>::UnsupportedTokenId
+		);
+
+		assert_eq!((100_000, 10), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!((50_000, 5_000), Bootstrap::valuations(ANOTHER_BOOTSTRAP_ID));
+		assert_eq!(5_000, Bootstrap::provisions((ANOTHER_BOOTSTRAP_ID, USER_ID, DUMMY_ID)));
+
+		Bootstrap::on_initialize(120_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(ANOTHER_BOOTSTRAP_ID));
+		assert_err!(
+			Bootstrap::claim_liquidity_tokens_by_id(
+				RuntimeOrigin::signed(USER_ID),
+				ANOTHER_BOOTSTRAP_ID
+			),
+			Error::<Test>::NotFinishedYet
+		);
+
+		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)).unwrap();
+		let (liq_token_id, liq_token_amount) = Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID);
+		assert_eq!(Bootstrap::balance(liq_token_id, USER_ID), liq_token_amount / 2 * 2);
+
+		Bootstrap::pre_finalize(RuntimeOrigin::signed(USER_ID)).unwrap();
+		Bootstrap::finalize(RuntimeOrigin::signed(USER_ID)).unwrap();
+		assert_eq!(BootstrapPhase::BeforeStart, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(5_000, Bootstrap::provisions((ANOTHER_BOOTSTRAP_ID, USER_ID, DUMMY_ID)));
+		assert!(BootstrapSchedule::<Test>::get(ANOTHER_BOOTSTRAP_ID).is_some());
+
+		Bootstrap::on_initialize(130_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(ANOTHER_BOOTSTRAP_ID));
+
+		Bootstrap::claim_liquidity_tokens_by_id(
+			RuntimeOrigin::signed(USER_ID),
+			ANOTHER_BOOTSTRAP_ID,
+		)
+		.unwrap();
+		let (another_liq_token_id, another_liq_token_amount) =
+			Bootstrap::minted_liquidity(ANOTHER_BOOTSTRAP_ID);
+		assert_ne!(liq_token_id, another_liq_token_id);
+		assert_eq!(
+			Bootstrap::balance(another_liq_token_id, USER_ID),
+			another_liq_token_amount / 2 * 2
+		);
+
+		Bootstrap::pre_finalize_by_id(RuntimeOrigin::signed(USER_ID), ANOTHER_BOOTSTRAP_ID)
+			.unwrap();
+		Bootstrap::finalize_by_id(RuntimeOrigin::signed(USER_ID), ANOTHER_BOOTSTRAP_ID).unwrap();
+		assert_eq!(BootstrapPhase::BeforeStart, Phase::<Test>::get(ANOTHER_BOOTSTRAP_ID));
+		assert!(BootstrapSchedule::<Test>::iter().next().is_none());
+		// one entry is added by set_up
+		assert_eq!(3, Bootstrap::archived().len());
+	});
+}
+
+#[test]
+#[serial]
+fn test_same_pair_cannot_be_bootstrapped_concurrently() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+
+		assert_err!(
+			Bootstrap::schedule_bootstrap_by_id(
+				RuntimeOrigin::root(),
+				1,
+				MGAId::get(),
+				KSMId::get(),
+				100_u32.into(),
+				Some(10),
+				10,
+				Some(DEFAULT_RATIO),
+				false,
+				None,
+				None,
+			),
+			Error::<Test>::PairAlreadyBootstrapped
+		);
+
+		assert_ok!(Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			200_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		));
+		assert!(Bootstrap::contains(&(MGAId::get(), KSMId::get())));
+		assert!(!Bootstrap::contains(&(MGAId::get(), DUMMY_ID)));
+	});
+}
+
+#[test]
+#[serial]
+fn test_number_of_concurrent_bootstraps_is_limited() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		const ANOTHER_BOOTSTRAP_ID: BootstrapId = 1;
+		MaxConcurrentBootstraps::set(2);
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+		Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			ANOTHER_BOOTSTRAP_ID,
+			DUMMY_ID,
+			MGAId::get(),
+			100_u32.into(),
+			None,
+			10,
+			None,
+			false,
+			None,
+			None,
+		)
+		.unwrap();
+
+		assert_err!(
+			Bootstrap::schedule_bootstrap_by_id(
+				RuntimeOrigin::root(),
+				ANOTHER_BOOTSTRAP_ID + 1,
+				DUMMY_ID,
+				KSMId::get(),
+				100_u32.into(),
+				None,
+				10,
+				None,
+				false,
+				None,
+				None,
+			),
+			Error::<Test>::TooManyBootstraps
+		);
+
+		// already scheduled bootstraps can still be updated
+		assert_ok!(Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			ANOTHER_BOOTSTRAP_ID,
+			DUMMY_ID,
+			MGAId::get(),
+			200_u32.into(),
+			None,
+			10,
+			None,
+			false,
+			None,
+			None,
+		));
+
+		Bootstrap::cancel_bootstrap_by_id(RuntimeOrigin::root(), ANOTHER_BOOTSTRAP_ID).unwrap();
+		assert_ok!(Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			ANOTHER_BOOTSTRAP_ID + 1,
+			DUMMY_ID,
+			KSMId::get(),
+			100_u32.into(),
+			None,
+			10,
+			None,
+			false,
+			None,
+			None,
+		));
+
+		MaxConcurrentBootstraps::set(10);
+	});
+}
+
+#[test]
+#[serial]
+fn migration_moves_bootstrap_state_under_default_id() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+		StorageVersion::new(2).put::<Bootstrap>();
+		migration::v3::Phase::<Test>::put(BootstrapPhase::Finished);
+		migration::v3::Valuations::<Test>::put((100, 10));
+		migration::v3::BootstrapSchedule::<Test>::put((100, 10, 10, DEFAULT_RATIO));
+		migration::v3::ActivePair::<Test>::put((KSMId::get(), MGAId::get()));
+		migration::v3::MintedLiquidity::<Test>::put((LIQ_TOKEN_ID, LIQ_TOKEN_AMOUNT));
+		migration::v3::PromoteBootstrapPool::<Test>::put(true);
+		migration::v3::Provisions::<Test>::insert(USER_ID, MGAId::get(), 100);
+		migration::v3::Provisions::<Test>::insert(ANOTHER_USER_ID, KSMId::get(), 10);
+		migration::v3::ClaimedRewards::<Test>::insert(ANOTHER_USER_ID, KSMId::get(), 5);
+		migration::v3::WhitelistedAccount::<Test>::insert(USER_ID, ());
+		migration::v3::ProvisionAccounts::<Test>::insert(USER_ID, ());
+
+		migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Bootstrap>(), 3);
+		assert!(!migration::v3::Phase::<Test>::exists());
+		assert!(!migration::v3::ActivePair::<Test>::exists());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!((100, 10), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(Some((100, 10, 10, DEFAULT_RATIO)), Bootstrap::config(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(Some((KSMId::get(), MGAId::get())), Bootstrap::pair(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(
+			(LIQ_TOKEN_ID, LIQ_TOKEN_AMOUNT),
+			Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID)
+		);
+		assert!(Bootstrap::get_promote_bootstrap_pool(DEFAULT_BOOTSTRAP_ID));
+
+		assert_eq!(2, Provisions::<Test>::iter().count());
+		assert_eq!(100, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get())));
+		assert_eq!(
+			10,
+			Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, KSMId::get()))
+		);
+		assert_eq!(
+			5,
+			Bootstrap::claimed_rewards((DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID, KSMId::get()))
+		);
+		assert!(Bootstrap::is_whitelisted(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+		assert!(ProvisionAccounts::<Test>::get(DEFAULT_BOOTSTRAP_ID, USER_ID).is_some());
+		assert!(ProvisionAccounts::<Test>::get(DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID).is_none());
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_fails_when_soft_cap_is_not_reached_and_provisions_are_refunded() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().times(0);
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock.expect().times(0);
+
+		Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+			Some((100, 1_000)),
+			None,
+		)
+		.unwrap();
+
+		Bootstrap::on_initialize(110_u32.into());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+		// vested provisions are not available through extrinsics
+		Bootstrap::transfer(MGAId::get(), USER_ID, Bootstrap::vault_address(), 1_000).unwrap();
+		VestedProvisions::<Test>::insert(
+			(DEFAULT_BOOTSTRAP_ID, USER_ID, MGAId::get()),
+			(1_000, 1, 200),
+		);
+
+		Bootstrap::on_initialize(120_u32.into());
+		assert_eq!(BootstrapPhase::Failed, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::BootstrapFailed(
+				DEFAULT_BOOTSTRAP_ID
+			))));
+
+		assert_err!(
+			Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)),
+			Error::<Test>::NotFinishedYet
+		);
+		assert_err!(
+			Bootstrap::pre_finalize(RuntimeOrigin::signed(USER_ID)),
+			Error::<Test>::BootstrapNotReadyToBeFinished
+		);
+
+		Bootstrap::claim_refund(RuntimeOrigin::signed(USER_ID), DEFAULT_BOOTSTRAP_ID).unwrap();
+		assert_eq!(INITIAL_AMOUNT, Bootstrap::balance(KSMId::get(), USER_ID));
+		assert_eq!(INITIAL_AMOUNT - 1_000, Bootstrap::balance(MGAId::get(), USER_ID));
+		assert_eq!(1_000, Bootstrap::locked_balance(MGAId::get(), USER_ID));
+		assert_err!(
+			Bootstrap::claim_refund(RuntimeOrigin::signed(USER_ID), DEFAULT_BOOTSTRAP_ID),
+			Error::<Test>::NothingToClaim
+		);
+
+		Bootstrap::pre_finalize(RuntimeOrigin::signed(USER_ID)).unwrap();
+		Bootstrap::finalize(RuntimeOrigin::signed(USER_ID)).unwrap();
+		assert_eq!(BootstrapPhase::BeforeStart, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(None, Bootstrap::soft_cap(DEFAULT_BOOTSTRAP_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_creates_pool_when_soft_cap_is_reached() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock.expect().times(1).return_const(POOL_CREATE_DUMMY_RETURN_VALUE);
+
+		Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+			Some((10, 100_000)),
+			None,
+		)
+		.unwrap();
+
+		Bootstrap::on_initialize(110_u32.into());
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+
+		Bootstrap::on_initialize(120_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_err!(
+			Bootstrap::claim_refund(RuntimeOrigin::signed(USER_ID), DEFAULT_BOOTSTRAP_ID),
+			Error::<Test>::BootstrapNotFailed
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_provision_with_proof_in_whitelist_phase() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_whitelist_phase();
+
+		let user_leaf = Bootstrap::whitelist_leaf(&USER_ID, Some(10));
+		let another_user_leaf = Bootstrap::whitelist_leaf(&ANOTHER_USER_ID, None);
+		let root = Bootstrap::compute_whitelist_root(user_leaf, &[another_user_leaf]);
+		assert_eq!(root, Bootstrap::compute_whitelist_root(another_user_leaf, &[user_leaf]));
+
+		Bootstrap::set_whitelist_root(RuntimeOrigin::root(), DEFAULT_BOOTSTRAP_ID, Some(root))
+			.unwrap();
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::WhitelistRootUpdated(
+				DEFAULT_BOOTSTRAP_ID,
+				Some(root)
+			))));
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 200_000).unwrap();
+		assert!(!Bootstrap::is_whitelisted(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+		Bootstrap::provision_with_proof(
+			RuntimeOrigin::signed(USER_ID),
+			DEFAULT_BOOTSTRAP_ID,
+			KSMId::get(),
+			10,
+			Some(10),
+			vec![another_user_leaf],
+		)
+		.unwrap();
+		assert_eq!(10, Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, USER_ID, KSMId::get())));
+		assert!(Bootstrap::provision_accounts(DEFAULT_BOOTSTRAP_ID, USER_ID).is_some());
+
+		assert_err!(
+			Bootstrap::provision_with_proof(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				1,
+				Some(10),
+				vec![another_user_leaf],
+			),
+			Error::<Test>::WhitelistCapExceeded
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_provision_with_invalid_proof_is_rejected() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_whitelist_phase();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+
+		let user_leaf = Bootstrap::whitelist_leaf(&USER_ID, Some(10));
+		let another_user_leaf = Bootstrap::whitelist_leaf(&ANOTHER_USER_ID, None);
+
+		assert_err!(
+			Bootstrap::provision_with_proof(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				10,
+				Some(10),
+				vec![another_user_leaf],
+			),
+			Error::<Test>::InvalidWhitelistProof
+		);
+
+		let root = Bootstrap::compute_whitelist_root(user_leaf, &[another_user_leaf]);
+		Bootstrap::set_whitelist_root(RuntimeOrigin::root(), DEFAULT_BOOTSTRAP_ID, Some(root))
+			.unwrap();
+
+		assert_err!(
+			Bootstrap::provision_with_proof(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				10,
+				Some(20),
+				vec![another_user_leaf],
+			),
+			Error::<Test>::InvalidWhitelistProof
+		);
+
+		assert_err!(
+			Bootstrap::provision_with_proof(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				10,
+				Some(10),
+				vec![another_user_leaf; MaxWhitelistProofLength::get() as usize + 1],
+			),
+			Error::<Test>::WhitelistProofTooLong
+		);
+
+		Bootstrap::set_whitelist_root(RuntimeOrigin::root(), DEFAULT_BOOTSTRAP_ID, None).unwrap();
+		assert_err!(
+			Bootstrap::provision_with_proof(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				10,
+				Some(10),
+				vec![another_user_leaf],
+			),
+			Error::<Test>::InvalidWhitelistProof
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_only_root_can_set_whitelist_root_of_scheduled_bootstrap() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		assert_err!(
+			Bootstrap::set_whitelist_root(
+				RuntimeOrigin::root(),
+				DEFAULT_BOOTSTRAP_ID,
+				Some(H256::zero())
+			),
+			Error::<Test>::BootstrapNotSchduled
+		);
+
+		jump_to_whitelist_phase();
+
+		assert_err!(
+			Bootstrap::set_whitelist_root(
+				RuntimeOrigin::signed(USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				Some(H256::zero())
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_provisions_are_limited_per_account() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_public_phase();
+		Bootstrap::transfer(MGAId::get(), USER_ID, ANOTHER_USER_ID, 500_000).unwrap();
+		Bootstrap::transfer(KSMId::get(), USER_ID, ANOTHER_USER_ID, 500_000).unwrap();
+
+		assert_eq!(
+			None,
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+		Bootstrap::set_provision_limits(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			Some(ProvisionLimits { per_account: Some(300_000), ..Default::default() }),
+		)
+		.unwrap();
+		assert_eq!(
+			Some(300_000),
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 200_000).unwrap();
+		assert_eq!(
+			Some(100_000),
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+
+		// 10 KSM are worth 200_000 MGA with current valuations
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10),
+			Error::<Test>::AccountProvisionCapExceeded
+		);
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		assert_eq!(
+			Some(0),
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 1),
+			Error::<Test>::AccountProvisionCapExceeded
+		);
+
+		Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), MGAId::get(), 300_000)
+			.unwrap();
+		assert_eq!(300_000, Bootstrap::account_provision_valuation(DEFAULT_BOOTSTRAP_ID, USER_ID));
+		assert_eq!(
+			300_000,
+			Bootstrap::account_provision_valuation(DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID)
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_large_first_token_provision_is_valuated_before_it_dilutes_valuations() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_public_phase();
+		Bootstrap::transfer(KSMId::get(), USER_ID, ANOTHER_USER_ID, 100).unwrap();
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 500_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 5).unwrap();
+		Bootstrap::set_provision_limits(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			Some(ProvisionLimits { per_account: Some(250_000), ..Default::default() }),
+		)
+		.unwrap();
+
+		// 5 KSM are worth 500_000 MGA before the provision, but only 250_000 MGA after it
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), KSMId::get(), 5),
+			Error::<Test>::AccountProvisionCapExceeded
+		);
+		assert_eq!((500_000, 5), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), KSMId::get(), 2).unwrap();
+		assert_eq!(
+			200_000,
+			Bootstrap::account_provision_valuation(DEFAULT_BOOTSTRAP_ID, ANOTHER_USER_ID)
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_provisions_are_limited_per_phase() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_whitelist_phase();
+		Bootstrap::transfer(MGAId::get(), USER_ID, ANOTHER_USER_ID, 500_000).unwrap();
+
+		Bootstrap::set_provision_limits(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			Some(ProvisionLimits {
+				per_account: Some(400_000),
+				whitelist_phase: Some(100_000),
+				public_phase: Some(500_000),
+			}),
+		)
+		.unwrap();
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(
+				crate::Event::<Test>::ProvisionLimitsUpdated(DEFAULT_BOOTSTRAP_ID)
+			)));
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 60_000).unwrap();
+		assert_eq!(
+			Some(40_000),
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &ANOTHER_USER_ID)
+		);
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), MGAId::get(), 50_000),
+			Error::<Test>::PhaseProvisionCapExceeded
+		);
+		Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), MGAId::get(), 40_000).unwrap();
+
+		Bootstrap::on_initialize(25_u32.into());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(
+			Some(340_000),
+			Bootstrap::get_remaining_provision_allowance(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 340_000).unwrap();
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), MGAId::get(), 160_001),
+			Error::<Test>::PhaseProvisionCapExceeded
+		);
+		Bootstrap::provision(RuntimeOrigin::signed(ANOTHER_USER_ID), MGAId::get(), 160_000)
+			.unwrap();
+		assert_eq!((100_000, 500_000), Bootstrap::phase_provision_valuation(DEFAULT_BOOTSTRAP_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_state_and_claimable_liquidity_helpers() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock
+			.expect()
+			.times(1)
+			.returning(move |addr, _, ksm_amount, _, mga_amount| {
+				let issuance = (ksm_amount + mga_amount) / 2;
+				let id = Bootstrap::create_new_token(&addr, issuance);
+				Some((id, issuance))
+			});
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			20,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+		assert_eq!(
+			(100, 110, 130),
+			Bootstrap::phase_blocks(&Bootstrap::config(DEFAULT_BOOTSTRAP_ID).unwrap())
+		);
+
+		Bootstrap::on_initialize(110_u32.into());
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_010).unwrap();
+		assert_eq!(None, Bootstrap::implied_price(DEFAULT_BOOTSTRAP_ID));
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+		assert_eq!(Some(10_001 * PRICE_PRECISION), Bootstrap::implied_price(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!((0, 0), Bootstrap::get_claimable_liquidity(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+
+		Bootstrap::on_initialize(130_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		let (_, issuance) = Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID);
+		assert_eq!(
+			(issuance, 0),
+			Bootstrap::get_claimable_liquidity(DEFAULT_BOOTSTRAP_ID, &USER_ID)
+		);
+		assert_eq!(0, Bootstrap::get_claimed_liquidity(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+
+		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(USER_ID)).unwrap();
+		assert_eq!((0, 0), Bootstrap::get_claimable_liquidity(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+		assert_eq!(issuance, Bootstrap::get_claimed_liquidity(DEFAULT_BOOTSTRAP_ID, &USER_ID));
+	});
+}
+
+fn schedule_bootstrap_with_liquidity_vesting(promote_bootstrap_pool: bool) {
+	let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+	pool_exists_mock.expect().return_const(false);
+
+	Bootstrap::schedule_bootstrap_by_id(
+		RuntimeOrigin::root(),
+		DEFAULT_BOOTSTRAP_ID,
+		KSMId::get(),
+		MGAId::get(),
+		10_u32.into(),
+		Some(10),
+		10,
+		Some(DEFAULT_RATIO),
+		promote_bootstrap_pool,
+		None,
+		Some(100),
+	)
+	.unwrap();
+	assert_eq!(Some(100), Bootstrap::liquidity_vesting_period(DEFAULT_BOOTSTRAP_ID));
+	Bootstrap::on_initialize(25_u32.into());
+	assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+}
+
+#[test]
+#[serial]
+fn test_liquidity_vesting_period_cannot_be_zero() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		assert_err!(
+			Bootstrap::schedule_bootstrap_by_id(
+				RuntimeOrigin::root(),
+				DEFAULT_BOOTSTRAP_ID,
+				KSMId::get(),
+				MGAId::get(),
+				10_u32.into(),
+				Some(10),
+				10,
+				Some(DEFAULT_RATIO),
+				false,
+				None,
+				Some(0),
+			),
+			Error::<Test>::VestingPeriodCannotBeZero
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_claimed_liquidity_is_vested_when_liquidity_vesting_period_is_set() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		schedule_bootstrap_with_liquidity_vesting(false);
+		init_mocks!();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		provisions(vec![
+			(PROVISION_USER1_ID, MGAId::get(), 1_000_000),
+			(PROVISION_USER2_ID, KSMId::get(), 100),
+		]);
+
+		Bootstrap::on_initialize(100_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+
+		let (liq_token_id, issuance) = Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID);
+		let expected_rewards = issuance / 2;
+
+		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID)).unwrap();
+		Bootstrap::claim_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER2_ID)).unwrap();
+
+		assert_eq!(expected_rewards, Bootstrap::balance(liq_token_id, PROVISION_USER1_ID));
+		assert_eq!(expected_rewards, Bootstrap::locked_balance(liq_token_id, PROVISION_USER1_ID));
+		assert_eq!(expected_rewards, Bootstrap::balance(liq_token_id, PROVISION_USER2_ID));
+		assert_eq!(expected_rewards, Bootstrap::locked_balance(liq_token_id, PROVISION_USER2_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_claimable_liquidity_is_vested_when_liquidity_vesting_period_is_set() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		schedule_bootstrap_with_liquidity_vesting(false);
+		init_mocks!();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		provisions(vec![
+			(PROVISION_USER1_ID, MGAId::get(), 1_000_000),
+			(PROVISION_USER2_ID, KSMId::get(), 100),
+		]);
+
+		Bootstrap::on_initialize(100_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+
+		let (_, issuance) = Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID);
+		let expected_rewards = issuance / 2;
+
+		// bootstrap finished at block 30, vesting ends at block 130
+		System::set_block_number(129);
+		assert_eq!(
+			(0, expected_rewards),
+			Bootstrap::get_claimable_liquidity(DEFAULT_BOOTSTRAP_ID, &PROVISION_USER1_ID)
+		);
+
+		System::set_block_number(130);
+		assert_eq!(
+			(expected_rewards, 0),
+			Bootstrap::get_claimable_liquidity(DEFAULT_BOOTSTRAP_ID, &PROVISION_USER2_ID)
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_vested_liquidity_is_reserved_before_activation() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		schedule_bootstrap_with_liquidity_vesting(true);
+		init_mocks!();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		let enable_mock = MockRewardsApi::enable_context();
+		enable_mock.expect().return_const(());
+
+		let is_enabled_mock = MockRewardsApi::is_enabled_context();
+		is_enabled_mock.expect().return_const(true);
+
+		provisions(vec![(PROVISION_USER1_ID, MGAId::get(), 1_000_000)]);
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 100).unwrap();
+
+		Bootstrap::on_initialize(100_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+
+		let (_, issuance) = Bootstrap::minted_liquidity(DEFAULT_BOOTSTRAP_ID);
+		let expected_rewards = issuance / 2;
+
+		let reserve_mock = MockVestedLiquidityReserveApi::reserve_vested_liquidity_context();
+		reserve_mock.expect().times(1).returning(move |who, _, amount| {
+			assert_eq!(PROVISION_USER1_ID, *who);
+			assert_eq!(expected_rewards, amount);
+			Ok(())
+		});
+
+		let activate_liquidity = MockRewardsApi::activate_liquidity_context();
+		activate_liquidity.expect().times(1).returning(move |who, _, amount, kind| {
+			assert_eq!(PROVISION_USER1_ID, who);
+			assert_eq!(expected_rewards, amount);
+			assert!(matches!(kind, Some(ActivateKind::UnspentReserves)));
+			Ok(())
+		});
+
+		Bootstrap::claim_and_activate_liquidity_tokens(RuntimeOrigin::signed(PROVISION_USER1_ID))
+			.unwrap();
+	});
+}
+
+#[test]
+#[serial]
+fn test_first_token_can_be_provisioned_first_with_external_price_source() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			10_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+		Bootstrap::set_valuation_source(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			PriceSource::External,
+		)
+		.unwrap();
+		Bootstrap::set_provision_limits(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			Some(ProvisionLimits { per_account: Some(1_000), ..Default::default() }),
+		)
+		.unwrap();
+
+		Bootstrap::on_initialize(25_u32.into());
+		assert_eq!(BootstrapPhase::Public, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_err!(
+			Bootstrap::set_valuation_source(
+				RuntimeOrigin::root(),
+				DEFAULT_BOOTSTRAP_ID,
+				PriceSource::Provisions,
+			),
+			Error::<Test>::AlreadyStarted
+		);
+
+		let valuate_mock = MockExternalPriceSource::valuate_context();
+		valuate_mock.expect().returning(|token_id, amount| {
+			if token_id == KSMId::get() {
+				Some(amount * 100)
+			} else {
+				None
+			}
+		});
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 5).unwrap();
+		assert_eq!((0, 5), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(500, AccountProvisionValuation::<Test>::get(DEFAULT_BOOTSTRAP_ID, USER_ID));
+
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 6),
+			Error::<Test>::AccountProvisionCapExceeded
+		);
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100),
+			Error::<Test>::TokenNotValuated
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_fails_when_only_one_token_is_provisioned_with_external_price_source() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock.expect().times(0);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			10_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+		)
+		.unwrap();
+		Bootstrap::set_valuation_source(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			PriceSource::External,
+		)
+		.unwrap();
+
+		let valuate_mock = MockExternalPriceSource::valuate_context();
+		valuate_mock.expect().returning(|_, amount| Some(amount * 100));
+
+		Bootstrap::on_initialize(25_u32.into());
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 5).unwrap();
+		assert_eq!((0, 5), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::on_initialize(100_u32.into());
+		assert_eq!(BootstrapPhase::Failed, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::BootstrapFailed(
+				DEFAULT_BOOTSTRAP_ID
+			))));
+
+		Bootstrap::claim_refund(RuntimeOrigin::signed(USER_ID), DEFAULT_BOOTSTRAP_ID).unwrap();
+		assert_eq!(INITIAL_AMOUNT, Bootstrap::balance(KSMId::get(), USER_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_fails_when_pool_cannot_be_created() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_public_phase();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock.expect().times(1).return_const(None);
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+
+		Bootstrap::on_initialize(100_u32.into());
+		assert_eq!(BootstrapPhase::Failed, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert!(!MintedLiquidity::<Test>::contains_key(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::claim_refund(RuntimeOrigin::signed(USER_ID), DEFAULT_BOOTSTRAP_ID).unwrap();
+		assert_eq!(INITIAL_AMOUNT, Bootstrap::balance(KSMId::get(), USER_ID));
+		assert_eq!(INITIAL_AMOUNT, Bootstrap::balance(MGAId::get(), USER_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_rewards_are_scheduled_when_pool_is_created() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		jump_to_public_phase();
+		init_mocks!();
+
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		Bootstrap::fund_bootstrap_rewards(
+			RuntimeOrigin::signed(USER_ID),
+			DEFAULT_BOOTSTRAP_ID,
+			DUMMY_ID,
+			1_000,
+			10,
+		)
+		.unwrap();
+		Bootstrap::fund_bootstrap_rewards(
+			RuntimeOrigin::signed(USER_ID),
+			DEFAULT_BOOTSTRAP_ID,
+			DUMMY_ID,
+			1_000,
+			20,
+		)
+		.unwrap();
+		assert_eq!(
+			Some((USER_ID, DUMMY_ID, 2_000, 20)),
+			Bootstrap::bootstrap_rewards(DEFAULT_BOOTSTRAP_ID)
+		);
+		assert_eq!(2_000, Bootstrap::balance(DUMMY_ID, Bootstrap::vault_address()));
+
+		Bootstrap::transfer(DUMMY_ID, USER_ID, ANOTHER_USER_ID, 100).unwrap();
+		assert_err!(
+			Bootstrap::fund_bootstrap_rewards(
+				RuntimeOrigin::signed(ANOTHER_USER_ID),
+				DEFAULT_BOOTSTRAP_ID,
+				DUMMY_ID,
+				100,
+				10,
+			),
+			Error::<Test>::BootstrapRewardsAlreadyFunded
+		);
+
+		let reward_pool_mock = MockThirdPartyRewardsApi::reward_pool_context();
+		reward_pool_mock
+			.expect()
+			.times(1)
+			.returning(|sender, pool, token_id, amount, sessions| {
+				assert_eq!(Bootstrap::vault_address(), sender);
+				assert_eq!((KSMId::get(), MGAId::get()), pool);
+				assert_eq!(DUMMY_ID, token_id);
+				assert_eq!(2_000, amount);
+				assert_eq!(20, sessions);
+				Ok(())
+			});
+		let reward_pool_weight_mock = MockThirdPartyRewardsApi::reward_pool_weight_context();
+		reward_pool_weight_mock.expect().return_const(Weight::from_parts(1_000_000, 0));
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 10).unwrap();
+
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		assert_eq!(
+			db_weight
+				.reads(1)
+				.saturating_add(db_weight.reads_writes(27, 21))
+				.saturating_add(db_weight.reads_writes(3, 3))
+				.saturating_add(Weight::from_parts(1_000_000, 0)),
+			Bootstrap::on_initialize(100_u32.into())
+		);
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(None, Bootstrap::bootstrap_rewards(DEFAULT_BOOTSTRAP_ID));
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(
+				crate::Event::<Test>::BootstrapRewardsScheduled(
+					DEFAULT_BOOTSTRAP_ID,
+					DUMMY_ID,
+					2_000
+				)
+			)));
+	});
+}
+
+#[test]
+#[serial]
+fn test_bootstrap_rewards_are_refunded_when_bootstrap_fails() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		Bootstrap::schedule_bootstrap_by_id(
+			RuntimeOrigin::root(),
+			DEFAULT_BOOTSTRAP_ID,
+			KSMId::get(),
+			MGAId::get(),
+			100_u32.into(),
+			Some(10),
+			10,
+			Some(DEFAULT_RATIO),
+			false,
+			Some((100, 1_000)),
+			None,
+		)
+		.unwrap();
+
+		Bootstrap::fund_bootstrap_rewards(
+			RuntimeOrigin::signed(USER_ID),
+			DEFAULT_BOOTSTRAP_ID,
+			DUMMY_ID,
+			1_000,
+			10,
+		)
+		.unwrap();
+		assert_eq!(INITIAL_AMOUNT - 1_000, Bootstrap::balance(DUMMY_ID, USER_ID));
+
+		Bootstrap::on_initialize(110_u32.into());
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 100).unwrap();
+
+		Bootstrap::on_initialize(120_u32.into());
+		assert_eq!(BootstrapPhase::Failed, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(None, Bootstrap::bootstrap_rewards(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(INITIAL_AMOUNT, Bootstrap::balance(DUMMY_ID, USER_ID));
+	});
+}
+
+fn schedule_auction(sale_amount: Balance, start_price: Balance, reserve_price: Balance) {
+	let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+	pool_exists_mock.expect().return_const(false);
+
+	Bootstrap::schedule_bootstrap(
+		RuntimeOrigin::root(),
+		KSMId::get(),
+		MGAId::get(),
+		10_u32.into(),
+		Some(10),
+		10,
+		None,
+		false,
+	)
+	.unwrap();
+	Bootstrap::set_auction_params(
+		RuntimeOrigin::root(),
+		DEFAULT_BOOTSTRAP_ID,
+		Some(AuctionParams {
+			sale_amount,
+			start_price: start_price * PRICE_PRECISION,
+			reserve_price: reserve_price * PRICE_PRECISION,
+		}),
+	)
+	.unwrap();
+	Bootstrap::whitelist_accounts(RuntimeOrigin::root(), vec![USER_ID]).unwrap();
+
+	System::set_block_number(15);
+	Bootstrap::on_initialize(15_u32.into());
+	assert_eq!(BootstrapPhase::Whitelist, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+}
+
+#[test]
+#[serial]
+fn test_auction_params_are_validated() {
+	new_test_ext().execute_with(|| {
+		set_up();
+
+		let pool_exists_mock = MockPoolCreateApi::pool_exists_context();
+		pool_exists_mock.expect().return_const(false);
+
+		Bootstrap::schedule_bootstrap(
+			RuntimeOrigin::root(),
+			KSMId::get(),
+			MGAId::get(),
+			10_u32.into(),
+			Some(10),
+			10,
+			None,
+			false,
+		)
+		.unwrap();
+
+		assert_err!(
+			Bootstrap::set_auction_params(
+				RuntimeOrigin::root(),
+				DEFAULT_BOOTSTRAP_ID,
+				Some(AuctionParams { sale_amount: 100, start_price: 10, reserve_price: 20 }),
+			),
+			Error::<Test>::InvalidAuctionParams
+		);
+		assert_err!(
+			Bootstrap::set_auction_params(
+				RuntimeOrigin::root(),
+				DEFAULT_BOOTSTRAP_ID,
+				Some(AuctionParams { sale_amount: 0, start_price: 20, reserve_price: 10 }),
+			),
+			Error::<Test>::InvalidAuctionParams
+		);
+	});
+}
+
+#[test]
+#[serial]
+fn test_auction_is_sold_out_at_descending_price_and_excess_bid_is_not_collected() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		schedule_auction(100, 20, 10);
+
+		let pool_create_mock = MockPoolCreateApi::pool_create_context();
+		pool_create_mock
+			.expect()
+			.times(1)
+			.returning(move |addr, _, ksm_amount, _, mga_amount| {
+				assert_eq!(100, ksm_amount);
+				assert_eq!(1_500, mga_amount);
+				let issuance = (ksm_amount + mga_amount) / 2;
+				let id = Bootstrap::create_new_token(&addr, issuance);
+				Some((id, issuance))
+			});
+		let enable_pool_creation_mock = MockAssetRegistryApi::enable_pool_creation_context();
+		enable_pool_creation_mock.expect().return_const(true);
+
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 101),
+			Error::<Test>::AuctionSaleAmountExceeded
+		);
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 100).unwrap();
+
+		assert_eq!(Some(20 * PRICE_PRECISION), Bootstrap::auction_price(DEFAULT_BOOTSTRAP_ID));
+		provisions(vec![(PROVISION_USER1_ID, MGAId::get(), 500)]);
+
+		System::set_block_number(25);
+		Bootstrap::on_initialize(25_u32.into());
+		assert_eq!(Some(15 * PRICE_PRECISION), Bootstrap::auction_price(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::transfer(MGAId::get(), USER_ID, PROVISION_USER2_ID, 2_000).unwrap();
+		Bootstrap::provision(RuntimeOrigin::signed(PROVISION_USER2_ID), MGAId::get(), 2_000)
+			.unwrap();
+		assert_eq!(1_000, Bootstrap::balance(MGAId::get(), PROVISION_USER2_ID));
+		assert_eq!(
+			1_000,
+			Bootstrap::provisions((DEFAULT_BOOTSTRAP_ID, PROVISION_USER2_ID, MGAId::get()))
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::mock::RuntimeEvent::Bootstrap(crate::Event::<Test>::Provisioned(
+				DEFAULT_BOOTSTRAP_ID,
+				MGAId::get(),
+				1_000
+			))));
+
+		assert_err!(
+			Bootstrap::provision(RuntimeOrigin::signed(USER_ID), MGAId::get(), 1),
+			Error::<Test>::AuctionSoldOut
+		);
+
+		Bootstrap::on_initialize(30_u32.into());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!((1_500, 100), Bootstrap::valuations(DEFAULT_BOOTSTRAP_ID));
+		assert_eq!(Some(15 * PRICE_PRECISION), Bootstrap::implied_price(DEFAULT_BOOTSTRAP_ID));
+	});
+}
+
+#[test]
+#[serial]
+fn test_auction_fails_when_not_sold_out() {
+	new_test_ext().execute_with(|| {
+		set_up();
+		schedule_auction(100, 20, 10);
+
+		Bootstrap::provision(RuntimeOrigin::signed(USER_ID), KSMId::get(), 100).unwrap();
+		provisions(vec![(PROVISION_USER1_ID, MGAId::get(), 500)]);
+
+		Bootstrap::on_initialize(30_u32.into());
+		assert_eq!(BootstrapPhase::Failed, Phase::<Test>::get(DEFAULT_BOOTSTRAP_ID));
+
+		Bootstrap::claim_refund(RuntimeOrigin::signed(PROVISION_USER1_ID), DEFAULT_BOOTSTRAP_ID)
+			.unwrap();
+		assert_eq!(500, Bootstrap::balance(MGAId::get(), PROVISION_USER1_ID));
+	});
+}
@@ -57,6 +57,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn schedule_bootstrap() -> Weight;
 	fn provision() -> Weight;
+	fn provision_with_proof(p: u32, ) -> Weight;
 	// fn provision_vested() -> Weight;
 	fn claim_and_activate_liquidity_tokens() -> Weight;
 	fn finalize() -> Weight;
@@ -70,10 +71,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
 	fn provision() -> Weight {
-		Weight::from_parts(89_870_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(9 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+		Weight::from_parts(98_370_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Estimate, not generated by the benchmark CLI yet: cost of `provision` with the
+	// whitelist root read and hashing of `p` proof nodes on top of it
+	fn provision_with_proof(p: u32, ) -> Weight {
+		Weight::from_parts(99_724_000, 0)
+			.saturating_add(Weight::from_parts(1_482_611, 0).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	// fn provision_vested() -> Weight {
 	// 	Weight::from_parts(183_414_000, 0)
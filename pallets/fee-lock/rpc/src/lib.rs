@@ -0,0 +1,230 @@
+// Copyright (C) 2021 Mangata team
+
+use codec::Codec;
+pub use fee_lock_runtime_api::FeeLockApi as FeeLockRuntimeApi;
+use fee_lock_runtime_api::{RpcAccountFeeLockData, RpcFeeLockPreview};
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait FeeLockApi<BlockHash, Balance, TokenId, AccountId, BlockNumber> {
+	/// Returns fee lock state of given account
+	///
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "feelock_get_account_fee_lock_data")]
+	fn get_account_fee_lock_data(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcAccountFeeLockData<NumberOrHex, TokenId, BlockNumber>>;
+
+	/// Returns block from which fee lock of given account can be unlocked
+	///
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "feelock_get_unlockable_at")]
+	fn get_unlockable_at(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<BlockNumber>>;
+
+	/// Previews effect of `sell_asset` (path of 2 tokens) or `multiswap_sell_asset` on the fee
+	/// lock of given account
+	///
+	/// * `account` - user account address
+	/// * `path` - list of swapped tokens
+	/// * `sold_asset_amount` - amount of sold token
+	/// * `min_amount_out` - minimal amount of bought token
+	/// * `at` - optional block hash
+	#[method(name = "feelock_preview_sell_asset")]
+	fn preview_sell_asset(
+		&self,
+		account: AccountId,
+		path: Vec<TokenId>,
+		sold_asset_amount: NumberOrHex,
+		min_amount_out: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcFeeLockPreview<NumberOrHex, TokenId>>;
+
+	/// Previews effect of `buy_asset` (path of 2 tokens) or `multiswap_buy_asset` on the fee
+	/// lock of given account
+	///
+	/// * `account` - user account address
+	/// * `path` - list of swapped tokens
+	/// * `bought_asset_amount` - amount of bought token
+	/// * `max_amount_in` - maximal amount of sold token
+	/// * `at` - optional block hash
+	#[method(name = "feelock_preview_buy_asset")]
+	fn preview_buy_asset(
+		&self,
+		account: AccountId,
+		path: Vec<TokenId>,
+		bought_asset_amount: NumberOrHex,
+		max_amount_in: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcFeeLockPreview<NumberOrHex, TokenId>>;
+}
+
+pub struct FeeLock<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, P> FeeLock<C, P> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+trait TryIntoBalance<Balance> {
+	fn try_into_balance(self) -> RpcResult<Balance>;
+}
+
+impl<T: TryFrom<U256>> TryIntoBalance<T> for NumberOrHex {
+	fn try_into_balance(self) -> RpcResult<T> {
+		self.into_u256().try_into().or(Err(JsonRpseeError::Call(CallError::Custom(
+			ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(String::from("input parameter doesnt fit into u128")),
+			),
+		))))
+	}
+}
+
+#[async_trait]
+impl<C, Block, Balance, TokenId, AccountId, BlockNumber>
+	FeeLockApiServer<<Block as BlockT>::Hash, Balance, TokenId, AccountId, BlockNumber>
+	for FeeLock<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: FeeLockRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + TryFrom<U256> + Into<NumberOrHex>,
+	TokenId: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeDisplay + MaybeFromStr,
+	BlockNumber: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_account_fee_lock_data(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcAccountFeeLockData<NumberOrHex, TokenId, BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_account_fee_lock_data(at, account)
+			.map(|data| RpcAccountFeeLockData {
+				total_fee_lock_amount: data.total_fee_lock_amount.into(),
+				lock_token: data.lock_token,
+				last_fee_lock_block: data.last_fee_lock_block,
+				swaps_in_period: data.swaps_in_period,
+				unlockable_at: data.unlockable_at,
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
+
+	fn get_unlockable_at(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_unlockable_at(at, account).map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn preview_sell_asset(
+		&self,
+		account: AccountId,
+		path: Vec<TokenId>,
+		sold_asset_amount: NumberOrHex,
+		min_amount_out: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcFeeLockPreview<NumberOrHex, TokenId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.preview_sell_asset(
+			at,
+			account,
+			path,
+			sold_asset_amount.try_into_balance()?,
+			min_amount_out.try_into_balance()?,
+		)
+		.map(|preview| RpcFeeLockPreview {
+			action: preview.action,
+			lock_token: preview.lock_token,
+			lock_amount: preview.lock_amount.into(),
+			total_locked: preview.total_locked.into(),
+			unlocked_amount: preview.unlocked_amount.into(),
+		})
+		.map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn preview_buy_asset(
+		&self,
+		account: AccountId,
+		path: Vec<TokenId>,
+		bought_asset_amount: NumberOrHex,
+		max_amount_in: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcFeeLockPreview<NumberOrHex, TokenId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.preview_buy_asset(
+			at,
+			account,
+			path,
+			bought_asset_amount.try_into_balance()?,
+			max_amount_in.try_into_balance()?,
+		)
+		.map(|preview| RpcFeeLockPreview {
+			action: preview.action,
+			lock_token: preview.lock_token,
+			lock_amount: preview.lock_amount.into(),
+			total_locked: preview.total_locked.into(),
+			unlocked_amount: preview.unlocked_amount.into(),
+		})
+		.map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2021 Mangata team
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::vec::Vec;
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcAccountFeeLockData<Balance, TokenId, BlockNumber> {
+	pub total_fee_lock_amount: Balance,
+	/// token in which the lock is reserved
+	pub lock_token: TokenId,
+	pub last_fee_lock_block: BlockNumber,
+	/// number of low value swaps made within the current lock period
+	pub swaps_in_period: u32,
+	/// block from which the lock can be unlocked, none if nothing is locked
+	pub unlockable_at: Option<BlockNumber>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RpcFeeLockAction {
+	/// fee locks are not initialized, regular transaction fee is charged
+	FeeCharged,
+	/// low value swap, native (or curated) tokens are locked
+	Lock,
+	/// high value swap, previous lock is unlocked if its period has passed
+	Unlock,
+	/// transaction would be rejected, e.g. swap prevalidation fails or lock cannot be reserved
+	Rejected,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcFeeLockPreview<Balance, TokenId> {
+	pub action: RpcFeeLockAction,
+	/// token that is locked or unlocked, none if the lock is not affected
+	pub lock_token: Option<TokenId>,
+	/// amount that is additionally locked by the swap
+	pub lock_amount: Balance,
+	/// total amount locked after the swap
+	pub total_locked: Balance,
+	/// amount unlocked by the swap
+	pub unlocked_amount: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeLockApi<Balance, TokenId, AccountId, BlockNumber> where
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+		TokenId: Codec + MaybeDisplay + MaybeFromStr,
+		AccountId: Codec + MaybeDisplay + MaybeFromStr,
+		BlockNumber: Codec + MaybeDisplay + MaybeFromStr,{
+
+		fn get_account_fee_lock_data(
+			user: AccountId,
+		) -> RpcAccountFeeLockData<Balance, TokenId, BlockNumber>;
+
+		fn get_unlockable_at(
+			user: AccountId,
+		) -> Option<BlockNumber>;
+
+		fn preview_sell_asset(
+			user: AccountId,
+			path: Vec<TokenId>,
+			sold_asset_amount: Balance,
+			min_amount_out: Balance,
+		) -> RpcFeeLockPreview<Balance, TokenId>;
+
+		fn preview_buy_asset(
+			user: AccountId,
+			path: Vec<TokenId>,
+			bought_asset_amount: Balance,
+			max_amount_in: Balance,
+		) -> RpcFeeLockPreview<Balance, TokenId>;
+	}
+}
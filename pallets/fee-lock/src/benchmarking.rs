@@ -37,15 +37,22 @@ benchmarks! {
 		let fee_lock_amount: BalanceOf<T> = 1000_u32.into();
 		let swap_value_threshold: BalanceOf<T> = 1000_u32.into();
 		let mut whitelisted_tokens: Vec<(CurrencyIdOf<T>, bool)> = Vec::new();
+		let mut swap_value_thresholds: Vec<(CurrencyIdOf<T>, Option<BalanceOf<T>>)> = Vec::new();
 		for i in 0..<T as Config>::MaxCuratedTokens::get() {
 			whitelisted_tokens.push((i.into(), true));
+			swap_value_thresholds.push((i.into(), Some(swap_value_threshold)));
 		}
-	}: {assert_ok!(FeeLock::<T>::update_fee_lock_metadata(RawOrigin::Root.into(), Some(period_length), Some(fee_lock_amount), Some(swap_value_threshold), Some(whitelisted_tokens)));}
+		let mut fee_lock_tiers: Vec<(u32, BalanceOf<T>)> = Vec::new();
+		for i in 0..<T as Config>::MaxFeeLockTiers::get() {
+			fee_lock_tiers.push((i + 2, fee_lock_amount));
+		}
+	}: {assert_ok!(FeeLock::<T>::update_fee_lock_metadata(RawOrigin::Root.into(), Some(period_length), Some(fee_lock_amount), Some(swap_value_threshold), Some(whitelisted_tokens), Some(swap_value_thresholds), Some(fee_lock_tiers)));}
 	verify{
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().period_length, period_length);
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().fee_lock_amount, fee_lock_amount);
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().swap_value_threshold, swap_value_threshold);
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().whitelisted_tokens.len(), <T as Config>::MaxCuratedTokens::get() as usize);
+		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().fee_lock_tiers.len(), <T as Config>::MaxFeeLockTiers::get() as usize);
 	}
 
 	unlock_fee{
@@ -68,7 +75,7 @@ benchmarks! {
 		let initial_user_reserved_balance = <T as Config>::Tokens::reserved_balance(token_id, &caller.clone());
 		let initial_user_locked_balance = <T as Config>::Tokens::locked_balance(token_id, &caller.clone());
 
-		assert_ok!(FeeLock::<T>::update_fee_lock_metadata(RawOrigin::Root.into(), Some(period_length), Some(fee_lock_amount), Some(swap_value_threshold), None));
+		assert_ok!(FeeLock::<T>::update_fee_lock_metadata(RawOrigin::Root.into(), Some(period_length), Some(fee_lock_amount), Some(swap_value_threshold), None, None, None));
 
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().period_length, period_length);
 		assert_eq!(FeeLock::<T>::get_fee_lock_metadata().unwrap().fee_lock_amount, fee_lock_amount);
@@ -89,6 +96,7 @@ benchmarks! {
 		assert_eq!(FeeLock::<T>::get_account_fee_lock_data(caller.clone()), AccountFeeLockDataInfo{
 			total_fee_lock_amount: fee_lock_amount,
 			last_fee_lock_block: now,
+			lock_token: token_id,
 		});
 
 		frame_system::Pallet::<T>::set_block_number(now + period_length);
@@ -105,6 +113,7 @@ benchmarks! {
 		assert_eq!(FeeLock::<T>::get_account_fee_lock_data(caller.clone()), AccountFeeLockDataInfo{
 			total_fee_lock_amount: BalanceOf::<T>::zero(),
 			last_fee_lock_block: 0_u32.into(),
+			lock_token: Default::default(),
 		});
 	}
 
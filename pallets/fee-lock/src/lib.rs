@@ -5,19 +5,19 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	storage::bounded_btree_set::BoundedBTreeSet,
+	storage::bounded_btree_map::BoundedBTreeMap,
 	traits::{Get, MultiTokenCurrency, StorageVersion},
 	transactional,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use mangata_support::traits::{FeeLockTriggerTrait, Valuate};
+use mangata_support::traits::FeeLockTriggerTrait;
 use orml_tokens::{MultiTokenCurrencyExtended, MultiTokenReservableCurrency};
 use sp_arithmetic::per_things::Rounding;
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 
 use sp_runtime::{
-	traits::{Bounded, CheckedAdd, SaturatedConversion, Zero},
-	Saturating,
+	traits::{Bounded, SaturatedConversion, Zero},
+	Percent, Saturating,
 };
 use sp_std::{convert::TryInto, prelude::*};
 
@@ -28,6 +28,7 @@ mod tests;
 
 mod benchmarking;
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -46,6 +47,68 @@ macro_rules! log {
 
 pub use pallet::*;
 
+/// Reserves of the native token and a token that are used to valuate the token in native token.
+/// They do not have to be reserves of a single pool, e.g. a token with no pool with the native
+/// token can be valuated through a path of pools.
+pub trait ValuationReservesProvider<CurrencyId, Balance> {
+	/// Returns (native token reserve, `token_id` reserve)
+	fn get_valuation_reserves(token_id: CurrencyId) -> Result<(Balance, Balance), DispatchError>;
+}
+
+/// Source of account positions (e.g. activated liquidity or staking bonds) that make the account
+/// eligible for a reduced fee lock
+pub trait FeeLockExemptionProvider<AccountId, Balance> {
+	/// Value of the account positions valuated in native token
+	fn positions_value(who: &AccountId) -> Balance;
+	/// Worst case weight of [`FeeLockExemptionProvider::positions_value`]
+	fn positions_value_weight() -> Weight;
+}
+
+impl<AccountId, Balance: Zero> FeeLockExemptionProvider<AccountId, Balance> for () {
+	fn positions_value(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn positions_value_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl<AccountId, Balance, A, B> FeeLockExemptionProvider<AccountId, Balance> for (A, B)
+where
+	Balance: Saturating,
+	A: FeeLockExemptionProvider<AccountId, Balance>,
+	B: FeeLockExemptionProvider<AccountId, Balance>,
+{
+	fn positions_value(who: &AccountId) -> Balance {
+		A::positions_value(who).saturating_add(B::positions_value(who))
+	}
+
+	fn positions_value_weight() -> Weight {
+		A::positions_value_weight().saturating_add(B::positions_value_weight())
+	}
+}
+
+impl<AccountId, Balance, A, B, C> FeeLockExemptionProvider<AccountId, Balance> for (A, B, C)
+where
+	Balance: Saturating,
+	A: FeeLockExemptionProvider<AccountId, Balance>,
+	B: FeeLockExemptionProvider<AccountId, Balance>,
+	C: FeeLockExemptionProvider<AccountId, Balance>,
+{
+	fn positions_value(who: &AccountId) -> Balance {
+		A::positions_value(who)
+			.saturating_add(B::positions_value(who))
+			.saturating_add(C::positions_value(who))
+	}
+
+	fn positions_value_weight() -> Weight {
+		A::positions_value_weight()
+			.saturating_add(B::positions_value_weight())
+			.saturating_add(C::positions_value_weight())
+	}
+}
+
 pub type BalanceOf<T> = <<T as pallet::Config>::Tokens as MultiTokenCurrency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
@@ -58,7 +121,7 @@ pub type CurrencyIdOf<T> = <<T as pallet::Config>::Tokens as MultiTokenCurrency<
 pub mod pallet {
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -66,74 +129,13 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-			let mut consumed_weight: Weight = Default::default();
-
-			// process only up to 80% or remaining weight
-			let base_cost = T::DbWeight::get().reads(1)   // UnlockQueueBegin
-				+ T::DbWeight::get().reads(1)   // UnlockQueueEnd
-				+ T::DbWeight::get().reads(1); // FeeLockMetadata
-
-			let cost_of_single_unlock_iteration = T::WeightInfo::unlock_fee() // cost of unlock action
-				+ T::DbWeight::get().reads(1)   // FeeLockMetadataQeueuePosition
-				+ T::DbWeight::get().reads(1)   // AccountFeeLockData
-				+ T::DbWeight::get().reads(1)   // UnlockQueue
-				+ T::DbWeight::get().writes(1); // UnlockQueueBegin
-
-			if (base_cost + cost_of_single_unlock_iteration).ref_time() >
-				remaining_weight.ref_time()
-			{
-				return Weight::from_parts(0, 0)
-			}
-
-			let metadata = Self::get_fee_lock_metadata();
-			let period_length = metadata.map(|meta| meta.period_length);
-			let begin = UnlockQueueBegin::<T>::get();
-			let end = UnlockQueueEnd::<T>::get();
-			consumed_weight += base_cost;
-
-			for i in begin..end {
-				consumed_weight += T::DbWeight::get().reads(3); // UnlockQueue, AccountFeeLockData FeeLockMetadataQeueuePosition
-				UnlockQueueBegin::<T>::put(i);
-				consumed_weight += T::DbWeight::get().writes(1);
-				let who = UnlockQueue::<T>::get(i);
-				let queue_pos =
-					who.as_ref().and_then(|acc| FeeLockMetadataQeueuePosition::<T>::get(acc));
-
-				if matches!(queue_pos, Some(id) if id == i) {
-					let lock_info = who.and_then(|who| {
-						AccountFeeLockData::<T>::try_get(&who).map(|lock| (who.clone(), lock)).ok()
-					});
-
-					match (period_length, lock_info) {
-						(Some(period_length), Some((who, lock))) => {
-							let unlock_block = lock.last_fee_lock_block.checked_add(&period_length);
-
-							if matches!(unlock_block, Some(unlock) if unlock <= now) {
-								UnlockQueueBegin::<T>::put(i + 1);
-								consumed_weight += T::WeightInfo::unlock_fee();
-								let _ = <Self as FeeLockTriggerTrait<
-									T::AccountId,
-									BalanceOf<T>,
-									CurrencyIdOf<T>,
-								>>::unlock_fee(&who);
-							} else {
-								break
-							}
-						},
-						_ => break,
-					};
-				} else {
-					UnlockQueueBegin::<T>::put(i + 1);
-				}
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// guarantees that expired locks are released even when blocks are full
+			Self::process_unlock_queue(now, T::MinUnlocksPerBlock::get(), Weight::MAX)
+		}
 
-				if cost_of_single_unlock_iteration.ref_time() >
-					(remaining_weight.ref_time() - consumed_weight.ref_time())
-				{
-					break
-				}
-			}
-			consumed_weight
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_unlock_queue(now, u32::MAX, remaining_weight)
 		}
 	}
 
@@ -144,15 +146,38 @@ pub mod pallet {
 		pub period_length: BlockNumberFor<T>,
 		pub fee_lock_amount: BalanceOf<T>,
 		pub swap_value_threshold: BalanceOf<T>,
-		pub whitelisted_tokens: BoundedBTreeSet<CurrencyIdOf<T>, T::MaxCuratedTokens>,
+		/// Curated tokens along with their optional custom swap value threshold,
+		/// `None` falls back to `swap_value_threshold`
+		pub whitelisted_tokens:
+			BoundedBTreeMap<CurrencyIdOf<T>, Option<BalanceOf<T>>, T::MaxCuratedTokens>,
+		/// Lock amounts applied once the account made at least the given number of low value
+		/// swaps within `period_length`, sorted by the number of swaps
+		pub fee_lock_tiers: BoundedVec<(u32, BalanceOf<T>), T::MaxFeeLockTiers>,
 	}
 
 	impl<T: Config> FeeLockMetadataInfo<T> {
 		pub fn is_whitelisted(&self, token_id: CurrencyIdOf<T>) -> bool {
-			if T::NativeTokenId::get() == token_id {
-				return true
+			self.swap_value_threshold_for(token_id).is_some()
+		}
+
+		/// Swap value threshold (in native tokens) above which a swap of `token_id` is
+		/// considered high value, `None` if the token is not curated
+		pub fn swap_value_threshold_for(&self, token_id: CurrencyIdOf<T>) -> Option<BalanceOf<T>> {
+			match self.whitelisted_tokens.get(&token_id) {
+				Some(threshold) => Some(threshold.unwrap_or(self.swap_value_threshold)),
+				None if T::NativeTokenId::get() == token_id => Some(self.swap_value_threshold),
+				None => None,
 			}
-			self.whitelisted_tokens.contains(&token_id)
+		}
+
+		/// Amount locked for the `swaps_in_period`-th low value swap within `period_length`
+		pub fn fee_lock_amount_for(&self, swaps_in_period: u32) -> BalanceOf<T> {
+			self.fee_lock_tiers
+				.iter()
+				.rev()
+				.find(|(min_swaps, _)| *min_swaps <= swaps_in_period)
+				.map(|(_, amount)| *amount)
+				.unwrap_or(self.fee_lock_amount)
 		}
 	}
 
@@ -160,25 +185,32 @@ pub mod pallet {
 	#[pallet::getter(fn get_fee_lock_metadata)]
 	pub type FeeLockMetadata<T: Config> = StorageValue<_, FeeLockMetadataInfo<T>, OptionQuery>;
 
-	#[pallet::storage]
-	pub type FeeLockMetadataQeueuePosition<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, u128, OptionQuery>;
+	#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct UnlockQueueNode<AccountId> {
+		pub prev: Option<AccountId>,
+		pub next: Option<AccountId>,
+	}
 
+	/// Accounts with fee lock ordered by the block of their last lock, linked from
+	/// [`UnlockQueueHead`] (oldest lock) to [`UnlockQueueTail`] (most recent lock)
 	#[pallet::storage]
-	pub type UnlockQueue<T: Config> = StorageMap<_, Twox64Concat, u128, T::AccountId, OptionQuery>;
+	pub type UnlockQueueNodes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, UnlockQueueNode<T::AccountId>, OptionQuery>;
 
 	#[pallet::storage]
-	pub type UnlockQueueBegin<T: Config> = StorageValue<_, u128, ValueQuery>;
+	pub type UnlockQueueHead<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
 	#[pallet::storage]
-	pub type UnlockQueueEnd<T: Config> = StorageValue<_, u128, ValueQuery>;
+	pub type UnlockQueueTail<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
 	#[derive(
 		Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Default,
 	)]
-	pub struct AccountFeeLockDataInfo<BlockNumber, Balance> {
+	pub struct AccountFeeLockDataInfo<BlockNumber, Balance, CurrencyId> {
 		pub total_fee_lock_amount: Balance,
 		pub last_fee_lock_block: BlockNumber,
+		/// Token in which `total_fee_lock_amount` is reserved
+		pub lock_token: CurrencyId,
 	}
 
 	#[pallet::storage]
@@ -187,16 +219,65 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::AccountId,
-		AccountFeeLockDataInfo<BlockNumberFor<T>, BalanceOf<T>>,
+		AccountFeeLockDataInfo<BlockNumberFor<T>, BalanceOf<T>, CurrencyIdOf<T>>,
 		ValueQuery,
 	>;
 
+	/// Number of low value swaps made by the account within the current lock period
+	#[pallet::storage]
+	#[pallet::getter(fn get_account_swaps_in_period)]
+	pub type AccountSwapsInPeriod<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[derive(
+		Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Default,
+	)]
+	pub struct FeeLockExemptionInfo<Balance> {
+		/// Positions value from which the lock amount is reduced by `reduction`
+		pub reduction_threshold: Balance,
+		pub reduction: Percent,
+		/// Positions value from which no fee lock is reserved at all
+		pub exemption_threshold: Balance,
+	}
+
+	#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum FeeLockExemptionLevel {
+		/// Whole lock amount is reserved
+		NotExempt,
+		/// Lock amount is reduced by `FeeLockExemptionInfo::reduction`
+		Reduced,
+		/// Nothing is reserved
+		Exempt,
+	}
+
+	/// Thresholds of positions value (see [`FeeLockExemptionProvider`]) that reduce the fee lock
+	#[pallet::storage]
+	#[pallet::getter(fn get_fee_lock_exemption)]
+	pub type FeeLockExemption<T: Config> =
+		StorageValue<_, FeeLockExemptionInfo<BalanceOf<T>>, OptionQuery>;
+
+	/// Accounts that are always exempt from the fee lock
+	#[pallet::storage]
+	pub type FeeLockExemptAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		FeeLockMetadataUpdated,
-		FeeLockUnlocked(T::AccountId, BalanceOf<T>),
-		FeeLocked { who: T::AccountId, lock_amount: BalanceOf<T>, total_locked: BalanceOf<T> },
+		FeeLockUnlocked(T::AccountId, BalanceOf<T>, CurrencyIdOf<T>),
+		FeeLocked {
+			who: T::AccountId,
+			lock_token: CurrencyIdOf<T>,
+			lock_amount: BalanceOf<T>,
+			total_locked: BalanceOf<T>,
+			exemption: FeeLockExemptionLevel,
+		},
+		FeeLockExemptionUpdated,
+		FeeLockExemptAccountSet {
+			who: T::AccountId,
+			exempt: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -214,6 +295,12 @@ pub mod pallet {
 		CantUnlockFeeYet,
 		/// The limit on the maximum curated tokens for which there is a swap threshold is exceeded
 		MaxCuratedTokensLimitExceeded,
+		/// The limit on the maximum number of fee lock tiers is exceeded
+		MaxFeeLockTiersLimitExceeded,
+		/// Fee lock exemption thresholds are invalid
+		InvalidFeeLockExemption,
+		/// Token of the current fee lock has no valuation in native token
+		FeeLockTokenNotValuated,
 		/// An unexpected failure has occured
 		UnexpectedFailure,
 	}
@@ -223,11 +310,18 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		#[pallet::constant]
 		type MaxCuratedTokens: Get<u32>;
+		#[pallet::constant]
+		type MaxFeeLockTiers: Get<u32>;
 		type Tokens: MultiTokenCurrencyExtended<Self::AccountId>
 			+ MultiTokenReservableCurrency<Self::AccountId>;
-		type PoolReservesProvider: Valuate<BalanceOf<Self>, CurrencyIdOf<Self>>;
+		type PoolReservesProvider: ValuationReservesProvider<CurrencyIdOf<Self>, BalanceOf<Self>>;
 		#[pallet::constant]
 		type NativeTokenId: Get<CurrencyIdOf<Self>>;
+		/// Provides value of account positions used for the fee lock exemption
+		type ExemptionProvider: FeeLockExemptionProvider<Self::AccountId, BalanceOf<Self>>;
+		/// Number of unlock queue entries processed in every block regardless of spare weight
+		#[pallet::constant]
+		type MinUnlocksPerBlock: Get<u32>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -255,11 +349,14 @@ pub mod pallet {
 		fn build(&self) {
 			match (self.period_length, self.fee_lock_amount, self.swap_value_threshold) {
 				(Some(period), Some(amount), Some(threshold)) => {
-					let mut tokens: BoundedBTreeSet<CurrencyIdOf<T>, T::MaxCuratedTokens> =
-						Default::default();
+					let mut tokens: BoundedBTreeMap<
+						CurrencyIdOf<T>,
+						Option<BalanceOf<T>>,
+						T::MaxCuratedTokens,
+					> = Default::default();
 					for t in self.whitelisted_tokens.iter() {
 						tokens
-							.try_insert(*t)
+							.try_insert(*t, None)
 							.expect("list of tokens is <= than T::MaxCuratedTokens");
 					}
 
@@ -268,6 +365,7 @@ pub mod pallet {
 						fee_lock_amount: amount,
 						swap_value_threshold: threshold,
 						whitelisted_tokens: tokens,
+						fee_lock_tiers: Default::default(),
 					});
 				},
 				(None, None, None) => {},
@@ -281,6 +379,9 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		// The weight is calculated using MaxCuratedTokens so it is the worst case weight
+		/// `swap_value_thresholds` sets a custom threshold for a token (curating it if needed),
+		/// `None` makes it use the default `swap_value_threshold`. `fee_lock_tiers` replaces
+		/// the tiers as a list of (minimum number of low value swaps in period, lock amount).
 		#[pallet::call_index(0)]
 		#[transactional]
 		#[pallet::weight(T::WeightInfo::update_fee_lock_metadata())]
@@ -290,6 +391,8 @@ pub mod pallet {
 			fee_lock_amount: Option<BalanceOf<T>>,
 			swap_value_threshold: Option<BalanceOf<T>>,
 			should_be_whitelisted: Option<Vec<(CurrencyIdOf<T>, bool)>>,
+			swap_value_thresholds: Option<Vec<(CurrencyIdOf<T>, Option<BalanceOf<T>>)>>,
+			fee_lock_tiers: Option<Vec<(u32, BalanceOf<T>)>>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
@@ -299,6 +402,7 @@ pub mod pallet {
 					fee_lock_amount: Default::default(),
 					swap_value_threshold: Default::default(),
 					whitelisted_tokens: Default::default(),
+					fee_lock_tiers: Default::default(),
 				});
 
 			fee_lock_metadata.period_length =
@@ -321,12 +425,13 @@ pub mod pallet {
 			if let Some(should_be_whitelisted) = should_be_whitelisted {
 				for (token_id, should_be_whitelisted) in should_be_whitelisted.iter() {
 					match should_be_whitelisted {
-						true => {
-							let _ = fee_lock_metadata
-								.whitelisted_tokens
-								.try_insert(*token_id)
-								.map_err(|_| Error::<T>::MaxCuratedTokensLimitExceeded)?;
-						},
+						true =>
+							if !fee_lock_metadata.whitelisted_tokens.contains_key(token_id) {
+								let _ = fee_lock_metadata
+									.whitelisted_tokens
+									.try_insert(*token_id, None)
+									.map_err(|_| Error::<T>::MaxCuratedTokensLimitExceeded)?;
+							},
 						false => {
 							let _ = fee_lock_metadata.whitelisted_tokens.remove(token_id);
 						},
@@ -334,6 +439,30 @@ pub mod pallet {
 				}
 			}
 
+			if let Some(swap_value_thresholds) = swap_value_thresholds {
+				for (token_id, threshold) in swap_value_thresholds.iter() {
+					ensure!(
+						!matches!(threshold, Some(t) if t.is_zero()),
+						Error::<T>::InvalidFeeLockMetadata
+					);
+					let _ = fee_lock_metadata
+						.whitelisted_tokens
+						.try_insert(*token_id, *threshold)
+						.map_err(|_| Error::<T>::MaxCuratedTokensLimitExceeded)?;
+				}
+			}
+
+			if let Some(fee_lock_tiers) = fee_lock_tiers {
+				ensure!(
+					fee_lock_tiers.windows(2).all(|w| w[0].0 < w[1].0) &&
+						fee_lock_tiers.iter().all(|(_, amount)| !amount.is_zero()),
+					Error::<T>::InvalidFeeLockMetadata
+				);
+				fee_lock_metadata.fee_lock_tiers = fee_lock_tiers
+					.try_into()
+					.map_err(|_| Error::<T>::MaxFeeLockTiersLimitExceeded)?;
+			}
+
 			FeeLockMetadata::<T>::put(fee_lock_metadata);
 
 			Pallet::<T>::deposit_event(Event::FeeLockMetadataUpdated);
@@ -349,26 +478,338 @@ pub mod pallet {
 
 			Ok(<Self as FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>>::unlock_fee(&who)?.into())
 		}
+
+		/// Sets thresholds of account positions value from which the fee lock is reduced or
+		/// skipped, `None` disables the exemption
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn update_fee_lock_exemption(
+			origin: OriginFor<T>,
+			exemption: Option<FeeLockExemptionInfo<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			if let Some(exemption) = exemption.as_ref() {
+				ensure!(
+					!exemption.reduction_threshold.is_zero() &&
+						exemption.reduction_threshold <= exemption.exemption_threshold,
+					Error::<T>::InvalidFeeLockExemption
+				);
+			}
+			FeeLockExemption::<T>::set(exemption);
+
+			Pallet::<T>::deposit_event(Event::FeeLockExemptionUpdated);
+
+			Ok(().into())
+		}
+
+		/// Adds account to or removes it from the fee lock exemption allowlist
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_fee_lock_exempt_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			exempt: bool,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			if exempt {
+				FeeLockExemptAccounts::<T>::insert(&who, ());
+			} else {
+				FeeLockExemptAccounts::<T>::remove(&who);
+			}
+
+			Pallet::<T>::deposit_event(Event::FeeLockExemptAccountSet { who, exempt });
+
+			Ok(().into())
+		}
 	}
 }
 impl<T: Config> Pallet<T> {
 	pub(crate) fn push_to_the_end_of_unlock_queue(who: &T::AccountId) {
-		let mut id = Default::default();
-		let id_ref = &mut id;
-		UnlockQueueEnd::<T>::mutate(|id| {
-			*id_ref = *id;
-			*id = *id + 1
-		});
-		UnlockQueue::<T>::insert(id, who);
-		FeeLockMetadataQeueuePosition::<T>::set(who, Some(id));
+		let tail = UnlockQueueTail::<T>::get();
+		match tail.as_ref() {
+			Some(tail) => UnlockQueueNodes::<T>::mutate(tail, |node| {
+				if let Some(node) = node {
+					node.next = Some(who.clone());
+				}
+			}),
+			None => UnlockQueueHead::<T>::put(who),
+		}
+		UnlockQueueNodes::<T>::insert(who, UnlockQueueNode { prev: tail, next: None });
+		UnlockQueueTail::<T>::put(who);
+	}
+
+	pub(crate) fn remove_from_unlock_queue(who: &T::AccountId) {
+		if let Some(UnlockQueueNode { prev, next }) = UnlockQueueNodes::<T>::take(who) {
+			match prev.as_ref() {
+				Some(prev) => UnlockQueueNodes::<T>::mutate(prev, |node| {
+					if let Some(node) = node {
+						node.next = next.clone();
+					}
+				}),
+				None => UnlockQueueHead::<T>::set(next.clone()),
+			}
+			match next.as_ref() {
+				Some(next) => UnlockQueueNodes::<T>::mutate(next, |node| {
+					if let Some(node) = node {
+						node.prev = prev.clone();
+					}
+				}),
+				None => UnlockQueueTail::<T>::set(prev),
+			}
+		}
 	}
 
 	pub(crate) fn move_to_the_end_of_unlock_queue(who: &T::AccountId) {
-		if let Ok(id) = FeeLockMetadataQeueuePosition::<T>::try_get(who) {
-			UnlockQueue::<T>::take(id);
-			Self::push_to_the_end_of_unlock_queue(who);
+		Self::remove_from_unlock_queue(who);
+		Self::push_to_the_end_of_unlock_queue(who);
+	}
+
+	/// Unlocks expired fee locks from the head of the unlock queue, processing at most
+	/// `max_iterations` entries within `weight_limit`. Entries without a lock are dropped from
+	/// the queue on the way.
+	pub(crate) fn process_unlock_queue(
+		now: BlockNumberFor<T>,
+		max_iterations: u32,
+		weight_limit: Weight,
+	) -> Weight {
+		let base_cost = T::DbWeight::get().reads(1) // FeeLockMetadata
+			+ T::DbWeight::get().reads(1); // UnlockQueueHead
+
+		// dropping an entry without a lock is cheaper than unlocking it
+		let cost_of_single_unlock_iteration = T::WeightInfo::unlock_fee() // cost of unlock action
+			+ T::DbWeight::get().reads(1) // AccountFeeLockData
+			+ T::DbWeight::get().reads(1); // UnlockQueueHead
+
+		if max_iterations.is_zero() ||
+			(base_cost + cost_of_single_unlock_iteration).ref_time() > weight_limit.ref_time()
+		{
+			return Weight::from_parts(0, 0)
+		}
+
+		let mut consumed_weight = base_cost;
+		let period_length = match Self::get_fee_lock_metadata() {
+			Some(metadata) => metadata.period_length,
+			None => return consumed_weight,
+		};
+
+		let mut iterations = 0u32;
+		while iterations < max_iterations &&
+			(consumed_weight + cost_of_single_unlock_iteration).ref_time() <=
+				weight_limit.ref_time()
+		{
+			let who = match UnlockQueueHead::<T>::get() {
+				Some(who) => who,
+				None => break,
+			};
+
+			match AccountFeeLockData::<T>::try_get(&who) {
+				Ok(lock)
+					if lock.last_fee_lock_block.saturating_add(period_length) <= now =>
+				{
+					if <Self as FeeLockTriggerTrait<
+						T::AccountId,
+						BalanceOf<T>,
+						CurrencyIdOf<T>,
+					>>::unlock_fee(&who)
+					.is_err()
+					{
+						Self::remove_from_unlock_queue(&who);
+					}
+				},
+				Ok(_) => {
+					// queue is ordered by lock block, so no further lock is expired
+					consumed_weight += T::DbWeight::get().reads(1);
+					break
+				},
+				Err(_) => Self::remove_from_unlock_queue(&who),
+			}
+			consumed_weight += cost_of_single_unlock_iteration;
+			iterations += 1;
+		}
+		consumed_weight
+	}
+
+	/// Block from which the fee lock of the account can be unlocked, `None` if nothing is locked
+	pub fn get_unlockable_at(who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+		let account_fee_lock_data = Self::get_account_fee_lock_data(who);
+		if account_fee_lock_data.total_fee_lock_amount.is_zero() {
+			return None
+		}
+		let fee_lock_metadata = Self::get_fee_lock_metadata()?;
+		Some(
+			account_fee_lock_data
+				.last_fee_lock_block
+				.saturating_add(fee_lock_metadata.period_length),
+		)
+	}
+
+	/// Token and amount that `process_fee_lock` would lock for the account at the current block
+	/// along with the total locked amount afterwards, `None` if the lock could not be processed
+	pub fn preview_fee_lock(
+		who: &T::AccountId,
+	) -> Option<(CurrencyIdOf<T>, BalanceOf<T>, BalanceOf<T>)> {
+		let fee_lock_metadata = Self::get_fee_lock_metadata()?;
+		let account_fee_lock_data = Self::get_account_fee_lock_data(who);
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		let in_period = now <
+			account_fee_lock_data
+				.last_fee_lock_block
+				.saturating_add(fee_lock_metadata.period_length);
+		let swaps_in_period =
+			if in_period { Self::get_account_swaps_in_period(who).saturating_add(1) } else { 1 };
+		let (native_lock_amount, _) = Self::exempted_fee_lock_amount(
+			who,
+			fee_lock_metadata.fee_lock_amount_for(swaps_in_period),
+		);
+		let (lock_token, lock_amount) = Self::select_fee_lock_token(
+			who,
+			&fee_lock_metadata,
+			&account_fee_lock_data,
+			in_period,
+			native_lock_amount,
+		)
+		.ok()?;
+
+		let (to_reserve, total_locked) = if in_period {
+			(lock_amount, account_fee_lock_data.total_fee_lock_amount.saturating_add(lock_amount))
+		} else if lock_token == account_fee_lock_data.lock_token {
+			(lock_amount.saturating_sub(account_fee_lock_data.total_fee_lock_amount), lock_amount)
 		} else {
-			Self::push_to_the_end_of_unlock_queue(who);
+			(lock_amount, lock_amount)
+		};
+		if !<T as pallet::Config>::Tokens::can_reserve(lock_token, who, to_reserve) {
+			return None
+		}
+
+		Some((lock_token, lock_amount, total_locked))
+	}
+
+	/// Amount of `token_id` worth `native_amount` of native tokens based on the pool reserves,
+	/// `None` if the token has no valuation
+	pub fn get_fee_lock_amount_in_token(
+		token_id: CurrencyIdOf<T>,
+		native_amount: BalanceOf<T>,
+	) -> Option<BalanceOf<T>> {
+		if T::NativeTokenId::get() == token_id {
+			return Some(native_amount)
+		}
+		let (native_token_pool_reserve, token_pool_reserve) =
+			T::PoolReservesProvider::get_valuation_reserves(token_id).ok()?;
+		if native_token_pool_reserve.is_zero() || token_pool_reserve.is_zero() {
+			return None
+		}
+		Some(
+			multiply_by_rational_with_rounding(
+				native_amount.into(),
+				token_pool_reserve.into(),
+				native_token_pool_reserve.into(),
+				Rounding::Up,
+			)
+			.map(SaturatedConversion::saturated_into)
+			.unwrap_or(BalanceOf::<T>::max_value()),
+		)
+	}
+
+	/// Token in which the account locks `native_lock_amount` worth of tokens along with the
+	/// amount locked in that token. A lock made within the current period is topped up in its
+	/// own token, otherwise the native token is preferred and curated tokens with a valuation are
+	/// used when the account cannot afford the native one.
+	pub(crate) fn select_fee_lock_token(
+		who: &T::AccountId,
+		fee_lock_metadata: &FeeLockMetadataInfo<T>,
+		account_fee_lock_data: &AccountFeeLockDataInfo<
+			BlockNumberFor<T>,
+			BalanceOf<T>,
+			CurrencyIdOf<T>,
+		>,
+		in_period: bool,
+		native_lock_amount: BalanceOf<T>,
+	) -> Result<(CurrencyIdOf<T>, BalanceOf<T>), DispatchError> {
+		if in_period && !account_fee_lock_data.total_fee_lock_amount.is_zero() {
+			let lock_token = account_fee_lock_data.lock_token;
+			let lock_amount = Self::get_fee_lock_amount_in_token(lock_token, native_lock_amount)
+				.ok_or(Error::<T>::FeeLockTokenNotValuated)?;
+			return Ok((lock_token, lock_amount))
+		}
+
+		let native_token_id = T::NativeTokenId::get();
+		let candidates = sp_std::iter::once(native_token_id).chain(
+			fee_lock_metadata
+				.whitelisted_tokens
+				.keys()
+				.copied()
+				.filter(|token_id| *token_id != native_token_id),
+		);
+		for token_id in candidates {
+			let lock_amount = match Self::get_fee_lock_amount_in_token(token_id, native_lock_amount)
+			{
+				Some(lock_amount) => lock_amount,
+				None => continue,
+			};
+			// lock from the previous period is released when the new one is made
+			let released = if !in_period && token_id == account_fee_lock_data.lock_token {
+				account_fee_lock_data.total_fee_lock_amount
+			} else {
+				Zero::zero()
+			};
+			if <T as pallet::Config>::Tokens::can_reserve(
+				token_id,
+				who,
+				lock_amount.saturating_sub(released),
+			) {
+				return Ok((token_id, lock_amount))
+			}
+		}
+
+		// no token is affordable, reserving native token reports the error
+		Ok((native_token_id, native_lock_amount))
+	}
+
+	/// Fee lock exemption level of the account based on the allowlist and value of its positions
+	pub fn get_fee_lock_exemption_level(who: &T::AccountId) -> FeeLockExemptionLevel {
+		Self::exempted_fee_lock_amount(who, Zero::zero()).1
+	}
+
+	/// Worst case weight of checking the fee lock exemption of an account, see
+	/// [`Pallet::exempted_fee_lock_amount`]
+	pub fn exemption_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(2)
+			.saturating_add(T::ExemptionProvider::positions_value_weight())
+	}
+
+	/// Reduces `amount` according to the fee lock exemption of the account
+	pub(crate) fn exempted_fee_lock_amount(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> (BalanceOf<T>, FeeLockExemptionLevel) {
+		if FeeLockExemptAccounts::<T>::contains_key(who) {
+			return (Zero::zero(), FeeLockExemptionLevel::Exempt)
+		}
+		let exemption = match Self::get_fee_lock_exemption() {
+			Some(exemption) => exemption,
+			None => return (amount, FeeLockExemptionLevel::NotExempt),
+		};
+		let positions_value = T::ExemptionProvider::positions_value(who);
+		if positions_value >= exemption.exemption_threshold {
+			(Zero::zero(), FeeLockExemptionLevel::Exempt)
+		} else if positions_value >= exemption.reduction_threshold {
+			(amount.saturating_sub(exemption.reduction * amount), FeeLockExemptionLevel::Reduced)
+		} else {
+			(amount, FeeLockExemptionLevel::NotExempt)
+		}
+	}
+
+	/// Amount that `unlock_fee` would unlock for the account at the current block
+	pub fn preview_unlock_fee(who: &T::AccountId) -> BalanceOf<T> {
+		match <Self as FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>>::can_unlock_fee(
+			who,
+		) {
+			Ok(()) => Self::get_account_fee_lock_data(who).total_fee_lock_amount,
+			Err(_) => Zero::zero(),
 		}
 	}
 }
@@ -390,11 +831,7 @@ impl<T: Config> FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>
 			return Some(valuating_token_amount)
 		}
 		let (native_token_pool_reserve, valuating_token_pool_reserve) =
-			<T::PoolReservesProvider as Valuate<BalanceOf<T>, CurrencyIdOf<T>>>::get_reserves(
-				T::NativeTokenId::get(),
-				valuating_token_id,
-			)
-			.ok()?;
+			T::PoolReservesProvider::get_valuation_reserves(valuating_token_id).ok()?;
 		if native_token_pool_reserve.is_zero() || valuating_token_pool_reserve.is_zero() {
 			return None
 		}
@@ -419,44 +856,42 @@ impl<T: Config> FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>
 		// This is cause now >= last_fee_lock_block
 		ensure!(now >= account_fee_lock_data.last_fee_lock_block, Error::<T>::UnexpectedFailure);
 
-		if now <
+		let in_period = now <
 			account_fee_lock_data
 				.last_fee_lock_block
-				.saturating_add(fee_lock_metadata.period_length)
-		{
+				.saturating_add(fee_lock_metadata.period_length);
+		// Once the period has passed, this is the first swap of a new one
+		let swaps_in_period =
+			if in_period { Self::get_account_swaps_in_period(who).saturating_add(1) } else { 1 };
+		let (native_lock_amount, exemption) = Self::exempted_fee_lock_amount(
+			who,
+			fee_lock_metadata.fee_lock_amount_for(swaps_in_period),
+		);
+		let (lock_token, lock_amount) = Self::select_fee_lock_token(
+			who,
+			&fee_lock_metadata,
+			&account_fee_lock_data,
+			in_period,
+			native_lock_amount,
+		)?;
+		let previous_lock_token = account_fee_lock_data.lock_token;
+
+		if in_period {
 			// First storage edit
 			// Cannot fail beyond this point
 			// Rerserve additional fee_lock_amount
-			<T as pallet::Config>::Tokens::reserve(
-				<T as pallet::Config>::NativeTokenId::get().into(),
-				who,
-				fee_lock_metadata.fee_lock_amount,
-			)?;
-
-			// Insert updated account_lock_info into storage
-			// This is not expected to fail
-			account_fee_lock_data.total_fee_lock_amount = account_fee_lock_data
-				.total_fee_lock_amount
-				.saturating_add(fee_lock_metadata.fee_lock_amount);
-			account_fee_lock_data.last_fee_lock_block = now;
-			AccountFeeLockData::<T>::insert(who.clone(), account_fee_lock_data.clone());
-			Self::move_to_the_end_of_unlock_queue(who);
-			Self::deposit_event(Event::FeeLocked {
-				who: who.clone(),
-				lock_amount: fee_lock_metadata.fee_lock_amount,
-				total_locked: account_fee_lock_data.total_fee_lock_amount,
-			});
-		} else {
+			<T as pallet::Config>::Tokens::reserve(lock_token, who, lock_amount)?;
+
+			account_fee_lock_data.total_fee_lock_amount =
+				account_fee_lock_data.total_fee_lock_amount.saturating_add(lock_amount);
+		} else if lock_token == previous_lock_token {
 			// We must either reserve more or unreserve
-			match (fee_lock_metadata.fee_lock_amount, account_fee_lock_data.total_fee_lock_amount) {
-				(x, y) if x > y => <T as pallet::Config>::Tokens::reserve(
-					<T as pallet::Config>::NativeTokenId::get().into(),
-					who,
-					x.saturating_sub(y),
-				)?,
+			match (lock_amount, account_fee_lock_data.total_fee_lock_amount) {
+				(x, y) if x > y =>
+					<T as pallet::Config>::Tokens::reserve(lock_token, who, x.saturating_sub(y))?,
 				(x, y) if x < y => {
 					let unreserve_result = <T as pallet::Config>::Tokens::unreserve(
-						<T as pallet::Config>::NativeTokenId::get().into(),
+						lock_token,
 						who,
 						y.saturating_sub(x),
 					);
@@ -469,18 +904,47 @@ impl<T: Config> FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>
 				},
 				_ => {},
 			}
-			// Insert updated account_lock_info into storage
-			// This is not expected to fail
-			account_fee_lock_data.total_fee_lock_amount = fee_lock_metadata.fee_lock_amount;
+
+			account_fee_lock_data.total_fee_lock_amount = lock_amount;
+		} else {
+			// Lock moves to another token, previous one is released as a whole
+			<T as pallet::Config>::Tokens::reserve(lock_token, who, lock_amount)?;
+			let unreserve_result = <T as pallet::Config>::Tokens::unreserve(
+				previous_lock_token,
+				who,
+				account_fee_lock_data.total_fee_lock_amount,
+			);
+			if !unreserve_result.is_zero() {
+				log::warn!(
+					"Process fee lock unreserve resulted in non-zero unreserve_result {:?}",
+					unreserve_result
+				);
+			}
+
+			account_fee_lock_data.total_fee_lock_amount = lock_amount;
+		}
+		account_fee_lock_data.lock_token = lock_token;
+
+		// Insert updated account_lock_info into storage
+		// This is not expected to fail
+		if account_fee_lock_data.total_fee_lock_amount.is_zero() {
+			// exempt account has nothing to unlock later
+			Self::remove_from_unlock_queue(who);
+			AccountFeeLockData::<T>::remove(who);
+			AccountSwapsInPeriod::<T>::remove(who);
+		} else {
 			account_fee_lock_data.last_fee_lock_block = now;
 			AccountFeeLockData::<T>::insert(who.clone(), account_fee_lock_data.clone());
+			AccountSwapsInPeriod::<T>::insert(who, swaps_in_period);
 			Self::move_to_the_end_of_unlock_queue(who);
-			Self::deposit_event(Event::FeeLocked {
-				who: who.clone(),
-				lock_amount: fee_lock_metadata.fee_lock_amount,
-				total_locked: account_fee_lock_data.total_fee_lock_amount,
-			});
 		}
+		Self::deposit_event(Event::FeeLocked {
+			who: who.clone(),
+			lock_token,
+			lock_amount,
+			total_locked: account_fee_lock_data.total_fee_lock_amount,
+			exemption,
+		});
 
 		Ok(())
 	}
@@ -529,7 +993,7 @@ impl<T: Config> FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>
 		);
 
 		let unreserve_result = <T as pallet::Config>::Tokens::unreserve(
-			<T as pallet::Config>::NativeTokenId::get().into(),
+			account_fee_lock_data.lock_token,
 			&who,
 			account_fee_lock_data.total_fee_lock_amount,
 		);
@@ -540,14 +1004,14 @@ impl<T: Config> FeeLockTriggerTrait<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>
 			);
 		}
 
-		if let Some(pos) = FeeLockMetadataQeueuePosition::<T>::take(&who) {
-			UnlockQueue::<T>::take(pos);
-		}
+		Self::remove_from_unlock_queue(&who);
 		AccountFeeLockData::<T>::remove(&who);
+		AccountSwapsInPeriod::<T>::remove(&who);
 
 		Self::deposit_event(Event::FeeLockUnlocked(
 			who.clone(),
 			account_fee_lock_data.total_fee_lock_amount,
+			account_fee_lock_data.lock_token,
 		));
 
 		Ok(())
@@ -0,0 +1,195 @@
+use super::*;
+use frame_support::{
+	storage::bounded_btree_set::BoundedBTreeSet,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Fee lock metadata used before v1, with a single threshold shared by all curated tokens
+	#[derive(Encode, Decode)]
+	pub struct OldFeeLockMetadataInfo<T: Config> {
+		pub period_length: BlockNumberFor<T>,
+		pub fee_lock_amount: BalanceOf<T>,
+		pub swap_value_threshold: BalanceOf<T>,
+		pub whitelisted_tokens: BoundedBTreeSet<CurrencyIdOf<T>, T::MaxCuratedTokens>,
+	}
+
+	/// Translates [`FeeLockMetadata`] to the per token thresholds representation. Curated
+	/// tokens keep using the default `swap_value_threshold` and no lock tiers are set, so the
+	/// behaviour is unchanged until governance updates the metadata.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply metadata migration to fee-lock but failed because storage version is {:?}, and not 0",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let _ = FeeLockMetadata::<T>::translate::<OldFeeLockMetadataInfo<T>, _>(|old| {
+				old.map(|old| {
+					let mut whitelisted_tokens: BoundedBTreeMap<
+						CurrencyIdOf<T>,
+						Option<BalanceOf<T>>,
+						T::MaxCuratedTokens,
+					> = Default::default();
+					for token_id in old.whitelisted_tokens.into_iter() {
+						// both collections share the same bound
+						let _ = whitelisted_tokens.try_insert(token_id, None);
+					}
+					FeeLockMetadataInfo {
+						period_length: old.period_length,
+						fee_lock_amount: old.fee_lock_amount,
+						swap_value_threshold: old.swap_value_threshold,
+						whitelisted_tokens,
+						fee_lock_tiers: Default::default(),
+					}
+				})
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "Migrated fee-lock metadata to storage version 1");
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Account fee lock data used before v2, always reserved in the native token
+	#[derive(Encode, Decode)]
+	pub struct OldAccountFeeLockDataInfo<T: Config> {
+		pub total_fee_lock_amount: BalanceOf<T>,
+		pub last_fee_lock_block: BlockNumberFor<T>,
+	}
+
+	/// Records the native token as the lock token of all existing fee locks
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply account lock data migration to fee-lock but failed because storage version is {:?}, and not 1",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut count: u64 = 0;
+			AccountFeeLockData::<T>::translate::<OldAccountFeeLockDataInfo<T>, _>(|_, old| {
+				count = count.saturating_add(1);
+				Some(AccountFeeLockDataInfo {
+					total_fee_lock_amount: old.total_fee_lock_amount,
+					last_fee_lock_block: old.last_fee_lock_block,
+					lock_token: T::NativeTokenId::get(),
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {} fee-lock accounts to storage version 2",
+				count
+			);
+
+			T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	#[storage_alias]
+	pub(crate) type FeeLockMetadataQeueuePosition<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		u128,
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type UnlockQueue<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		u128,
+		<T as frame_system::Config>::AccountId,
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub(crate) type UnlockQueueBegin<T: Config> = StorageValue<Pallet<T>, u128, ValueQuery>;
+
+	#[storage_alias]
+	pub(crate) type UnlockQueueEnd<T: Config> = StorageValue<Pallet<T>, u128, ValueQuery>;
+
+	/// Rebuilds the indexed unlock queue as [`UnlockQueueNodes`] keeping its order. Holes left
+	/// by accounts moved to the end of the queue and entries without a lock are dropped.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 2 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply unlock queue migration to fee-lock but failed because storage version is {:?}, and not 2",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			UnlockQueueBegin::<T>::kill();
+			UnlockQueueEnd::<T>::kill();
+			let mut reads = 1u64;
+			let mut writes = 3u64;
+			let mut migrated = 0u64;
+
+			// only existing entries are visited, so the cost does not depend on the width of
+			// the begin..end range
+			let mut queue = UnlockQueue::<T>::drain().collect::<Vec<_>>();
+			queue.sort_by_key(|(i, _)| *i);
+			reads = reads.saturating_add(queue.len() as u64);
+			writes = writes.saturating_add(queue.len() as u64);
+
+			for (i, who) in queue {
+				reads = reads.saturating_add(2);
+				if FeeLockMetadataQeueuePosition::<T>::get(&who) == Some(i) &&
+					AccountFeeLockData::<T>::contains_key(&who)
+				{
+					Pallet::<T>::push_to_the_end_of_unlock_queue(&who);
+					migrated = migrated.saturating_add(1);
+					reads = reads.saturating_add(1);
+					writes = writes.saturating_add(3);
+				}
+			}
+
+			let positions = FeeLockMetadataQeueuePosition::<T>::clear(u32::MAX, None);
+			writes = writes.saturating_add(positions.unique.into());
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {} fee-lock unlock queue entries to storage version 3",
+				migrated
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
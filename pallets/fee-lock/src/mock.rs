@@ -102,75 +102,56 @@ parameter_types! {
 
 pub struct MockPoolReservesProvider<T>(PhantomData<T>);
 
-impl<T: pallet_fee_lock::Config> Valuate<Balance, TokenId> for MockPoolReservesProvider<T> {
-	fn get_liquidity_asset(
-		_first_asset_id: TokenId,
-		_second_asset_id: TokenId,
-	) -> Result<TokenId, DispatchError> {
-		unimplemented!()
-	}
-
-	fn get_liquidity_token_mga_pool(
-		_liquidity_token_id: TokenId,
-	) -> Result<(TokenId, TokenId), DispatchError> {
-		unimplemented!()
-	}
-
-	fn valuate_liquidity_token(
-		_liquidity_token_id: TokenId,
-		_liquidity_token_amount: Balance,
-	) -> Balance {
-		unimplemented!()
-	}
-
-	fn scale_liquidity_by_mga_valuation(
-		_mga_valuation: Balance,
-		_liquidity_token_amount: Balance,
-		_mga_token_amount: Balance,
-	) -> Balance {
-		unimplemented!()
-	}
-
-	fn get_pool_state(_liquidity_token_id: TokenId) -> Option<(Balance, Balance)> {
-		unimplemented!()
-	}
-
-	fn get_reserves(
-		first_asset_id: TokenId,
-		second_asset_id: TokenId,
-	) -> Result<(Balance, Balance), DispatchError> {
-		match (first_asset_id, second_asset_id) {
-			(0, 1) => Ok((5000, 10000)),
-			(0, 2) => Ok((10000, 5000)),
-			(0, 3) => Ok((0, 10000)),
-			(0, 4) => Ok((5000, 0)),
+impl<T: pallet_fee_lock::Config> ValuationReservesProvider<TokenId, Balance>
+	for MockPoolReservesProvider<T>
+{
+	fn get_valuation_reserves(token_id: TokenId) -> Result<(Balance, Balance), DispatchError> {
+		match token_id {
+			1 => Ok((5000, 10000)),
+			2 => Ok((10000, 5000)),
+			3 => Ok((0, 10000)),
+			4 => Ok((5000, 0)),
 			_ => Err(pallet_fee_lock::Error::<T>::UnexpectedFailure.into()),
 		}
 	}
+}
+
+parameter_types! {
+	pub static PositionsValues: Vec<(AccountId, Balance)> = vec![];
+}
+
+pub struct MockExemptionProvider;
 
-	fn is_liquidity_token(liquidity_asset_id: TokenId) -> bool {
-		unimplemented!()
+impl FeeLockExemptionProvider<AccountId, Balance> for MockExemptionProvider {
+	fn positions_value(who: &AccountId) -> Balance {
+		PositionsValues::get()
+			.iter()
+			.find(|(account, _)| account == who)
+			.map(|(_, value)| *value)
+			.unwrap_or_default()
 	}
 
-	fn valuate_non_liquidity_token(
-		liquidity_token_id: TokenId,
-		liquidity_token_amount: Balance,
-	) -> Balance {
-		unimplemented!()
+	fn positions_value_weight() -> Weight {
+		Weight::zero()
 	}
 }
 
 parameter_types! {
 	#[derive(PartialEq)]
 	pub const MaxCuratedTokens: u32 = 100;
+	pub const MaxFeeLockTiers: u32 = 5;
+	pub const MinUnlocksPerBlock: u32 = 2;
 }
 
 impl pallet_fee_lock::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxCuratedTokens = MaxCuratedTokens;
+	type MaxFeeLockTiers = MaxFeeLockTiers;
 	type Tokens = orml_tokens::MultiTokenCurrencyAdapter<Test>;
 	type PoolReservesProvider = MockPoolReservesProvider<Test>;
 	type NativeTokenId = NativeCurrencyId;
+	type ExemptionProvider = MockExemptionProvider;
+	type MinUnlocksPerBlock = MinUnlocksPerBlock;
 	type WeightInfo = ();
 }
 
@@ -217,6 +198,8 @@ impl ExtBuilder {
 				Some(lock_amount),
 				Some(threshold),
 				None,
+				None,
+				None,
 			)
 			.unwrap()
 		});
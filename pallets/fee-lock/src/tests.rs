@@ -1,11 +1,15 @@
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::bounded_btree_set::BoundedBTreeSet,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade},
+};
 use sp_std::convert::TryFrom;
 use test_case::test_case;
 
 use orml_tokens::AccountData;
-use sp_std::collections::btree_set::BTreeSet;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 #[test]
 fn update_fee_lock_metadata_works() {
@@ -17,6 +21,8 @@ fn update_fee_lock_metadata_works() {
 				Some(0),
 				Some(1000),
 				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
@@ -28,6 +34,8 @@ fn update_fee_lock_metadata_works() {
 				None,
 				Some(1000),
 				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
@@ -39,6 +47,8 @@ fn update_fee_lock_metadata_works() {
 				Some(500),
 				Some(1000),
 				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
@@ -50,6 +60,8 @@ fn update_fee_lock_metadata_works() {
 				Some(500),
 				Some(1000),
 				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
@@ -60,6 +72,8 @@ fn update_fee_lock_metadata_works() {
 			Some(500),
 			Some(1000),
 			Some(vec![(0, true), (1, true)]),
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -68,11 +82,15 @@ fn update_fee_lock_metadata_works() {
 				fee_lock_amount: 500,
 				swap_value_threshold: 1000,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![0, 1].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![0, 1]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 
@@ -82,6 +100,8 @@ fn update_fee_lock_metadata_works() {
 			Some(2500),
 			Some(3000),
 			Some(vec![(0, false), (1, true), (2, true)]),
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -90,21 +110,41 @@ fn update_fee_lock_metadata_works() {
 				fee_lock_amount: 2500,
 				swap_value_threshold: 3000,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![1, 2].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![1, 2]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 
 		assert_noop!(
-			FeeLock::update_fee_lock_metadata(RuntimeOrigin::root(), None, Some(0), None, None,),
+			FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				Some(0),
+				None,
+				None,
+				None,
+				None
+			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
 
 		assert_noop!(
-			FeeLock::update_fee_lock_metadata(RuntimeOrigin::root(), Some(0), None, None, None,),
+			FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				Some(0),
+				None,
+				None,
+				None,
+				None,
+				None
+			),
 			Error::<Test>::InvalidFeeLockMetadata
 		);
 
@@ -113,7 +153,9 @@ fn update_fee_lock_metadata_works() {
 			None,
 			Some(8000),
 			None,
-			None
+			None,
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -122,11 +164,15 @@ fn update_fee_lock_metadata_works() {
 				fee_lock_amount: 8000,
 				swap_value_threshold: 3000,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![1, 2].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![1, 2]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 	})
@@ -154,6 +200,8 @@ fn process_fee_lock_trigger_works() {
 			Some(fee_lock_amount),
 			Some(swap_value_threshold),
 			None,
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -162,11 +210,15 @@ fn process_fee_lock_trigger_works() {
 				fee_lock_amount,
 				swap_value_threshold,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 
@@ -196,6 +248,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -216,6 +269,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 2 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -236,6 +290,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 3 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -261,6 +316,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 1 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -286,6 +342,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 1 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -306,6 +363,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 2 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -330,6 +388,7 @@ fn process_fee_lock_trigger_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 1 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 	})
@@ -357,6 +416,8 @@ fn unlock_fee_works() {
 			Some(fee_lock_amount),
 			Some(swap_value_threshold),
 			None,
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -365,11 +426,15 @@ fn unlock_fee_works() {
 				fee_lock_amount,
 				swap_value_threshold,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 
@@ -408,6 +473,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -428,6 +494,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 2 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -448,6 +515,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 3 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -482,6 +550,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 0 * fee_lock_amount,
 				last_fee_lock_block: 0 * now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -511,6 +580,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 1 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -545,6 +615,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 1 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -574,6 +645,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 2 * fee_lock_amount,
 				last_fee_lock_block: now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -608,6 +680,7 @@ fn unlock_fee_works() {
 			AccountFeeLockDataInfo {
 				total_fee_lock_amount: 0 * fee_lock_amount,
 				last_fee_lock_block: 0 * now,
+				lock_token: NATIVE_CURRENCY_ID,
 			}
 		);
 
@@ -631,6 +704,8 @@ fn whitelist_and_valuation_works() {
 			Some(500),
 			Some(1000),
 			Some(vec![(1, true), (2, true)]),
+			None,
+			None,
 		));
 		assert_eq!(
 			FeeLock::get_fee_lock_metadata(),
@@ -639,11 +714,15 @@ fn whitelist_and_valuation_works() {
 				fee_lock_amount: 500,
 				swap_value_threshold: 1000,
 				whitelisted_tokens: {
-					BoundedBTreeSet::<TokenId, MaxCuratedTokens>::try_from(
-						vec![1, 2].into_iter().collect::<BTreeSet<TokenId>>(),
+					BoundedBTreeMap::<TokenId, Option<Balance>, MaxCuratedTokens>::try_from(
+						vec![1, 2]
+							.into_iter()
+							.map(|token_id| (token_id, None))
+							.collect::<BTreeMap<TokenId, Option<Balance>>>(),
 					)
 					.unwrap()
-				}
+				},
+				fee_lock_tiers: Default::default(),
 			})
 		);
 
@@ -774,25 +853,41 @@ fn calculate_estimated_weight(unlock_fee_calls: u64, reads: u64, writes: u64) ->
 		(<Test as Config>::WeightInfo::unlock_fee() * unlock_fee_calls)
 }
 
+/// Accounts in the unlock queue from head to tail, checks that backward links match
+fn unlock_queue() -> Vec<AccountId> {
+	let mut accounts = vec![];
+	let mut prev = None;
+	let mut pos = UnlockQueueHead::<Test>::get();
+	while let Some(who) = pos {
+		let node = UnlockQueueNodes::<Test>::get(who).unwrap();
+		assert_eq!(node.prev, prev);
+		accounts.push(who);
+		prev = Some(who);
+		pos = node.next;
+	}
+	assert_eq!(UnlockQueueTail::<Test>::get(), prev);
+	accounts
+}
+
 #[test_case(
 	UNLIMITED_WEIGHT,
-	calculate_estimated_weight(1, 6, 1),
+	calculate_estimated_weight(1, 4, 0),
 	ACCOUNT_WITHOUT_LOCKED_TOKENS; "unlocks tokens for an user")]
 #[test_case(
 	Weight::from_parts(0, 0),
 	Weight::from_parts(0, 0),
 	ACCOUNT_WITH_LOCKED_TOKENS; "does not unlock tokens when weigh is zero")]
 #[test_case(
-	calculate_estimated_weight(1, 6, 1),
-	calculate_estimated_weight(1, 6, 1),
+	calculate_estimated_weight(1, 4, 0),
+	calculate_estimated_weight(1, 4, 0),
 	ACCOUNT_WITHOUT_LOCKED_TOKENS; "unlock tokens using exact amount of weight required")]
 #[test_case(
-	calculate_estimated_weight(1, 4, 1),
+	calculate_estimated_weight(1, 3, 0),
 	Weight::from_parts(0, 0),
 	ACCOUNT_WITH_LOCKED_TOKENS; "unlock tokens using a too small weight that required")]
 #[test_case(
-	calculate_estimated_weight(1, 7, 1),
-	calculate_estimated_weight(1, 6, 1),
+	calculate_estimated_weight(1, 5, 0),
+	calculate_estimated_weight(1, 4, 0),
 	ACCOUNT_WITHOUT_LOCKED_TOKENS; "unlock tokens using a bit more weight that required")]
 fn test_on_idle_unlock_for_single_user(
 	availabe_weight: Weight,
@@ -827,21 +922,21 @@ fn test_on_idle_unlock_for_single_user(
 
 #[test_case(
 	Weight::from_parts(u64::MAX, 0),
-	calculate_estimated_weight(2, 9, 2),
+	calculate_estimated_weight(2, 6, 0),
 	vec![
 	(ALICE, ACCOUNT_WITHOUT_LOCKED_TOKENS),
 	(BOB, ACCOUNT_WITHOUT_LOCKED_TOKENS),
 	]; "unlocks tokens for both users with unlimited input weight")]
 #[test_case(
-	calculate_estimated_weight(2, 9, 2),
-	calculate_estimated_weight(2, 9, 2),
+	calculate_estimated_weight(2, 6, 0),
+	calculate_estimated_weight(2, 6, 0),
 	vec![
 	(ALICE, ACCOUNT_WITHOUT_LOCKED_TOKENS),
 	(BOB, ACCOUNT_WITHOUT_LOCKED_TOKENS),
 	]; "unlocks tokens for both users using exact required weight ")]
 #[test_case(
-	calculate_estimated_weight(1, 6, 1),
-	calculate_estimated_weight(1, 6, 1),
+	calculate_estimated_weight(1, 4, 0),
+	calculate_estimated_weight(1, 4, 0),
 	vec![
 	(ALICE, ACCOUNT_WITHOUT_LOCKED_TOKENS),
 	(BOB, ACCOUNT_WITH_LOCKED_TOKENS),
@@ -923,7 +1018,7 @@ fn test_unlock_stops_after_single_iteration_without_consuming_unnecessary_weight
 
 			fast_forward_blocks(3);
 			let consumed_weight = FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
-			assert_eq!(consumed_weight, calculate_estimated_weight(0, 6, 1));
+			assert_eq!(consumed_weight, calculate_estimated_weight(0, 3, 0));
 
 			assert_eq!(
 				Tokens::accounts(ALICE, NativeCurrencyId::get()),
@@ -953,48 +1048,33 @@ fn test_maintain_queue_with_subsequent_fee_locks_on_single_account() {
 		.build()
 		.execute_with(|| {
 			fast_forward_blocks(3);
-			assert_eq!(UnlockQueue::<Test>::get(0), None);
-			assert_eq!(UnlockQueue::<Test>::get(1), None);
-			assert_eq!(UnlockQueue::<Test>::get(2), None);
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(ALICE), None);
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(BOB), None);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 0);
+			assert_eq!(unlock_queue(), vec![]);
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-
-			assert_eq!(UnlockQueue::<Test>::get(0), Some(ALICE));
-			assert_eq!(UnlockQueue::<Test>::get(1), None);
-			assert_eq!(UnlockQueue::<Test>::get(2), None);
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(ALICE), Some(0));
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(BOB), None);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 1);
+			assert_eq!(unlock_queue(), vec![ALICE]);
 
 			fast_forward_blocks(1);
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
-			assert_eq!(UnlockQueue::<Test>::get(0), Some(ALICE));
-			assert_eq!(UnlockQueue::<Test>::get(1), Some(BOB));
-			assert_eq!(UnlockQueue::<Test>::get(2), None);
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(ALICE), Some(0));
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(BOB), Some(1));
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
+			assert_eq!(unlock_queue(), vec![ALICE, BOB]);
 
 			fast_forward_blocks(1);
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&CHARLIE).unwrap();
+			assert_eq!(unlock_queue(), vec![ALICE, BOB, CHARLIE]);
+
+			// moving an account does not leave any hole behind
+			fast_forward_blocks(1);
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
+			assert_eq!(unlock_queue(), vec![ALICE, CHARLIE, BOB]);
+
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-			assert_eq!(UnlockQueue::<Test>::get(0), None);
-			assert_eq!(UnlockQueue::<Test>::get(1), Some(BOB));
-			assert_eq!(UnlockQueue::<Test>::get(2), Some(ALICE));
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(ALICE), Some(2));
-			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::get(BOB), Some(1));
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 3);
+			assert_eq!(unlock_queue(), vec![CHARLIE, BOB, ALICE]);
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(unlock_queue(), vec![CHARLIE, BOB, ALICE]);
 		});
 }
 
 #[test]
-fn test_process_queue_and_ignore_outdated_items_in_unlock_queue_because_of_subsequent_process_fee_lock_calls(
-) {
+fn test_process_queue_after_subsequent_process_fee_lock_calls() {
 	ExtBuilder::new()
 		.create_token(NativeCurrencyId::get())
 		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
@@ -1004,36 +1084,34 @@ fn test_process_queue_and_ignore_outdated_items_in_unlock_queue_because_of_subse
 		.build()
 		.execute_with(|| {
 			fast_forward_blocks(3);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 0);
-
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 1);
 			fast_forward_blocks(1);
-
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
 			fast_forward_blocks(1);
 
 			FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
+			assert_eq!(unlock_queue(), vec![ALICE, BOB]);
 
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 3);
+			assert_eq!(unlock_queue(), vec![BOB, ALICE]);
 
-			// outdated queue item was consumed
+			// BOB expires first as ALICE extended the lock
+			fast_forward_blocks(PERIOD_LENGTH - 1);
 			FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 1);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 3);
+			assert_eq!(unlock_queue(), vec![ALICE]);
+			assert_eq!(
+				Tokens::accounts(BOB, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+
+			fast_forward_blocks(1);
+			FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
+			assert_eq!(unlock_queue(), vec![]);
 		});
 }
 
 #[test]
-fn test_process_queue_and_ignore_outdated_items_in_unlock_queue_because_of_manual_unlock() {
+fn test_process_queue_after_manual_unlock() {
 	ExtBuilder::new()
 		.create_token(NativeCurrencyId::get())
 		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
@@ -1043,35 +1121,25 @@ fn test_process_queue_and_ignore_outdated_items_in_unlock_queue_because_of_manua
 		.build()
 		.execute_with(|| {
 			fast_forward_blocks(3);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 0);
-
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 1);
 			fast_forward_blocks(PERIOD_LENGTH / 2);
-
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
 
 			FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
+			assert_eq!(unlock_queue(), vec![ALICE, BOB]);
 
 			fast_forward_blocks(PERIOD_LENGTH / 2);
 			FeeLock::unlock_fee(RuntimeOrigin::signed(ALICE).into()).unwrap();
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
+			assert_eq!(unlock_queue(), vec![BOB]);
 			assert_eq!(
 				Tokens::accounts(ALICE, NativeCurrencyId::get()),
 				ACCOUNT_WITHOUT_LOCKED_TOKENS
 			);
 
-			// nothing to unlock, but increment UnlockQueueBegin counter
-			FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
-			assert_eq!(UnlockQueueBegin::<Test>::get(), 1);
-			assert_eq!(UnlockQueueEnd::<Test>::get(), 2);
+			// nothing to unlock
+			let consumed_weight = FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT);
+			assert_eq!(consumed_weight, calculate_estimated_weight(0, 3, 0));
+			assert_eq!(unlock_queue(), vec![BOB]);
 
 			assert_eq!(Tokens::accounts(BOB, NativeCurrencyId::get()), ACCOUNT_WITH_LOCKED_TOKENS);
 		});
@@ -1087,7 +1155,7 @@ fn test_unlock_happens_in_order() {
 		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
 		.build()
 		.execute_with(|| {
-			let weight_for_single_unlock: Weight = calculate_estimated_weight(1, 6, 1);
+			let weight_for_single_unlock: Weight = calculate_estimated_weight(1, 4, 0);
 
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
@@ -1154,20 +1222,29 @@ fn test_queue_storage_is_cleaned_up() {
 		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
 		.build()
 		.execute_with(|| {
-			assert_eq!(UnlockQueue::<Test>::get(0), None);
-			assert_eq!(UnlockQueue::<Test>::get(1), None);
+			assert_eq!(UnlockQueueNodes::<Test>::get(ALICE), None);
+			assert_eq!(UnlockQueueNodes::<Test>::get(BOB), None);
 
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
-			assert_eq!(UnlockQueue::<Test>::get(0), Some(ALICE));
-			assert_eq!(UnlockQueue::<Test>::get(1), None);
+			assert_eq!(
+				UnlockQueueNodes::<Test>::get(ALICE),
+				Some(UnlockQueueNode { prev: None, next: None })
+			);
+			assert_eq!(UnlockQueueNodes::<Test>::get(BOB), None);
 			assert_eq!(
 				Tokens::accounts(ALICE, NativeCurrencyId::get()),
 				ACCOUNT_WITH_LOCKED_TOKENS
 			);
 
 			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
-			assert_eq!(UnlockQueue::<Test>::get(0), Some(ALICE));
-			assert_eq!(UnlockQueue::<Test>::get(1), Some(BOB));
+			assert_eq!(
+				UnlockQueueNodes::<Test>::get(ALICE),
+				Some(UnlockQueueNode { prev: None, next: Some(BOB) })
+			);
+			assert_eq!(
+				UnlockQueueNodes::<Test>::get(BOB),
+				Some(UnlockQueueNode { prev: Some(ALICE), next: None })
+			);
 			assert_eq!(Tokens::accounts(BOB, NativeCurrencyId::get()), ACCOUNT_WITH_LOCKED_TOKENS);
 
 			fast_forward_blocks(PERIOD_LENGTH);
@@ -1182,7 +1259,575 @@ fn test_queue_storage_is_cleaned_up() {
 				ACCOUNT_WITHOUT_LOCKED_TOKENS
 			);
 
-			assert_eq!(UnlockQueue::<Test>::get(0), None);
-			assert_eq!(UnlockQueue::<Test>::get(1), None);
+			assert_eq!(UnlockQueueNodes::<Test>::get(ALICE), None);
+			assert_eq!(UnlockQueueNodes::<Test>::get(BOB), None);
+			assert_eq!(UnlockQueueHead::<Test>::get(), None);
+			assert_eq!(UnlockQueueTail::<Test>::get(), None);
+		});
+}
+
+#[test]
+fn test_on_initialize_unlocks_min_number_of_locks_per_block() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(BOB, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(CHARLIE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&CHARLIE).unwrap();
+
+			// nothing expired yet
+			assert_eq!(
+				FeeLock::on_initialize(System::block_number()),
+				calculate_estimated_weight(0, 3, 0)
+			);
+
+			fast_forward_blocks(PERIOD_LENGTH);
+			assert_eq!(
+				FeeLock::on_initialize(System::block_number()),
+				calculate_estimated_weight(MinUnlocksPerBlock::get().into(), 6, 0)
+			);
+			assert_eq!(unlock_queue(), vec![CHARLIE]);
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+			assert_eq!(
+				Tokens::accounts(BOB, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+
+			fast_forward_blocks(1);
+			assert_eq!(
+				FeeLock::on_initialize(System::block_number()),
+				calculate_estimated_weight(1, 4, 0)
+			);
+			assert_eq!(unlock_queue(), vec![]);
+			assert_eq!(
+				Tokens::accounts(CHARLIE, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+		});
+}
+
+#[test]
+fn test_unlock_queue_drops_entries_without_lock() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			FeeLock::push_to_the_end_of_unlock_queue(&BOB);
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(unlock_queue(), vec![BOB, ALICE]);
+
+			// stale entry is dropped even though the lock of ALICE has not expired yet
+			assert_eq!(
+				FeeLock::on_idle(System::block_number(), UNLIMITED_WEIGHT),
+				calculate_estimated_weight(1, 5, 0)
+			);
+			assert_eq!(unlock_queue(), vec![ALICE]);
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITH_LOCKED_TOKENS
+			);
+		});
+}
+
+#[test]
+fn migration_rebuilds_unlock_queue_without_holes() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(BOB, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			use crate::migration::v3::{
+				FeeLockMetadataQeueuePosition, UnlockQueue, UnlockQueueBegin, UnlockQueueEnd,
+			};
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
+			UnlockQueueHead::<Test>::kill();
+			UnlockQueueTail::<Test>::kill();
+			let _ = UnlockQueueNodes::<Test>::clear(u32::MAX, None);
+
+			// ALICE was moved to the end of the queue, CHARLIE has no lock anymore
+			StorageVersion::new(2).put::<FeeLock>();
+			UnlockQueue::<Test>::insert(1, ALICE);
+			UnlockQueue::<Test>::insert(2, CHARLIE);
+			UnlockQueue::<Test>::insert(3, BOB);
+			UnlockQueue::<Test>::insert(4, ALICE);
+			FeeLockMetadataQeueuePosition::<Test>::insert(ALICE, 4);
+			FeeLockMetadataQeueuePosition::<Test>::insert(BOB, 3);
+			FeeLockMetadataQeueuePosition::<Test>::insert(CHARLIE, 2);
+			UnlockQueueBegin::<Test>::put(1);
+			UnlockQueueEnd::<Test>::put(5);
+
+			crate::migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+			assert_eq!(unlock_queue(), vec![BOB, ALICE]);
+			assert_eq!(UnlockQueue::<Test>::iter().count(), 0);
+			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::iter().count(), 0);
+			assert_eq!(UnlockQueueBegin::<Test>::get(), 0);
+			assert_eq!(UnlockQueueEnd::<Test>::get(), 0);
+			assert_eq!(FeeLock::on_chain_storage_version(), StorageVersion::new(3));
+		});
+}
+
+#[test]
+fn migration_visits_only_existing_unlock_queue_entries() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(BOB, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			use crate::migration::v3::{
+				FeeLockMetadataQeueuePosition, UnlockQueue, UnlockQueueBegin, UnlockQueueEnd,
+			};
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			UnlockQueueHead::<Test>::kill();
+			UnlockQueueTail::<Test>::kill();
+			let _ = UnlockQueueNodes::<Test>::clear(u32::MAX, None);
+
+			// range between begin and end is far too wide to be walked
+			StorageVersion::new(2).put::<FeeLock>();
+			UnlockQueue::<Test>::insert(u128::MAX - 1, BOB);
+			UnlockQueue::<Test>::insert(7, ALICE);
+			FeeLockMetadataQeueuePosition::<Test>::insert(BOB, u128::MAX - 1);
+			FeeLockMetadataQeueuePosition::<Test>::insert(ALICE, 7);
+			UnlockQueueBegin::<Test>::put(0);
+			UnlockQueueEnd::<Test>::put(u128::MAX);
+
+			crate::migration::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+			assert_eq!(unlock_queue(), vec![ALICE, BOB]);
+			assert_eq!(UnlockQueue::<Test>::iter().count(), 0);
+			assert_eq!(FeeLockMetadataQeueuePosition::<Test>::iter().count(), 0);
+			assert_eq!(FeeLock::on_chain_storage_version(), StorageVersion::new(3));
+		});
+}
+
+#[test]
+fn update_fee_lock_metadata_sets_per_token_thresholds_and_tiers() {
+	ExtBuilder::new()
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				None,
+				None,
+				Some(vec![(1, true)]),
+				Some(vec![(2, Some(5000)), (NativeCurrencyId::get(), Some(2000))]),
+				Some(vec![(3, 2 * FEE_LOCK_AMOUNT), (5, 5 * FEE_LOCK_AMOUNT)]),
+			));
+
+			let metadata = FeeLock::get_fee_lock_metadata().unwrap();
+			assert_eq!(metadata.swap_value_threshold_for(NativeCurrencyId::get()), Some(2000));
+			assert_eq!(metadata.swap_value_threshold_for(1), Some(SWAP_VALUE_THRESHOLD));
+			assert_eq!(metadata.swap_value_threshold_for(2), Some(5000));
+			assert_eq!(metadata.swap_value_threshold_for(3), None);
+			assert!(!metadata.is_whitelisted(3));
+
+			assert_eq!(metadata.fee_lock_amount_for(1), FEE_LOCK_AMOUNT);
+			assert_eq!(metadata.fee_lock_amount_for(2), FEE_LOCK_AMOUNT);
+			assert_eq!(metadata.fee_lock_amount_for(3), 2 * FEE_LOCK_AMOUNT);
+			assert_eq!(metadata.fee_lock_amount_for(4), 2 * FEE_LOCK_AMOUNT);
+			assert_eq!(metadata.fee_lock_amount_for(10), 5 * FEE_LOCK_AMOUNT);
+
+			// reverting to the default threshold keeps the token curated
+			assert_ok!(FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				None,
+				None,
+				None,
+				Some(vec![(2, None)]),
+				None,
+			));
+			let metadata = FeeLock::get_fee_lock_metadata().unwrap();
+			assert_eq!(metadata.swap_value_threshold_for(2), Some(SWAP_VALUE_THRESHOLD));
+			assert_eq!(metadata.fee_lock_tiers.len(), 2);
+
+			assert_noop!(
+				FeeLock::update_fee_lock_metadata(
+					RuntimeOrigin::root(),
+					None,
+					None,
+					None,
+					None,
+					Some(vec![(2, Some(0))]),
+					None,
+				),
+				Error::<Test>::InvalidFeeLockMetadata
+			);
+			assert_noop!(
+				FeeLock::update_fee_lock_metadata(
+					RuntimeOrigin::root(),
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(vec![(5, FEE_LOCK_AMOUNT), (3, FEE_LOCK_AMOUNT)]),
+				),
+				Error::<Test>::InvalidFeeLockMetadata
+			);
+			assert_noop!(
+				FeeLock::update_fee_lock_metadata(
+					RuntimeOrigin::root(),
+					None,
+					None,
+					None,
+					None,
+					None,
+					Some(
+						(2..=(MaxFeeLockTiers::get() + 2)).map(|n| (n, FEE_LOCK_AMOUNT)).collect()
+					),
+				),
+				Error::<Test>::MaxFeeLockTiersLimitExceeded
+			);
+		});
+}
+
+#[test]
+fn process_fee_lock_uses_tiered_amounts_within_period() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(vec![(2, 2 * FEE_LOCK_AMOUNT), (3, 4 * FEE_LOCK_AMOUNT)]),
+			));
+
+			for (swaps, total_locked) in
+				[(1, 1), (2, 3), (3, 7), (4, 11)].iter().map(|(s, t)| (*s, t * FEE_LOCK_AMOUNT))
+			{
+				<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+				assert_eq!(FeeLock::get_account_swaps_in_period(ALICE), swaps);
+				assert_eq!(
+					FeeLock::get_account_fee_lock_data(ALICE).total_fee_lock_amount,
+					total_locked
+				);
+				assert_eq!(Tokens::accounts(ALICE, NativeCurrencyId::get()).reserved, total_locked);
+			}
+
+			// the first swap in the next period locks the base amount again
+			fast_forward_blocks(PERIOD_LENGTH);
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(FeeLock::get_account_swaps_in_period(ALICE), 1);
+			assert_eq!(Tokens::accounts(ALICE, NativeCurrencyId::get()).reserved, FEE_LOCK_AMOUNT);
+
+			fast_forward_blocks(PERIOD_LENGTH);
+			assert_ok!(FeeLock::unlock_fee(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(FeeLock::get_account_swaps_in_period(ALICE), 0);
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+		});
+}
+
+#[test]
+fn migration_translates_whitelisted_tokens_to_thresholds_map() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<FeeLock>();
+		frame_support::storage::unhashed::put(
+			&FeeLockMetadata::<Test>::hashed_key(),
+			&crate::migration::v1::OldFeeLockMetadataInfo::<Test> {
+				period_length: PERIOD_LENGTH,
+				fee_lock_amount: FEE_LOCK_AMOUNT,
+				swap_value_threshold: SWAP_VALUE_THRESHOLD,
+				whitelisted_tokens: BoundedBTreeSet::try_from(
+					vec![1, 2].into_iter().collect::<BTreeSet<TokenId>>(),
+				)
+				.unwrap(),
+			},
+		);
+
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let metadata = FeeLock::get_fee_lock_metadata().unwrap();
+		assert_eq!(metadata.period_length, PERIOD_LENGTH);
+		assert_eq!(metadata.fee_lock_amount, FEE_LOCK_AMOUNT);
+		assert_eq!(metadata.swap_value_threshold_for(1), Some(SWAP_VALUE_THRESHOLD));
+		assert_eq!(metadata.swap_value_threshold_for(2), Some(SWAP_VALUE_THRESHOLD));
+		assert_eq!(metadata.swap_value_threshold_for(3), None);
+		assert!(metadata.fee_lock_tiers.is_empty());
+		assert_eq!(FeeLock::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn migration_records_native_token_as_lock_token() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<FeeLock>();
+		frame_support::storage::unhashed::put(
+			&AccountFeeLockData::<Test>::hashed_key_for(ALICE),
+			&crate::migration::v2::OldAccountFeeLockDataInfo::<Test> {
+				total_fee_lock_amount: FEE_LOCK_AMOUNT,
+				last_fee_lock_block: 1,
+			},
+		);
+
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			FeeLock::get_account_fee_lock_data(ALICE),
+			AccountFeeLockDataInfo {
+				total_fee_lock_amount: FEE_LOCK_AMOUNT,
+				last_fee_lock_block: 1,
+				lock_token: NATIVE_CURRENCY_ID,
+			}
+		);
+		assert_eq!(FeeLock::on_chain_storage_version(), StorageVersion::new(2));
+	});
+}
+
+#[test]
+fn preview_matches_processed_fee_lock_and_unlock() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(BOB, NativeCurrencyId::get(), FEE_LOCK_AMOUNT - 1)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(vec![(2, 3 * FEE_LOCK_AMOUNT)]),
+			));
+
+			assert_eq!(FeeLock::get_unlockable_at(&ALICE), None);
+			assert_eq!(
+				FeeLock::preview_fee_lock(&ALICE),
+				Some((NATIVE_CURRENCY_ID, FEE_LOCK_AMOUNT, FEE_LOCK_AMOUNT))
+			);
+			assert_eq!(FeeLock::preview_fee_lock(&BOB), None);
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(FeeLock::get_unlockable_at(&ALICE), Some(PERIOD_LENGTH));
+			assert_eq!(
+				FeeLock::preview_fee_lock(&ALICE),
+				Some((NATIVE_CURRENCY_ID, 3 * FEE_LOCK_AMOUNT, 4 * FEE_LOCK_AMOUNT))
+			);
+			assert_eq!(FeeLock::preview_unlock_fee(&ALICE), 0);
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(
+				FeeLock::get_account_fee_lock_data(ALICE).total_fee_lock_amount,
+				4 * FEE_LOCK_AMOUNT
+			);
+
+			fast_forward_blocks(PERIOD_LENGTH);
+			assert_eq!(
+				FeeLock::preview_fee_lock(&ALICE),
+				Some((NATIVE_CURRENCY_ID, FEE_LOCK_AMOUNT, FEE_LOCK_AMOUNT))
+			);
+			assert_eq!(FeeLock::preview_unlock_fee(&ALICE), 4 * FEE_LOCK_AMOUNT);
+			assert_ok!(FeeLock::unlock_fee(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(FeeLock::get_unlockable_at(&ALICE), None);
+		});
+}
+
+#[test]
+fn fee_lock_is_reduced_or_skipped_for_accounts_with_positions() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(BOB, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.mint(CHARLIE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			PositionsValues::set(vec![(BOB, 5_000), (CHARLIE, 10_000)]);
+
+			assert_noop!(
+				FeeLock::update_fee_lock_exemption(
+					RuntimeOrigin::root(),
+					Some(FeeLockExemptionInfo {
+						reduction_threshold: 10_000,
+						reduction: Percent::from_percent(50),
+						exemption_threshold: 5_000,
+					}),
+				),
+				Error::<Test>::InvalidFeeLockExemption
+			);
+			assert_ok!(FeeLock::update_fee_lock_exemption(
+				RuntimeOrigin::root(),
+				Some(FeeLockExemptionInfo {
+					reduction_threshold: 5_000,
+					reduction: Percent::from_percent(50),
+					exemption_threshold: 10_000,
+				}),
+			));
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			System::assert_last_event(RuntimeEvent::FeeLock(Event::FeeLocked {
+				who: ALICE,
+				lock_token: NATIVE_CURRENCY_ID,
+				lock_amount: FEE_LOCK_AMOUNT,
+				total_locked: FEE_LOCK_AMOUNT,
+				exemption: FeeLockExemptionLevel::NotExempt,
+			}));
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).unwrap();
+			System::assert_last_event(RuntimeEvent::FeeLock(Event::FeeLocked {
+				who: BOB,
+				lock_token: NATIVE_CURRENCY_ID,
+				lock_amount: FEE_LOCK_AMOUNT / 2,
+				total_locked: FEE_LOCK_AMOUNT / 2,
+				exemption: FeeLockExemptionLevel::Reduced,
+			}));
+			assert_eq!(
+				Tokens::accounts(BOB, NativeCurrencyId::get()).reserved,
+				FEE_LOCK_AMOUNT / 2
+			);
+
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&CHARLIE).unwrap();
+			System::assert_last_event(RuntimeEvent::FeeLock(Event::FeeLocked {
+				who: CHARLIE,
+				lock_token: NATIVE_CURRENCY_ID,
+				lock_amount: 0,
+				total_locked: 0,
+				exemption: FeeLockExemptionLevel::Exempt,
+			}));
+			assert_eq!(
+				Tokens::accounts(CHARLIE, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+			assert!(!AccountFeeLockData::<Test>::contains_key(CHARLIE));
+			assert_eq!(UnlockQueueNodes::<Test>::get(CHARLIE), None);
+		});
+}
+
+#[test]
+fn allowlisted_account_releases_lock_on_next_period() {
+	ExtBuilder::new()
+		.create_token(NativeCurrencyId::get())
+		.mint(ALICE, NativeCurrencyId::get(), INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITH_LOCKED_TOKENS
+			);
+
+			assert_noop!(
+				FeeLock::set_fee_lock_exempt_account(RuntimeOrigin::signed(ALICE), ALICE, true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(FeeLock::set_fee_lock_exempt_account(RuntimeOrigin::root(), ALICE, true));
+			assert_eq!(
+				FeeLock::get_fee_lock_exemption_level(&ALICE),
+				FeeLockExemptionLevel::Exempt
+			);
+
+			// lock taken within the period stays in place
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITH_LOCKED_TOKENS
+			);
+
+			fast_forward_blocks(PERIOD_LENGTH);
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(
+				Tokens::accounts(ALICE, NativeCurrencyId::get()),
+				ACCOUNT_WITHOUT_LOCKED_TOKENS
+			);
+			assert_eq!(unlock_queue(), vec![]);
+
+			assert_ok!(FeeLock::set_fee_lock_exempt_account(RuntimeOrigin::root(), ALICE, false));
+			assert_eq!(
+				FeeLock::get_fee_lock_exemption_level(&ALICE),
+				FeeLockExemptionLevel::NotExempt
+			);
+		});
+}
+
+#[test]
+fn fee_lock_is_reserved_in_curated_token_when_native_is_not_affordable() {
+	ExtBuilder::new()
+		.create_token(3)
+		.mint(ALICE, 1, INITIAL_AMOUNT)
+		.mint(BOB, 3, INITIAL_AMOUNT)
+		.initialize_fee_locks(PERIOD_LENGTH, FEE_LOCK_AMOUNT, SWAP_VALUE_THRESHOLD)
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeLock::update_fee_lock_metadata(
+				RuntimeOrigin::root(),
+				None,
+				None,
+				None,
+				Some(vec![(1, true), (3, true)]),
+				None,
+				None,
+			));
+
+			// pool (0, 1) values a single native token at 2 tokens of id 1
+			assert_eq!(FeeLock::get_fee_lock_amount_in_token(1, FEE_LOCK_AMOUNT), Some(2_000));
+			assert_eq!(FeeLock::preview_fee_lock(&ALICE), Some((1, 2_000, 2_000)));
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(Tokens::accounts(ALICE, 1).reserved, 4_000);
+			assert_eq!(
+				FeeLock::get_account_fee_lock_data(ALICE),
+				AccountFeeLockDataInfo {
+					total_fee_lock_amount: 4_000,
+					last_fee_lock_block: 0,
+					lock_token: 1,
+				}
+			);
+
+			// token 3 has no valuation so it cannot be used for the lock
+			assert_eq!(FeeLock::preview_fee_lock(&BOB), None);
+			assert!(<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&BOB).is_err());
+			assert_eq!(Tokens::accounts(BOB, 3).reserved, 0);
+
+			// once native tokens are available the lock of the next period moves to them
+			fast_forward_blocks(PERIOD_LENGTH);
+			assert_ok!(Tokens::mint(
+				RuntimeOrigin::root(),
+				NATIVE_CURRENCY_ID,
+				ALICE,
+				INITIAL_AMOUNT
+			));
+			<FeeLock as FeeLockTriggerTrait<_, _, _>>::process_fee_lock(&ALICE).unwrap();
+			assert_eq!(Tokens::accounts(ALICE, 1).reserved, 0);
+			assert_eq!(Tokens::accounts(ALICE, NATIVE_CURRENCY_ID).reserved, FEE_LOCK_AMOUNT);
+			assert_eq!(FeeLock::get_account_fee_lock_data(ALICE).lock_token, NATIVE_CURRENCY_ID);
+
+			fast_forward_blocks(PERIOD_LENGTH);
+			assert_ok!(FeeLock::unlock_fee(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(Tokens::accounts(ALICE, NATIVE_CURRENCY_ID).reserved, 0);
 		});
 }
@@ -24,7 +24,7 @@ use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use orml_tokens::MultiTokenCurrencyExtended;
 
-use crate::{Pallet as Issuance, TgeInfo};
+use crate::{IssuanceConfigUpdate, IssuanceSchedule, Pallet as Issuance, TgeInfo};
 
 const SEED: u32 = u32::max_value();
 const TGE_AMOUNT: u32 = 1_000_000;
@@ -98,5 +98,29 @@ benchmarks! {
 
 	}
 
+	update_issuance_config {
+		assert_ok!(Issuance::<T>::finalize_tge(RawOrigin::Root.into()));
+		assert_ok!(Issuance::<T>::init_issuance_config(RawOrigin::Root.into()));
+		assert!(
+			PendingIssuanceConfigUpdate::<T>::get().is_none()
+		);
+
+		let schedule = IssuanceSchedule::ExponentialDecay {
+			initial_session_issuance: TGE_AMOUNT.into(),
+			decay: Perbill::from_percent(5),
+			period_sessions: 1_000,
+		};
+	}: _(RawOrigin::Root, Perbill::from_percent(60), Perbill::from_percent(40), schedule.clone())
+	verify {
+		assert_eq!(
+			PendingIssuanceConfigUpdate::<T>::get(),
+			Some(IssuanceConfigUpdate {
+				liquidity_mining_split: Perbill::from_percent(60),
+				staking_split: Perbill::from_percent(40),
+				schedule,
+			})
+		);
+	}
+
 	impl_benchmark_test_suite!(Issuance, crate::mock::new_test_ext_without_issuance_config(), crate::mock::Test)
 }
@@ -42,6 +42,28 @@ pub struct IssuanceInfo<Balance> {
 	pub total_crowdloan_allocation: Balance,
 }
 
+/// Schedule according to which the issuance of a session is computed
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum IssuanceSchedule<Balance> {
+	// Total issuance of MGA grows linearly to `cap` over `linear_issuance_blocks`
+	Linear,
+	// Issuance of a session starts at `initial_session_issuance` and decreases by `decay` every
+	// `period_sessions` sessions, e.g. yearly
+	ExponentialDecay { initial_session_issuance: Balance, decay: Perbill, period_sessions: u32 },
+	// Current total issuance of MGA is inflated by `rate` every `period_sessions` sessions,
+	// distributed equally among the sessions of a period
+	FixedInflation { rate: Perbill, period_sessions: u32 },
+}
+
+/// Issuance configuration change that is applied when the issuance of the next session is
+/// computed
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct IssuanceConfigUpdate<Balance> {
+	pub liquidity_mining_split: Perbill,
+	pub staking_split: Perbill,
+	pub schedule: IssuanceSchedule<Balance>,
+}
+
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct TgeInfo<AccountId, Balance> {
 	// The tge target
@@ -55,6 +77,7 @@ pub trait WeightInfo {
 	fn init_issuance_config() -> Weight;
 	fn finalize_tge() -> Weight;
 	fn execute_tge(x: u32) -> Weight;
+	fn update_issuance_config() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -77,6 +100,12 @@ impl WeightInfo for () {
 			// Standard Error: 1_000
 			.saturating_add((Weight::from_parts(130_000, 0)).saturating_mul(l as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	// Storage: Issuance IssuanceConfigStore (r:1 w:0)
+	// Storage: Issuance PendingIssuanceConfigUpdate (r:0 w:1)
+	fn update_issuance_config() -> Weight {
+		Weight::from_parts(16_240_000, 0)
+	}
 }
 
 pub use pallet::*;
@@ -160,6 +189,19 @@ pub mod pallet {
 	pub type IssuanceConfigStore<T: Config> =
 		StorageValue<_, IssuanceInfo<BalanceOf<T>>, OptionQuery>;
 
+	/// Issuance schedule along with the session it applies from, the linear schedule is used
+	/// when not set
+	#[pallet::storage]
+	#[pallet::getter(fn get_issuance_schedule)]
+	pub type IssuanceScheduleStore<T: Config> =
+		StorageValue<_, (u32, IssuanceSchedule<BalanceOf<T>>), OptionQuery>;
+
+	/// Issuance config update waiting for the beginning of the next session
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_issuance_config_update)]
+	pub type PendingIssuanceConfigUpdate<T: Config> =
+		StorageValue<_, IssuanceConfigUpdate<BalanceOf<T>>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn get_tge_total)]
 	pub type TGETotal<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
@@ -272,6 +314,49 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Schedules an update of issuance splits and of the schedule the issuance is computed
+		/// with. The update is applied when the issuance of the next session is computed, so
+		/// issuance of the current session is never affected. Scheduling another update before
+		/// that replaces the pending one. None of the schedules issues beyond the issuance cap.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::update_issuance_config())]
+		pub fn update_issuance_config(
+			origin: OriginFor<T>,
+			liquidity_mining_split: Perbill,
+			staking_split: Perbill,
+			schedule: IssuanceSchedule<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				IssuanceConfigStore::<T>::get().is_some(),
+				Error::<T>::IssuanceConfigNotInitialized
+			);
+			ensure!(
+				liquidity_mining_split
+					.checked_add(&staking_split)
+					.ok_or(Error::<T>::IssuanceConfigInvalid)? ==
+					Perbill::from_percent(100),
+				Error::<T>::IssuanceConfigInvalid
+			);
+			match schedule {
+				IssuanceSchedule::Linear => {},
+				IssuanceSchedule::ExponentialDecay { decay, period_sessions, .. } => ensure!(
+					period_sessions != u32::zero() && decay < Perbill::from_percent(100),
+					Error::<T>::IssuanceConfigInvalid
+				),
+				IssuanceSchedule::FixedInflation { period_sessions, .. } =>
+					ensure!(period_sessions != u32::zero(), Error::<T>::IssuanceConfigInvalid),
+			}
+
+			let update = IssuanceConfigUpdate { liquidity_mining_split, staking_split, schedule };
+			PendingIssuanceConfigUpdate::<T>::put(update.clone());
+
+			Pallet::<T>::deposit_event(Event::IssuanceConfigUpdateScheduled(update));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -283,6 +368,10 @@ pub mod pallet {
 		SessionIssuanceRecorded(u32, BalanceOf<T>, BalanceOf<T>),
 		/// Issuance configuration has been finalized
 		IssuanceConfigInitialized(IssuanceInfo<BalanceOf<T>>),
+		/// Issuance config update scheduled for the next session
+		IssuanceConfigUpdateScheduled(IssuanceConfigUpdate<BalanceOf<T>>),
+		/// Issuance config updated, applies from the given session
+		IssuanceConfigUpdated(u32, IssuanceInfo<BalanceOf<T>>, IssuanceSchedule<BalanceOf<T>>),
 		/// TGE has been finalized
 		TGEFinalized,
 		/// A TGE instance has failed
@@ -381,9 +470,111 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Applies [`PendingIssuanceConfigUpdate`] starting from `current_round`
+	fn apply_pending_issuance_config_update(current_round: u32) {
+		let update = match PendingIssuanceConfigUpdate::<T>::take() {
+			Some(update) => update,
+			None => return,
+		};
+
+		IssuanceConfigStore::<T>::mutate(|issuance_config| {
+			if let Some(issuance_config) = issuance_config {
+				issuance_config.liquidity_mining_split = update.liquidity_mining_split;
+				issuance_config.staking_split = update.staking_split;
+			}
+		});
+		IssuanceScheduleStore::<T>::put((current_round, update.schedule.clone()));
+
+		if let Some(issuance_config) = IssuanceConfigStore::<T>::get() {
+			Pallet::<T>::deposit_event(Event::IssuanceConfigUpdated(
+				current_round,
+				issuance_config,
+				update.schedule,
+			));
+		}
+	}
+
 	pub fn calculate_and_store_round_issuance(current_round: u32) -> DispatchResult {
+		Self::apply_pending_issuance_config_update(current_round);
+
 		let issuance_config =
 			IssuanceConfigStore::<T>::get().ok_or(Error::<T>::IssuanceConfigNotInitialized)?;
+
+		let current_round_issuance = match IssuanceScheduleStore::<T>::get() {
+			None | Some((_, IssuanceSchedule::Linear)) =>
+				Self::linear_round_issuance(&issuance_config, current_round)?,
+			Some((
+				start_round,
+				IssuanceSchedule::ExponentialDecay {
+					initial_session_issuance,
+					decay,
+					period_sessions,
+				},
+			)) => {
+				let periods = current_round
+					.saturating_sub(start_round)
+					.checked_div(period_sessions)
+					.ok_or(Error::<T>::MathError)?;
+				Perbill::from_percent(100)
+					.saturating_sub(decay)
+					.saturating_pow(periods as usize) *
+					initial_session_issuance
+			},
+			Some((_, IssuanceSchedule::FixedInflation { rate, period_sessions })) => (rate *
+				T::Tokens::total_issuance(T::NativeCurrencyId::get().into()))
+			.checked_div(&period_sessions.into())
+			.ok_or(Error::<T>::MathError)?,
+		};
+		// None of the schedules is allowed to issue beyond the cap
+		let current_round_issuance = current_round_issuance.min(
+			issuance_config
+				.cap
+				.saturating_sub(T::Tokens::total_issuance(T::NativeCurrencyId::get().into())),
+		);
+
+		let liquidity_mining_issuance =
+			issuance_config.liquidity_mining_split * current_round_issuance;
+
+		let staking_issuance = issuance_config.staking_split * current_round_issuance;
+
+		T::LiquidityMiningApi::distribute_rewards(liquidity_mining_issuance);
+
+		{
+			let liquidity_mining_issuance_issued = T::Tokens::deposit_creating(
+				T::NativeCurrencyId::get().into(),
+				&T::LiquidityMiningIssuanceVault::get(),
+				liquidity_mining_issuance,
+			);
+			let staking_issuance_issued = T::Tokens::deposit_creating(
+				T::NativeCurrencyId::get().into(),
+				&T::StakingIssuanceVault::get(),
+				staking_issuance,
+			);
+			Self::deposit_event(Event::SessionIssuanceIssued(
+				current_round,
+				liquidity_mining_issuance_issued.peek(),
+				staking_issuance_issued.peek(),
+			));
+		}
+
+		SessionIssuance::<T>::insert(
+			current_round,
+			Some((liquidity_mining_issuance, staking_issuance)),
+		);
+
+		Pallet::<T>::deposit_event(Event::SessionIssuanceRecorded(
+			current_round,
+			liquidity_mining_issuance,
+			staking_issuance,
+		));
+
+		Ok(())
+	}
+
+	fn linear_round_issuance(
+		issuance_config: &IssuanceInfo<BalanceOf<T>>,
+		current_round: u32,
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let to_be_issued: BalanceOf<T> = issuance_config
 			.cap
 			.checked_sub(&issuance_config.issuance_at_init)
@@ -424,43 +615,7 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		let liquidity_mining_issuance =
-			issuance_config.liquidity_mining_split * current_round_issuance;
-
-		let staking_issuance = issuance_config.staking_split * current_round_issuance;
-
-		T::LiquidityMiningApi::distribute_rewards(liquidity_mining_issuance);
-
-		{
-			let liquidity_mining_issuance_issued = T::Tokens::deposit_creating(
-				T::NativeCurrencyId::get().into(),
-				&T::LiquidityMiningIssuanceVault::get(),
-				liquidity_mining_issuance,
-			);
-			let staking_issuance_issued = T::Tokens::deposit_creating(
-				T::NativeCurrencyId::get().into(),
-				&T::StakingIssuanceVault::get(),
-				staking_issuance,
-			);
-			Self::deposit_event(Event::SessionIssuanceIssued(
-				current_round,
-				liquidity_mining_issuance_issued.peek(),
-				staking_issuance_issued.peek(),
-			));
-		}
-
-		SessionIssuance::<T>::insert(
-			current_round,
-			Some((liquidity_mining_issuance, staking_issuance)),
-		);
-
-		Pallet::<T>::deposit_event(Event::SessionIssuanceRecorded(
-			current_round,
-			liquidity_mining_issuance,
-			staking_issuance,
-		));
-
-		Ok(())
+		Ok(current_round_issuance)
 	}
 
 	pub fn clear_round_issuance_history(current_round: u32) -> DispatchResult {
@@ -1,7 +1,8 @@
 use super::*;
 use mock::{
 	new_test_ext, new_test_ext_without_issuance_config, roll_to_while_minting, BlocksPerRound,
-	Issuance, RuntimeOrigin, StakeCurrency, System, Test, Tokens, Vesting, MGA_TOKEN_ID,
+	Issuance, IssuanceCap, RuntimeEvent, RuntimeOrigin, StakeCurrency, System, Test, Tokens,
+	Vesting, MGA_TOKEN_ID,
 };
 use sp_runtime::SaturatedConversion;
 
@@ -347,3 +348,187 @@ fn issuance_after_linear_period_never_execeeds_linear() {
 		assert_eq!(3902430240, Tokens::total_issuance(0u32));
 	});
 }
+
+#[test]
+fn issuance_config_update_applies_from_next_session() {
+	new_test_ext().execute_with(|| {
+		let schedule = IssuanceSchedule::ExponentialDecay {
+			initial_session_issuance: 500_000u128,
+			decay: Perbill::from_percent(10),
+			period_sessions: 2,
+		};
+		assert_ok!(Issuance::update_issuance_config(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(40),
+			Perbill::from_percent(60),
+			schedule.clone(),
+		));
+		assert_eq!(
+			Issuance::get_pending_issuance_config_update(),
+			Some(IssuanceConfigUpdate {
+				liquidity_mining_split: Perbill::from_percent(40),
+				staking_split: Perbill::from_percent(60),
+				schedule: schedule.clone(),
+			})
+		);
+		assert_eq!(Issuance::get_issuance_schedule(), None);
+
+		// Issuance of the current session is not affected
+		roll_to_while_minting(4, Some(81008));
+
+		assert_eq!(Issuance::get_pending_issuance_config_update(), None);
+		assert_eq!(Issuance::get_issuance_schedule(), Some((1, schedule.clone())));
+		let issuance_config = Issuance::get_issuance_config().unwrap();
+		assert_eq!(issuance_config.liquidity_mining_split, Perbill::from_percent(40));
+		assert_eq!(issuance_config.staking_split, Perbill::from_percent(60));
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Issuance(Event::IssuanceConfigUpdated(
+				1,
+				issuance_config.clone(),
+				schedule.clone()
+			))));
+		assert_eq!(Issuance::get_session_issuance(1), Some((200_000, 300_000)));
+	});
+}
+
+#[test]
+fn exponential_decay_issuance_decreases_every_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Issuance::update_issuance_config(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(50),
+			Perbill::from_percent(50),
+			IssuanceSchedule::ExponentialDecay {
+				initial_session_issuance: 500_000u128,
+				decay: Perbill::from_percent(10),
+				period_sessions: 2,
+			},
+		));
+
+		roll_to_while_minting(4, Some(81008));
+		roll_to_while_minting(14, Some(100_000));
+		roll_to_while_minting(24, Some(90_000));
+		roll_to_while_minting(34, Some(81_000));
+	});
+}
+
+#[test]
+fn fixed_inflation_issuance_is_based_on_total_issuance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Issuance::update_issuance_config(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(50),
+			Perbill::from_percent(50),
+			IssuanceSchedule::FixedInflation {
+				rate: Perbill::from_percent(10),
+				period_sessions: 10
+			},
+		));
+
+		roll_to_while_minting(3, Some(81008));
+		let total_issuance = Tokens::total_issuance(0u32);
+		roll_to_while_minting(4, Some(81008));
+
+		let session_issuance = Perbill::from_percent(10) * total_issuance / 10;
+		assert_eq!(
+			Issuance::get_session_issuance(1),
+			Some((session_issuance / 2, session_issuance / 2))
+		);
+		assert_eq!(Tokens::total_issuance(0u32), total_issuance + session_issuance);
+	});
+}
+
+#[test]
+fn fixed_inflation_issuance_stops_at_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Issuance::update_issuance_config(
+			RuntimeOrigin::root(),
+			Perbill::from_percent(100),
+			Perbill::from_percent(0),
+			IssuanceSchedule::FixedInflation {
+				rate: Perbill::from_percent(100),
+				period_sessions: 1
+			},
+		));
+
+		roll_to_while_minting(3, Some(81008));
+		let total_issuance = Tokens::total_issuance(0u32);
+		roll_to_while_minting(4, Some(81008));
+
+		// doubling the total issuance would overshoot the cap
+		assert_eq!(
+			Issuance::get_session_issuance(1),
+			Some((IssuanceCap::get() - total_issuance, 0))
+		);
+		assert_eq!(Tokens::total_issuance(0u32), IssuanceCap::get());
+
+		roll_to_while_minting(14, None);
+		assert_eq!(Issuance::get_session_issuance(2), Some((0, 0)));
+		assert_eq!(Issuance::get_session_issuance(3), Some((0, 0)));
+		assert_eq!(Tokens::total_issuance(0u32), IssuanceCap::get());
+	});
+}
+
+#[test]
+fn cannot_update_issuance_config_with_invalid_params() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Issuance::update_issuance_config(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(50),
+				Perbill::from_percent(50),
+				IssuanceSchedule::Linear,
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Issuance::update_issuance_config(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(50),
+				Perbill::from_percent(40),
+				IssuanceSchedule::Linear,
+			),
+			Error::<Test>::IssuanceConfigInvalid
+		);
+		assert_noop!(
+			Issuance::update_issuance_config(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(50),
+				Perbill::from_percent(50),
+				IssuanceSchedule::ExponentialDecay {
+					initial_session_issuance: 500_000u128,
+					decay: Perbill::from_percent(100),
+					period_sessions: 2,
+				},
+			),
+			Error::<Test>::IssuanceConfigInvalid
+		);
+		assert_noop!(
+			Issuance::update_issuance_config(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(50),
+				Perbill::from_percent(50),
+				IssuanceSchedule::FixedInflation {
+					rate: Perbill::from_percent(10),
+					period_sessions: 0
+				},
+			),
+			Error::<Test>::IssuanceConfigInvalid
+		);
+	});
+}
+
+#[test]
+fn cannot_update_issuance_config_before_it_is_initialized() {
+	new_test_ext_without_issuance_config().execute_with(|| {
+		assert_noop!(
+			Issuance::update_issuance_config(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(50),
+				Perbill::from_percent(50),
+				IssuanceSchedule::Linear,
+			),
+			Error::<Test>::IssuanceConfigNotInitialized
+		);
+	});
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2021 Mangata team
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use multipurpose_liquidity_runtime_api::MultipurposeLiquidityApi as MultipurposeLiquidityRuntimeApi;
+use multipurpose_liquidity_runtime_api::{RpcRelockStatus, RpcReserveStatus};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait MultipurposeLiquidityApi<BlockHash, Balance, TokenId, AccountId, BlockNumber> {
+	/// Returns reserves of given token held by multipurpose liquidity for the account, together
+	/// with its relock instances
+	///
+	/// * `account` - user account address
+	/// * `token_id` - id of the (liquidity) token
+	/// * `at` - optional block hash
+	#[method(name = "mpl_get_reserve_status")]
+	fn get_reserve_status(
+		&self,
+		account: AccountId,
+		token_id: TokenId,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcReserveStatus<NumberOrHex, TokenId, BlockNumber>>;
+
+	/// Returns reserves of all the tokens held by multipurpose liquidity for the account
+	///
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "mpl_get_account_reserve_statuses")]
+	fn get_account_reserve_statuses(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcReserveStatus<NumberOrHex, TokenId, BlockNumber>>>;
+
+	/// Returns amount of relocked tokens released by vesting schedules of relock instances at
+	/// given (possibly future) block
+	///
+	/// * `account` - user account address
+	/// * `token_id` - id of the (liquidity) token
+	/// * `block` - block number
+	/// * `at` - optional block hash
+	#[method(name = "mpl_get_relock_unlocked_amount_at")]
+	fn get_relock_unlocked_amount_at(
+		&self,
+		account: AccountId,
+		token_id: TokenId,
+		block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+}
+
+pub struct MultipurposeLiquidity<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, P> MultipurposeLiquidity<C, P> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn into_rpc_error(e: impl std::fmt::Debug) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		1,
+		"Unable to serve the request",
+		Some(format!("{:?}", e)),
+	)))
+}
+
+fn reserve_status_into_rpc<Balance, TokenId, BlockNumber>(
+	status: RpcReserveStatus<Balance, TokenId, BlockNumber>,
+) -> RpcReserveStatus<NumberOrHex, TokenId, BlockNumber>
+where
+	Balance: Into<NumberOrHex>,
+{
+	RpcReserveStatus {
+		token_id: status.token_id,
+		staked_unactivated_reserves: status.staked_unactivated_reserves.into(),
+		activated_unstaked_reserves: status.activated_unstaked_reserves.into(),
+		staked_and_activated_reserves: status.staked_and_activated_reserves.into(),
+		unspent_reserves: status.unspent_reserves.into(),
+		relock_amount: status.relock_amount.into(),
+		max_instant_unreserve_amount: status.max_instant_unreserve_amount.into(),
+		relocks: status
+			.relocks
+			.into_iter()
+			.map(|relock| RpcRelockStatus {
+				amount: relock.amount.into(),
+				starting_block: relock.starting_block,
+				ending_block_as_balance: relock.ending_block_as_balance.into(),
+			})
+			.collect(),
+	}
+}
+
+#[async_trait]
+impl<C, Block, Balance, TokenId, AccountId, BlockNumber>
+	MultipurposeLiquidityApiServer<
+		<Block as BlockT>::Hash,
+		Balance,
+		TokenId,
+		AccountId,
+		BlockNumber,
+	> for MultipurposeLiquidity<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: MultipurposeLiquidityRuntimeApi<Block, Balance, TokenId, AccountId, BlockNumber>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + Into<NumberOrHex>,
+	TokenId: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeDisplay + MaybeFromStr,
+	BlockNumber: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_reserve_status(
+		&self,
+		account: AccountId,
+		token_id: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcReserveStatus<NumberOrHex, TokenId, BlockNumber>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_reserve_status(at, account, token_id)
+			.map(reserve_status_into_rpc)
+			.map_err(into_rpc_error)
+	}
+
+	fn get_account_reserve_statuses(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcReserveStatus<NumberOrHex, TokenId, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_account_reserve_statuses(at, account)
+			.map(|statuses| statuses.into_iter().map(reserve_status_into_rpc).collect())
+			.map_err(into_rpc_error)
+	}
+
+	fn get_relock_unlocked_amount_at(
+		&self,
+		account: AccountId,
+		token_id: TokenId,
+		block: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+
+		api.get_relock_unlocked_amount_at(at, account, token_id, block)
+			.map(Into::into)
+			.map_err(into_rpc_error)
+	}
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2021 Mangata team
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::vec::Vec;
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcRelockStatus<Balance, BlockNumber> {
+	pub amount: Balance,
+	/// block from which relocked tokens are vested
+	pub starting_block: BlockNumber,
+	/// block at which relocked tokens are fully vested
+	pub ending_block_as_balance: Balance,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcReserveStatus<Balance, TokenId, BlockNumber> {
+	pub token_id: TokenId,
+	/// reserved for staking, not activated for liquidity mining
+	pub staked_unactivated_reserves: Balance,
+	/// activated for liquidity mining, not reserved for staking
+	pub activated_unstaked_reserves: Balance,
+	/// reserved for staking and activated for liquidity mining
+	pub staked_and_activated_reserves: Balance,
+	/// reserved but neither staked nor activated
+	pub unspent_reserves: Balance,
+	/// part of the reserves that has to be relocked when unreserved
+	pub relock_amount: Balance,
+	/// amount that can be unreserved immediately
+	pub max_instant_unreserve_amount: Balance,
+	pub relocks: Vec<RpcRelockStatus<Balance, BlockNumber>>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait MultipurposeLiquidityApi<Balance, TokenId, AccountId, BlockNumber> where
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+		TokenId: Codec + MaybeDisplay + MaybeFromStr,
+		AccountId: Codec + MaybeDisplay + MaybeFromStr,
+		BlockNumber: Codec + MaybeDisplay + MaybeFromStr,{
+
+		fn get_reserve_status(
+			account: AccountId,
+			token_id: TokenId,
+		) -> RpcReserveStatus<Balance, TokenId, BlockNumber>;
+
+		fn get_account_reserve_statuses(
+			account: AccountId,
+		) -> Vec<RpcReserveStatus<Balance, TokenId, BlockNumber>>;
+
+		fn get_relock_unlocked_amount_at(
+			account: AccountId,
+			token_id: TokenId,
+			block: BlockNumber,
+		) -> Balance;
+	}
+}
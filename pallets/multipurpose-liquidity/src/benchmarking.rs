@@ -107,5 +107,27 @@ benchmarks! {
 		assert_eq!(MultiPurposeLiquidity::<T>::get_relock_status(caller, asset_id), vec![]);
 	}
 
+	release_expired_relocks{
+		let x in 1..<T as Config>::MaxRelocks::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let initial_amount: BalanceOf<T> = 2_000_000__u32.into();
+		let asset_id = <T as Config>::Tokens::create(&caller, initial_amount).unwrap();
+
+		// worst case, all relock instances of the account have expired
+		let relock_amount: BalanceOf<T> = 1_000__u32.into();
+		let relocks = (0..x).map(|_| RelockStatusInfo{amount: relock_amount, starting_block: 0_u32.into(), ending_block_as_balance: 10_u32.into()}).collect::<Vec<_>>();
+		let total_relock_amount = relock_amount * x.into();
+		<T as Config>::Tokens::reserve(asset_id, &caller, total_relock_amount).unwrap();
+		ReserveStatus::<T>::insert(&caller, asset_id, ReserveStatusInfo{unspent_reserves: total_relock_amount, relock_amount: total_relock_amount, ..Default::default()});
+		RelockStatus::<T>::insert(&caller, asset_id, BoundedVec::<_, T::MaxRelocks>::truncate_from(relocks));
+		RelockedAccounts::<T>::insert((caller.clone(), asset_id), ());
+
+	}: {MultiPurposeLiquidity::<T>::release_expired_relocks(&caller, asset_id, 10_u32.into());}
+	verify{
+		assert_eq!(<T as Config>::Tokens::reserved_balance(asset_id, &caller), 0_u32.into());
+		assert_eq!(MultiPurposeLiquidity::<T>::get_relock_status(caller.clone(), asset_id), vec![]);
+		assert!(!RelockedAccounts::<T>::contains_key((caller, asset_id)));
+	}
+
 	// impl_benchmark_test_suite!(MultiPurposeLiquidity, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -6,17 +6,21 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		tokens::currency::{MultiTokenCurrency, MultiTokenVestingLocks},
-		Get, StorageVersion, WithdrawReasons,
+		BalanceStatus, Get, StorageVersion, WithdrawReasons,
 	},
 	transactional,
 };
-use frame_system::{ensure_signed, pallet_prelude::*};
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use mangata_support::traits::{
 	ActivationReservesProviderTrait, StakingReservesProviderTrait, XykFunctionsTrait,
 };
 use mangata_types::multipurpose_liquidity::{ActivateKind, BondKind};
 use orml_tokens::{MultiTokenCurrencyExtended, MultiTokenReservableCurrency};
-use sp_runtime::traits::{Bounded, CheckedAdd, CheckedSub, Saturating, Zero};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Bounded, CheckedAdd, CheckedSub, SaturatedConversion, Saturating, Zero},
+	Rounding,
+};
 use sp_std::{convert::TryInto, prelude::*};
 
 #[cfg(feature = "std")]
@@ -29,6 +33,8 @@ mod tests;
 
 mod benchmarking;
 
+pub mod migration;
+
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -59,14 +65,18 @@ pub mod pallet {
 
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_expired_relocks(now, remaining_weight)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -106,6 +116,7 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		VestingTokensReserved(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
 		TokensRelockedFromReserve(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>, BalanceOf<T>),
+		ExpiredRelockReleased(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>, BalanceOf<T>),
 	}
 
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -118,6 +129,8 @@ pub mod pallet {
 		pub staked_and_activated_reserves: Balance,
 		pub unspent_reserves: Balance,
 		pub relock_amount: Balance,
+		pub activated_3rdparty_rewards_reserves: Balance,
+		pub staked_and_activated_3rdparty_rewards_reserves: Balance,
 	}
 
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -154,6 +167,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Accounts with relock instances, [`RelockStatus`] keys can not be iterated so expired
+	/// relocks are looked up through this index. Relocks created before the index was introduced
+	/// are added to it with `index_relocked_accounts`.
+	#[pallet::storage]
+	pub type RelockedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, CurrencyIdOf<T>), (), OptionQuery>;
+
+	/// Last entry of [`RelockedAccounts`] processed by `on_idle`
+	#[pallet::storage]
+	pub type RelockExpiryCursor<T: Config> =
+		StorageValue<_, (T::AccountId, CurrencyIdOf<T>), OptionQuery>;
+
 	// MPL extrinsics.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -213,44 +238,11 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			ensure!(T::Xyk::is_liquidity_token(liquidity_token_id), Error::<T>::NotALiquidityToken);
-
-			let (vesting_starting_block, vesting_ending_block_as_balance): (
-				BlockNumberFor<T>,
-				BalanceOf<T>,
-			) = T::VestingProvider::unlock_tokens(&sender, liquidity_token_id, liquidity_token_amount)
-				.map(|x| (x.0, x.1))?;
-
-			let mut reserve_status = Pallet::<T>::get_reserve_status(&sender, liquidity_token_id);
-
-			reserve_status.relock_amount = reserve_status
-				.relock_amount
-				.checked_add(&liquidity_token_amount)
-				.ok_or(Error::<T>::MathError)?;
-			reserve_status.unspent_reserves = reserve_status
-				.unspent_reserves
-				.checked_add(&liquidity_token_amount)
-				.ok_or(Error::<T>::MathError)?;
-
-			ReserveStatus::<T>::insert(&sender, liquidity_token_id, reserve_status);
-
-			RelockStatus::<T>::try_append(
-				&sender,
-				liquidity_token_id,
-				RelockStatusInfo {
-					amount: liquidity_token_amount,
-					starting_block: vesting_starting_block,
-					ending_block_as_balance: vesting_ending_block_as_balance,
-				},
-			)
-			.map_err(|_| Error::<T>::RelockCountLimitExceeded)?;
-
-			T::Tokens::reserve(liquidity_token_id.into(), &sender, liquidity_token_amount)?;
-
-			Pallet::<T>::deposit_event(Event::VestingTokensReserved(
+			Self::do_reserve_vesting_liquidity_tokens(
 				sender,
 				liquidity_token_id,
 				liquidity_token_amount,
-			));
+			)?;
 
 			Ok(().into())
 		}
@@ -323,7 +315,12 @@ pub mod pallet {
 
 			ReserveStatus::<T>::insert(&sender, liquidity_token_id, reserve_status);
 
-			RelockStatus::<T>::insert(&sender, liquidity_token_id, updated_relock_instances);
+			if updated_relock_instances.is_empty() {
+				RelockStatus::<T>::remove(&sender, liquidity_token_id);
+				RelockedAccounts::<T>::remove((&sender, liquidity_token_id));
+			} else {
+				RelockStatus::<T>::insert(&sender, liquidity_token_id, updated_relock_instances);
+			}
 
 			Pallet::<T>::deposit_event(Event::TokensRelockedFromReserve(
 				sender,
@@ -334,6 +331,25 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1) * (accounts.len() as u64))]
+		/// Adds relocks created before [`RelockedAccounts`] was introduced to the index, so their
+		/// expired instances are released by `on_idle`. The list of (account, token) pairs is
+		/// built off-chain, pairs without relock instances are skipped.
+		pub fn index_relocked_accounts(
+			origin: OriginFor<T>,
+			accounts: Vec<(T::AccountId, CurrencyIdOf<T>)>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			for (account_id, token_id) in accounts {
+				if !RelockStatus::<T>::get(&account_id, token_id).is_empty() {
+					RelockedAccounts::<T>::insert((account_id, token_id), ());
+				}
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -444,12 +460,29 @@ impl<T: Config> StakingReservesProviderTrait<T::AccountId, BalanceOf<T>, Currenc
 			reserve_status.activated_unstaked_reserves.saturating_add(move_reserve);
 		working_amount = working_amount.saturating_sub(move_reserve);
 
+		// From staked_and_activated_3rdparty_rewards_reserves goes to activated for 3rdparty
+		// rewards, so it is never mixed up with liquidity activated for native rewards
+		let mut move_3rdparty_reserve =
+			working_amount.min(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
+		move_3rdparty_reserve = BalanceOf::<T>::max_value()
+			.saturating_sub(reserve_status.activated_3rdparty_rewards_reserves)
+			.min(move_3rdparty_reserve);
+		reserve_status.staked_and_activated_3rdparty_rewards_reserves = reserve_status
+			.staked_and_activated_3rdparty_rewards_reserves
+			.saturating_sub(move_3rdparty_reserve);
+		reserve_status.activated_3rdparty_rewards_reserves = reserve_status
+			.activated_3rdparty_rewards_reserves
+			.saturating_add(move_3rdparty_reserve);
+		working_amount = working_amount.saturating_sub(move_3rdparty_reserve);
+
 		// Now we will attempt to unreserve the amount on the basis of the relock_amount
 		let total_remaining_reserve = reserve_status
 			.staked_unactivated_reserves
 			.saturating_add(reserve_status.activated_unstaked_reserves)
 			.saturating_add(reserve_status.staked_and_activated_reserves)
-			.saturating_add(reserve_status.unspent_reserves);
+			.saturating_add(reserve_status.unspent_reserves)
+			.saturating_add(reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
 
 		let mut add_to_unspent =
 			reserve_status.relock_amount.saturating_sub(total_remaining_reserve);
@@ -493,7 +526,9 @@ impl<T: Config> ActivationReservesProviderTrait<T::AccountId, BalanceOf<T>, Curr
 		let total_remaining_reserve = reserve_status
 			.staked_unactivated_reserves
 			.saturating_add(reserve_status.staked_and_activated_reserves)
-			.saturating_add(reserve_status.unspent_reserves);
+			.saturating_add(reserve_status.unspent_reserves)
+			.saturating_add(reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
 
 		let amount_held_back_by_relock =
 			reserve_status.relock_amount.saturating_sub(total_remaining_reserve);
@@ -612,7 +647,9 @@ impl<T: Config> ActivationReservesProviderTrait<T::AccountId, BalanceOf<T>, Curr
 			.staked_unactivated_reserves
 			.saturating_add(reserve_status.activated_unstaked_reserves)
 			.saturating_add(reserve_status.staked_and_activated_reserves)
-			.saturating_add(reserve_status.unspent_reserves);
+			.saturating_add(reserve_status.unspent_reserves)
+			.saturating_add(reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
 
 		let mut add_to_unspent =
 			reserve_status.relock_amount.saturating_sub(total_remaining_reserve);
@@ -645,6 +682,493 @@ impl<T: Config> ActivationReservesProviderTrait<T::AccountId, BalanceOf<T>, Curr
 }
 
 impl<T: Config> Pallet<T> {
+	/// Moves `liquidity_token_amount` of vested tokens from Vested pallet to MPL reserves,
+	/// preserving their unlock schedule, so they can be activated using
+	/// [`ActivateKind::UnspentReserves`]
+	pub fn do_reserve_vesting_liquidity_tokens(
+		sender: T::AccountId,
+		liquidity_token_id: CurrencyIdOf<T>,
+		liquidity_token_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let (vesting_starting_block, vesting_ending_block_as_balance): (
+			BlockNumberFor<T>,
+			BalanceOf<T>,
+		) = T::VestingProvider::unlock_tokens(&sender, liquidity_token_id, liquidity_token_amount)
+			.map(|x| (x.0, x.1))?;
+
+		let mut reserve_status = Pallet::<T>::get_reserve_status(&sender, liquidity_token_id);
+
+		reserve_status.relock_amount = reserve_status
+			.relock_amount
+			.checked_add(&liquidity_token_amount)
+			.ok_or(Error::<T>::MathError)?;
+		reserve_status.unspent_reserves = reserve_status
+			.unspent_reserves
+			.checked_add(&liquidity_token_amount)
+			.ok_or(Error::<T>::MathError)?;
+
+		ReserveStatus::<T>::insert(&sender, liquidity_token_id, reserve_status);
+
+		RelockStatus::<T>::try_append(
+			&sender,
+			liquidity_token_id,
+			RelockStatusInfo {
+				amount: liquidity_token_amount,
+				starting_block: vesting_starting_block,
+				ending_block_as_balance: vesting_ending_block_as_balance,
+			},
+		)
+		.map_err(|_| Error::<T>::RelockCountLimitExceeded)?;
+		RelockedAccounts::<T>::insert((&sender, liquidity_token_id), ());
+
+		T::Tokens::reserve(liquidity_token_id.into(), &sender, liquidity_token_amount)?;
+
+		Pallet::<T>::deposit_event(Event::VestingTokensReserved(
+			sender,
+			liquidity_token_id,
+			liquidity_token_amount,
+		));
+
+		Ok(())
+	}
+
+	/// Checks if `amount` of tokens can be activated for 3rdparty rewards. Tokens taken from
+	/// available balance or unspent reserves are kept in a dedicated
+	/// [`ReserveStatusInfo::activated_3rdparty_rewards_reserves`] bucket and staked tokens in
+	/// [`ReserveStatusInfo::staked_and_activated_3rdparty_rewards_reserves`], so they are not
+	/// mixed up with liquidity activated for native rewards.
+	pub fn can_activate_for_3rdparty_rewards(
+		token_id: CurrencyIdOf<T>,
+		account_id: &T::AccountId,
+		amount: BalanceOf<T>,
+		use_balance_from: Option<ActivateKind>,
+	) -> bool {
+		let reserve_status = Pallet::<T>::get_reserve_status(account_id, token_id);
+
+		match use_balance_from.unwrap_or(ActivateKind::AvailableBalance) {
+			ActivateKind::AvailableBalance =>
+				T::Tokens::ensure_can_withdraw(
+					token_id.into(),
+					&account_id,
+					amount,
+					WithdrawReasons::all(),
+					Default::default(),
+				)
+				.is_ok() && reserve_status
+					.activated_3rdparty_rewards_reserves
+					.checked_add(&amount)
+					.is_some(),
+			ActivateKind::StakedUnactivatedReserves =>
+				reserve_status.staked_unactivated_reserves.checked_sub(&amount).is_some() &&
+					reserve_status
+						.staked_and_activated_3rdparty_rewards_reserves
+						.checked_add(&amount)
+						.is_some(),
+			ActivateKind::UnspentReserves =>
+				reserve_status.unspent_reserves.checked_sub(&amount).is_some() &&
+					reserve_status
+						.activated_3rdparty_rewards_reserves
+						.checked_add(&amount)
+						.is_some(),
+		}
+	}
+
+	/// Activates `amount` of tokens for 3rdparty rewards, see
+	/// [`Pallet::can_activate_for_3rdparty_rewards`]
+	pub fn activate_for_3rdparty_rewards(
+		token_id: CurrencyIdOf<T>,
+		account_id: &T::AccountId,
+		amount: BalanceOf<T>,
+		use_balance_from: Option<ActivateKind>,
+	) -> DispatchResult {
+		let mut reserve_status = Pallet::<T>::get_reserve_status(account_id, token_id);
+
+		match use_balance_from.unwrap_or(ActivateKind::AvailableBalance) {
+			ActivateKind::AvailableBalance => {
+				reserve_status.activated_3rdparty_rewards_reserves = reserve_status
+					.activated_3rdparty_rewards_reserves
+					.checked_add(&amount)
+					.ok_or(Error::<T>::MathError)?;
+				T::Tokens::reserve(token_id.into(), &account_id, amount)?;
+			},
+			ActivateKind::StakedUnactivatedReserves => {
+				reserve_status.staked_unactivated_reserves = reserve_status
+					.staked_unactivated_reserves
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::NotEnoughTokens)?;
+				reserve_status.staked_and_activated_3rdparty_rewards_reserves = reserve_status
+					.staked_and_activated_3rdparty_rewards_reserves
+					.checked_add(&amount)
+					.ok_or(Error::<T>::MathError)?;
+			},
+			ActivateKind::UnspentReserves => {
+				reserve_status.unspent_reserves = reserve_status
+					.unspent_reserves
+					.checked_sub(&amount)
+					.ok_or(Error::<T>::NotEnoughTokens)?;
+				reserve_status.activated_3rdparty_rewards_reserves = reserve_status
+					.activated_3rdparty_rewards_reserves
+					.checked_add(&amount)
+					.ok_or(Error::<T>::MathError)?;
+			},
+		}
+
+		ReserveStatus::<T>::insert(account_id, token_id, reserve_status);
+		Ok(())
+	}
+
+	/// Deactivates `amount` of tokens activated for 3rdparty rewards. Tokens held back by
+	/// relocks go back to unspent reserves, the rest is unreserved. Staked tokens go back to
+	/// staked reserves. Whatever is not covered by the 3rdparty buckets (activations made before
+	/// the buckets were introduced) is deactivated the same way as liquidity activated for
+	/// native rewards. Returns the amount that could not be deactivated.
+	pub fn deactivate_from_3rdparty_rewards(
+		token_id: CurrencyIdOf<T>,
+		account_id: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let mut reserve_status = Pallet::<T>::get_reserve_status(account_id, token_id);
+		let deactivated = amount.min(reserve_status.activated_3rdparty_rewards_reserves);
+		reserve_status.activated_3rdparty_rewards_reserves =
+			reserve_status.activated_3rdparty_rewards_reserves.saturating_sub(deactivated);
+
+		let mut move_reserve = amount
+			.saturating_sub(deactivated)
+			.min(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
+		// This is just to prevent overflow.
+		move_reserve = BalanceOf::<T>::max_value()
+			.saturating_sub(reserve_status.staked_unactivated_reserves)
+			.min(move_reserve);
+		reserve_status.staked_and_activated_3rdparty_rewards_reserves = reserve_status
+			.staked_and_activated_3rdparty_rewards_reserves
+			.saturating_sub(move_reserve);
+		reserve_status.staked_unactivated_reserves =
+			reserve_status.staked_unactivated_reserves.saturating_add(move_reserve);
+
+		let total_remaining_reserve = reserve_status
+			.staked_unactivated_reserves
+			.saturating_add(reserve_status.activated_unstaked_reserves)
+			.saturating_add(reserve_status.staked_and_activated_reserves)
+			.saturating_add(reserve_status.unspent_reserves)
+			.saturating_add(reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
+
+		let add_to_unspent = reserve_status
+			.relock_amount
+			.saturating_sub(total_remaining_reserve)
+			.min(deactivated);
+		reserve_status.unspent_reserves =
+			reserve_status.unspent_reserves.saturating_add(add_to_unspent);
+
+		let unreserve_result: BalanceOf<T> = T::Tokens::unreserve(
+			token_id.into(),
+			account_id,
+			deactivated.saturating_sub(add_to_unspent),
+		);
+
+		if !unreserve_result.is_zero() {
+			log::warn!(
+				"3rdparty deactivation resulted in non-zero unreserve_result {:?}",
+				unreserve_result
+			);
+		}
+
+		ReserveStatus::<T>::insert(account_id, token_id, reserve_status);
+
+		let remaining = amount.saturating_sub(deactivated).saturating_sub(move_reserve);
+		if remaining.is_zero() {
+			unreserve_result
+		} else {
+			unreserve_result.saturating_add(Self::deactivate(token_id, account_id, remaining))
+		}
+	}
+
+	/// Moves `amount` of tokens activated for native rewards and `amount_3rdparty` of tokens
+	/// activated for 3rdparty rewards from `from` to `to`, keeping them activated. Relock
+	/// instances that hold the moved tokens back are moved along with them. Tokens activated from
+	/// staked reserves stay bonded by `from` and can not be moved.
+	pub fn transfer_activated_reserves(
+		token_id: CurrencyIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		amount_3rdparty: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut from_reserve_status = Pallet::<T>::get_reserve_status(from, token_id);
+		let mut to_reserve_status = Pallet::<T>::get_reserve_status(to, token_id);
+
+		let moved_3rdparty =
+			amount_3rdparty.min(from_reserve_status.activated_3rdparty_rewards_reserves);
+		// 3rdparty activations made before the dedicated bucket was introduced are kept
+		// together with native ones
+		let moved_unstaked = amount.saturating_add(amount_3rdparty.saturating_sub(moved_3rdparty));
+
+		from_reserve_status.activated_3rdparty_rewards_reserves = from_reserve_status
+			.activated_3rdparty_rewards_reserves
+			.saturating_sub(moved_3rdparty);
+		from_reserve_status.activated_unstaked_reserves = from_reserve_status
+			.activated_unstaked_reserves
+			.checked_sub(&moved_unstaked)
+			.ok_or(Error::<T>::NotEnoughTokens)?;
+		to_reserve_status.activated_3rdparty_rewards_reserves = to_reserve_status
+			.activated_3rdparty_rewards_reserves
+			.checked_add(&moved_3rdparty)
+			.ok_or(Error::<T>::MathError)?;
+		to_reserve_status.activated_unstaked_reserves = to_reserve_status
+			.activated_unstaked_reserves
+			.checked_add(&moved_unstaked)
+			.ok_or(Error::<T>::MathError)?;
+
+		// relocked tokens that are no longer covered by reserves of `from` follow the moved ones
+		let total_remaining_reserve = from_reserve_status
+			.staked_unactivated_reserves
+			.saturating_add(from_reserve_status.activated_unstaked_reserves)
+			.saturating_add(from_reserve_status.staked_and_activated_reserves)
+			.saturating_add(from_reserve_status.unspent_reserves)
+			.saturating_add(from_reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(from_reserve_status.staked_and_activated_3rdparty_rewards_reserves);
+		let relocked = from_reserve_status.relock_amount.saturating_sub(total_remaining_reserve);
+		if !relocked.is_zero() {
+			Self::transfer_relocks(token_id, from, to, relocked)?;
+			from_reserve_status.relock_amount =
+				from_reserve_status.relock_amount.saturating_sub(relocked);
+			to_reserve_status.relock_amount = to_reserve_status
+				.relock_amount
+				.checked_add(&relocked)
+				.ok_or(Error::<T>::MathError)?;
+		}
+
+		let not_moved = T::Tokens::repatriate_reserved(
+			token_id.into(),
+			from,
+			to,
+			moved_unstaked.saturating_add(moved_3rdparty),
+			BalanceStatus::Reserved,
+		)?;
+		ensure!(not_moved.is_zero(), Error::<T>::NotEnoughTokens);
+
+		ReserveStatus::<T>::insert(from, token_id, from_reserve_status);
+		ReserveStatus::<T>::insert(to, token_id, to_reserve_status);
+		Ok(())
+	}
+
+	/// Moves relock instances worth `amount` from `from` to `to`, the most recent instances are
+	/// moved first and the last moved instance is split if needed
+	fn transfer_relocks(
+		token_id: CurrencyIdOf<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut remaining = amount;
+		let mut kept = Vec::new();
+		let mut to_relocks = Self::get_relock_status(to, token_id);
+
+		for relock_instance in Self::get_relock_status(from, token_id).into_iter().rev() {
+			if remaining.is_zero() {
+				kept.push(relock_instance);
+				continue
+			}
+			let moved = remaining.min(relock_instance.amount);
+			remaining = remaining.saturating_sub(moved);
+			if moved < relock_instance.amount {
+				kept.push(RelockStatusInfo {
+					amount: relock_instance.amount.saturating_sub(moved),
+					..relock_instance.clone()
+				});
+			}
+			to_relocks
+				.try_push(RelockStatusInfo { amount: moved, ..relock_instance })
+				.map_err(|_| Error::<T>::RelockCountLimitExceeded)?;
+		}
+		ensure!(remaining.is_zero(), Error::<T>::NotEnoughUnspentReserves);
+
+		if kept.is_empty() {
+			RelockStatus::<T>::remove(from, token_id);
+			RelockedAccounts::<T>::remove((from, token_id));
+		} else {
+			kept.reverse();
+			// kept instances were already stored in the bounded vector
+			RelockStatus::<T>::insert(
+				from,
+				token_id,
+				BoundedVec::<_, T::MaxRelocks>::truncate_from(kept),
+			);
+		}
+		RelockStatus::<T>::insert(to, token_id, to_relocks);
+		RelockedAccounts::<T>::insert((to, token_id), ());
+		Ok(())
+	}
+
+	/// Releases relock instances whose vesting schedule has already ended, iterating over
+	/// [`RelockedAccounts`] from [`RelockExpiryCursor`] as long as `weight_limit` allows
+	pub(crate) fn process_expired_relocks(now: BlockNumberFor<T>, weight_limit: Weight) -> Weight {
+		let base_cost = T::DbWeight::get().reads_writes(1, 1); // RelockExpiryCursor
+
+		// all expired relock instances of an account are released at once, plus the read of
+		// the RelockedAccounts entry
+		let cost_of_single_release_iteration =
+			T::WeightInfo::release_expired_relocks(T::MaxRelocks::get()) +
+				T::DbWeight::get().reads(1);
+
+		if !(base_cost + cost_of_single_release_iteration).all_lte(weight_limit) {
+			return Weight::from_parts(0, 0)
+		}
+
+		let remaining_weight = weight_limit.saturating_sub(base_cost);
+		let max_iterations = remaining_weight
+			.ref_time()
+			.checked_div(cost_of_single_release_iteration.ref_time())
+			.unwrap_or(u64::MAX)
+			.min(
+				remaining_weight
+					.proof_size()
+					.checked_div(cost_of_single_release_iteration.proof_size())
+					.unwrap_or(u64::MAX),
+			);
+
+		let cursor = RelockExpiryCursor::<T>::get();
+		let had_cursor = cursor.is_some();
+		let keys = match cursor {
+			Some(cursor) =>
+				RelockedAccounts::<T>::iter_keys_from(RelockedAccounts::<T>::hashed_key_for(cursor))
+					.take(max_iterations as usize)
+					.collect::<Vec<_>>(),
+			None => RelockedAccounts::<T>::iter_keys()
+				.take(max_iterations as usize)
+				.collect::<Vec<_>>(),
+		};
+
+		if (keys.len() as u64) < max_iterations {
+			// reached the end of the index, next run starts from the beginning
+			if had_cursor {
+				RelockExpiryCursor::<T>::kill();
+			}
+		} else if let Some(last) = keys.last() {
+			RelockExpiryCursor::<T>::put(last);
+		}
+
+		let mut consumed_weight = base_cost;
+		for (account_id, token_id) in keys {
+			Self::release_expired_relocks(&account_id, token_id, now);
+			consumed_weight += cost_of_single_release_iteration;
+		}
+		consumed_weight
+	}
+
+	pub(crate) fn release_expired_relocks(
+		account_id: &T::AccountId,
+		token_id: CurrencyIdOf<T>,
+		now: BlockNumberFor<T>,
+	) {
+		let now_as_balance: u128 = now.saturated_into();
+		let (expired, active): (Vec<_>, Vec<_>) = Self::get_relock_status(account_id, token_id)
+			.into_iter()
+			.partition(|relock_instance: &RelockStatusInfo<BalanceOf<T>, BlockNumberFor<T>>| {
+				relock_instance.ending_block_as_balance.saturated_into::<u128>() <= now_as_balance
+			});
+
+		if expired.is_empty() {
+			return
+		}
+
+		let expired_amount = expired.iter().fold(BalanceOf::<T>::zero(), |acc, relock_instance| {
+			acc.saturating_add(relock_instance.amount)
+		});
+
+		let mut reserve_status = Pallet::<T>::get_reserve_status(account_id, token_id);
+		reserve_status.relock_amount = reserve_status.relock_amount.saturating_sub(expired_amount);
+
+		// unspent reserves are only kept to cover relocks, so whatever exceeds the remaining
+		// relock amount is released, expired tokens that are still staked or activated become
+		// free once they are unbonded or deactivated
+		let other_reserves = reserve_status
+			.staked_unactivated_reserves
+			.saturating_add(reserve_status.activated_unstaked_reserves)
+			.saturating_add(reserve_status.staked_and_activated_reserves)
+			.saturating_add(reserve_status.activated_3rdparty_rewards_reserves)
+			.saturating_add(reserve_status.staked_and_activated_3rdparty_rewards_reserves);
+		let required_unspent = reserve_status.relock_amount.saturating_sub(other_reserves);
+		let released = reserve_status
+			.unspent_reserves
+			.saturating_sub(required_unspent)
+			.min(expired_amount);
+		reserve_status.unspent_reserves = reserve_status.unspent_reserves.saturating_sub(released);
+
+		let unreserve_result: BalanceOf<T> =
+			T::Tokens::unreserve(token_id.into(), account_id, released);
+		if !unreserve_result.is_zero() {
+			log::warn!(
+				"Releasing expired relocks resulted in non-zero unreserve_result {:?}",
+				unreserve_result
+			);
+		}
+
+		ReserveStatus::<T>::insert(account_id, token_id, reserve_status);
+
+		if active.is_empty() {
+			RelockStatus::<T>::remove(account_id, token_id);
+			RelockedAccounts::<T>::remove((account_id, token_id));
+		} else {
+			// active instances were already stored in the bounded vector
+			RelockStatus::<T>::insert(
+				account_id,
+				token_id,
+				BoundedVec::<_, T::MaxRelocks>::truncate_from(active),
+			);
+		}
+
+		for relock_instance in expired {
+			Pallet::<T>::deposit_event(Event::ExpiredRelockReleased(
+				account_id.clone(),
+				token_id,
+				relock_instance.amount,
+				relock_instance.ending_block_as_balance,
+			));
+		}
+	}
+
+	/// Amount of relocked tokens of the account that is released by vesting schedules of its
+	/// relock instances at block `n`
+	pub fn get_relock_unlocked_amount_at(
+		account_id: &T::AccountId,
+		token_id: CurrencyIdOf<T>,
+		n: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		Self::get_relock_status(account_id, token_id).iter().fold(
+			BalanceOf::<T>::zero(),
+			|unlocked, relock_instance| {
+				unlocked
+					.saturating_add(Self::relock_instance_unlocked_amount_at(relock_instance, n))
+			},
+		)
+	}
+
+	fn relock_instance_unlocked_amount_at(
+		relock_instance: &RelockStatusInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		n: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		let starting_block: u128 = relock_instance.starting_block.saturated_into();
+		let ending_block: u128 = relock_instance.ending_block_as_balance.saturated_into();
+		let n: u128 = n.saturated_into();
+
+		if n >= ending_block {
+			return relock_instance.amount
+		}
+		if n <= starting_block {
+			return BalanceOf::<T>::zero()
+		}
+
+		multiply_by_rational_with_rounding(
+			relock_instance.amount.saturated_into(),
+			n - starting_block,
+			ending_block - starting_block,
+			Rounding::Down,
+		)
+		.map(SaturatedConversion::saturated_into)
+		.unwrap_or_default()
+	}
+
 	fn do_reserve_tokens_by_vesting_index(
 		account: T::AccountId,
 		liquidity_token_id: CurrencyIdOf<T>,
@@ -686,6 +1210,7 @@ impl<T: Config> Pallet<T> {
 			},
 		)
 		.map_err(|_| Error::<T>::RelockCountLimitExceeded)?;
+		RelockedAccounts::<T>::insert((&account, liquidity_token_id), ());
 
 		T::Tokens::reserve(liquidity_token_id.into(), &account, unlocked_amount)?;
 
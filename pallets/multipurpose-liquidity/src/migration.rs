@@ -1,341 +1,317 @@
 use super::*;
 use frame_support::{
 	migration::storage_key_iter,
-	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
 	weights::Weight,
 };
 use parachain_staking::{CollatorCandidate, Delegator};
 use sp_std::collections::btree_map::BTreeMap;
 
-#[cfg(feature = "try-runtime")]
-use frame_support::traits::OnRuntimeUpgradeHelpersExt;
-
-#[cfg(feature = "try-runtime")]
-pub fn migrate_from_v0_pre_runtime_upgrade<T: Config, P: GetStorageVersion + PalletInfoAccess>(
-) -> Result<(), &'static str> {
-	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
-	if on_chain_storage_version != 0 {
-		log::info!(
-			target: "mpl",
-			"Attempted to apply xyk-staking-mpl consistency pre-migration to mpl but failed because storage version is {:?}, and not 0",
-			on_chain_storage_version,
-		);
-		return Ok(())
-	}
-
-	log::info!(
-		target: "mpl",
-		"Running xyk-staking-mpl consistency pre-migration to mpl with storage version {:?}",
-		on_chain_storage_version,
-	);
+pub mod v1 {
+	use super::*;
 
-	Pallet::<T>::set_temp_storage(true, "is_pre_migration");
+	#[storage_alias]
+	pub(crate) type ReserveStatus<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_256,
+		<T as frame_system::Config>::AccountId,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		v2::OldReserveStatusInfo<BalanceOf<T>>,
+		ValueQuery,
+	>;
+
+	/// Reserves of every (account, liquidity token) pair: activated in xyk, staked and in total
+	pub type UserReserveInfo<T> = BTreeMap<
+		(<T as frame_system::Config>::AccountId, CurrencyIdOf<T>),
+		(BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+	>;
+
+	fn collator_storage<T: Config>(
+	) -> Vec<(T::AccountId, CollatorCandidate<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>)> {
+		storage_key_iter::<
+			T::AccountId,
+			CollatorCandidate<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>,
+			Twox64Concat,
+		>(b"ParachainStaking", b"CandidateState")
+		.collect::<Vec<_>>()
+	}
 
-	// Check consistency of xyk storage, staking storage and orml reserves
-	// Ensure reserve and relock status is zero
+	fn delegator_storage<T: Config>(
+	) -> Vec<(T::AccountId, Delegator<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>)> {
+		storage_key_iter::<
+			T::AccountId,
+			Delegator<T::AccountId, BalanceOf<T>, CurrencyIdOf<T>>,
+			Twox64Concat,
+		>(b"ParachainStaking", b"DelegatorState")
+		.collect::<Vec<_>>()
+	}
 
-	// Get all required storage from xyk and staking
+	fn activation_storage<T: Config>() -> Vec<((T::AccountId, CurrencyIdOf<T>), BalanceOf<T>)> {
+		storage_key_iter::<(T::AccountId, CurrencyIdOf<T>), BalanceOf<T>, Twox64Concat>(
+			b"Xyk",
+			b"LiquidityMiningActiveUser",
+		)
+		.collect::<Vec<_>>()
+	}
 
-	let collator_storage = storage_key_iter::<
-		T::AccountId,
-		CollatorCandidate<T::AccountId>,
-		Twox64Concat,
-	>(b"ParachainStaking", b"CandidateState")
-	.collect::<Vec<_>>();
+	/// Collects xyk activations and staking bonds of all accounts
+	#[cfg(feature = "try-runtime")]
+	fn user_reserve_info<T: Config>() -> UserReserveInfo<T> {
+		let mut user_reserve_info: UserReserveInfo<T> = BTreeMap::new();
 
-	let delegator_storage =
-		storage_key_iter::<T::AccountId, Delegator<T::AccountId>, Twox64Concat>(
-			b"ParachainStaking",
-			b"DelegatorState",
-		)
-		.collect::<Vec<_>>();
-
-	let activation_storage = storage_key_iter::<(T::AccountId, TokenId), Balance, Twox64Concat>(
-		b"Xyk",
-		b"LiquidityMiningActiveUser",
-	)
-	.collect::<Vec<_>>();
-
-	// Get all users list
-
-	// (Balance, Balance, Balance)
-	// represents reserves
-	// xyk, staking, total
-	let mut user_reserve_info: BTreeMap<(T::AccountId, TokenId), (Balance, Balance, Balance)> =
-		BTreeMap::new();
-
-	for (collator_account, collator_info) in collator_storage.iter() {
-		if let Some((xyk_reserve, staking_reserve, total_reserve)) =
-			user_reserve_info.get_mut(&(collator_account.clone(), collator_info.liquidity_token))
-		{
+		for (collator_account, collator_info) in collator_storage::<T>().iter() {
+			let (_, staking_reserve, total_reserve) = user_reserve_info
+				.entry((collator_account.clone(), collator_info.liquidity_token))
+				.or_default();
 			*staking_reserve = staking_reserve.saturating_add(collator_info.bond);
 			*total_reserve = total_reserve.saturating_add(collator_info.bond);
-		} else {
-			user_reserve_info.insert(
-				(collator_account.clone(), collator_info.liquidity_token),
-				(Balance::zero(), collator_info.bond, collator_info.bond),
-			);
-		};
-	}
+		}
 
-	for (delegator_account, delegator_info) in delegator_storage.iter() {
-		for delegation in delegator_info.delegations.0.iter() {
-			if let Some((xyk_reserve, staking_reserve, total_reserve)) =
-				user_reserve_info.get_mut(&(delegator_account.clone(), delegation.liquidity_token))
-			{
+		for (delegator_account, delegator_info) in delegator_storage::<T>().iter() {
+			for delegation in delegator_info.delegations.0.iter() {
+				let (_, staking_reserve, total_reserve) = user_reserve_info
+					.entry((delegator_account.clone(), delegation.liquidity_token))
+					.or_default();
 				*staking_reserve = staking_reserve.saturating_add(delegation.amount);
 				*total_reserve = total_reserve.saturating_add(delegation.amount);
-			} else {
-				user_reserve_info.insert(
-					(delegator_account.clone(), delegation.liquidity_token),
-					(Balance::zero(), delegation.amount, delegation.amount),
-				);
-			};
+			}
 		}
-	}
 
-	for ((account, liquidity_token), amount) in activation_storage.iter() {
-		if let Some((xyk_reserve, staking_reserve, total_reserve)) =
-			user_reserve_info.get_mut(&(account.clone(), *liquidity_token))
-		{
+		for ((account, liquidity_token), amount) in activation_storage::<T>().iter() {
+			let (xyk_reserve, _, total_reserve) =
+				user_reserve_info.entry((account.clone(), *liquidity_token)).or_default();
 			*xyk_reserve = xyk_reserve.saturating_add(*amount);
 			*total_reserve = total_reserve.saturating_add(*amount);
-		} else {
-			user_reserve_info
-				.insert((account.clone(), *liquidity_token), (*amount, Balance::zero(), *amount));
-		};
-	}
-
-	for ((account, liquidity_token), (xyk_reserve, staking_reserve, total_reserve)) in
-		user_reserve_info.iter()
-	{
-		assert_eq!(xyk_reserve.saturating_add(*staking_reserve), *total_reserve);
-		assert_eq!(
-			T::Tokens::reserved_balance((*liquidity_token).into(), account).into(),
-			*total_reserve
-		);
-		let reserve_status = Pallet::<T>::get_reserve_status(account, liquidity_token);
-		assert!(reserve_status.staked_and_activated_reserves.is_zero());
-		assert!(reserve_status.activated_unstaked_reserves.is_zero());
-		assert!(reserve_status.staked_unactivated_reserves.is_zero());
-		assert!(reserve_status.unspent_reserves.is_zero());
-		assert!(reserve_status.relock_amount.is_zero());
-		assert!(Pallet::<T>::get_relock_status(account, liquidity_token).is_empty());
-	}
-
-	Pallet::<T>::set_temp_storage(user_reserve_info, "user_reserve_info");
-
-	log::info!(
-		target: "mpl",
-		"xyk-staking-mpl consistency pre-migration to mpl with storage version {:?} completed successfully",
-		on_chain_storage_version,
-	);
-
-	Ok(())
-}
+		}
 
-/// Migrate the pallet storage to v1.
-pub fn migrate_from_v0<T: Config, P: GetStorageVersion + PalletInfoAccess>(
-) -> frame_support::weights::Weight {
-	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
-
-	if on_chain_storage_version != 0 {
-		log::info!(
-			target: "mpl",
-			"Attempted to apply xyk-staking-mpl consistency migration to mpl but failed because storage version is {:?}, and not 0",
-			on_chain_storage_version,
-		);
-		return T::DbWeight::get().reads(1)
+		user_reserve_info
 	}
 
-	log::info!(
-		target: "mpl",
-		"Running xyk-staking-mpl consistency migration to mpl with storage version {:?}",
-		on_chain_storage_version,
-	);
-
-	// Apply storage migration from StorageVersion 0 to 1
-
-	//////////////////////////////////////////////////////
-
-	let collator_storage = storage_key_iter::<
-		T::AccountId,
-		CollatorCandidate<T::AccountId>,
-		Twox64Concat,
-	>(b"ParachainStaking", b"CandidateState")
-	.collect::<Vec<_>>();
-
-	let delegator_storage =
-		storage_key_iter::<T::AccountId, Delegator<T::AccountId>, Twox64Concat>(
-			b"ParachainStaking",
-			b"DelegatorState",
-		)
-		.collect::<Vec<_>>();
-
-	let activation_storage = storage_key_iter::<(T::AccountId, TokenId), Balance, Twox64Concat>(
-		b"Xyk",
-		b"LiquidityMiningActiveUser",
-	)
-	.collect::<Vec<_>>();
-
-	// Now we have all the info in memory
-
-	let mut collators_processed_count = u32::zero();
-
-	let mut delegations_processed_count = u32::zero();
-
-	let mut activation_processed_count = u32::zero();
-
-	for (collator_account, collator_info) in collator_storage.iter() {
-		let mut reserve_status =
-			Pallet::<T>::get_reserve_status(collator_account, collator_info.liquidity_token);
-		reserve_status.staked_unactivated_reserves =
-			reserve_status.staked_unactivated_reserves.saturating_add(collator_info.bond);
-
-		log::info!(
-			target: "mpl",
-			"Reserve status after processing collator: {:?} on liquidity token: {:?} with amount: {:?} := {:?}",
-			collator_account,
-			collator_info.liquidity_token,
-			collator_info.bond,
-			reserve_status,
-		);
-
-		collators_processed_count = collators_processed_count.saturating_add(1u32);
+	/// Initializes [`ReserveStatus`] from xyk activations and staking bonds, so reserves made
+	/// before mpl was introduced are accounted in it
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply xyk-staking-mpl consistency pre-migration to mpl but failed because storage version is {:?}, and not 0",
+					on_chain_storage_version,
+				);
+				return Ok(Option::<UserReserveInfo<T>>::None.encode())
+			}
+
+			// Check consistency of xyk storage, staking storage and orml reserves
+			// Ensure reserve and relock status is zero
+			let user_reserve_info = user_reserve_info::<T>();
+			for ((account, liquidity_token), (xyk_reserve, staking_reserve, total_reserve)) in
+				user_reserve_info.iter()
+			{
+				ensure!(
+					xyk_reserve.saturating_add(*staking_reserve) == *total_reserve,
+					"xyk and staking reserves do not add up to total reserve"
+				);
+				ensure!(
+					T::Tokens::reserved_balance((*liquidity_token).into(), account) ==
+						*total_reserve,
+					"reserved balance does not match xyk and staking reserves"
+				);
+				let reserve_status = ReserveStatus::<T>::get(account, liquidity_token);
+				ensure!(
+					reserve_status.staked_and_activated_reserves.is_zero() &&
+						reserve_status.activated_unstaked_reserves.is_zero() &&
+						reserve_status.staked_unactivated_reserves.is_zero() &&
+						reserve_status.unspent_reserves.is_zero() &&
+						reserve_status.relock_amount.is_zero(),
+					"reserve status is already set"
+				);
+				ensure!(
+					Pallet::<T>::get_relock_status(account, liquidity_token).is_empty(),
+					"relock status is already set"
+				);
+			}
 
-		ReserveStatus::<T>::insert(collator_account, collator_info.liquidity_token, reserve_status);
-	}
+			log::info!(
+				target: LOG_TARGET,
+				"xyk-staking-mpl consistency pre-migration to mpl with storage version {:?} completed successfully",
+				on_chain_storage_version,
+			);
 
-	for (delegator_account, delegator_info) in delegator_storage.iter() {
-		for delegation in delegator_info.delegations.0.iter() {
-			let mut reserve_status =
-				Pallet::<T>::get_reserve_status(delegator_account, delegation.liquidity_token);
+			Ok(Some(user_reserve_info).encode())
+		}
 
-			reserve_status.staked_unactivated_reserves =
-				reserve_status.staked_unactivated_reserves.saturating_add(delegation.amount);
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply xyk-staking-mpl consistency migration to mpl but failed because storage version is {:?}, and not 0",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let collator_storage = collator_storage::<T>();
+			let delegator_storage = delegator_storage::<T>();
+			let activation_storage = activation_storage::<T>();
+			let mut processed_count = 0u64;
+
+			for (collator_account, collator_info) in collator_storage.iter() {
+				ReserveStatus::<T>::mutate(
+					collator_account,
+					collator_info.liquidity_token,
+					|reserve_status| {
+						reserve_status.staked_unactivated_reserves = reserve_status
+							.staked_unactivated_reserves
+							.saturating_add(collator_info.bond);
+					},
+				);
+				processed_count = processed_count.saturating_add(1);
+			}
+
+			for (delegator_account, delegator_info) in delegator_storage.iter() {
+				for delegation in delegator_info.delegations.0.iter() {
+					ReserveStatus::<T>::mutate(
+						delegator_account,
+						delegation.liquidity_token,
+						|reserve_status| {
+							reserve_status.staked_unactivated_reserves = reserve_status
+								.staked_unactivated_reserves
+								.saturating_add(delegation.amount);
+						},
+					);
+					processed_count = processed_count.saturating_add(1);
+				}
+			}
+
+			for ((account, liquidity_token), amount) in activation_storage.iter() {
+				ReserveStatus::<T>::mutate(account, liquidity_token, |reserve_status| {
+					reserve_status.activated_unstaked_reserves =
+						reserve_status.activated_unstaked_reserves.saturating_add(*amount);
+				});
+				processed_count = processed_count.saturating_add(1);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
 
 			log::info!(
-				target: "mpl",
-				"Reserve status after processing delegation of delegator: {:?} on liquidity token: {:?} with amount: {:?} := {:?}",
-				delegator_account,
-				delegation.liquidity_token,
-				delegation.amount,
-				reserve_status,
+				target: LOG_TARGET,
+				"xyk-staking-mpl consistency migration to mpl completed successfully, {} reserves processed",
+				processed_count,
 			);
 
-			delegations_processed_count = delegations_processed_count.saturating_add(1u32);
-
-			ReserveStatus::<T>::insert(
-				delegator_account,
-				delegation.liquidity_token,
-				reserve_status,
-			);
+			let reads_to_collect_info = (collator_storage.len() as u64)
+				.saturating_add(delegator_storage.len() as u64)
+				.saturating_add(activation_storage.len() as u64);
+			T::DbWeight::get().reads_writes(
+				reads_to_collect_info.saturating_add(processed_count).saturating_add(1),
+				processed_count.saturating_add(1),
+			)
 		}
-	}
 
-	for ((account, liquidity_token), amount) in activation_storage.iter() {
-		let mut reserve_status = Pallet::<T>::get_reserve_status(account, liquidity_token);
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let user_reserve_info = match Option::<UserReserveInfo<T>>::decode(&mut &state[..])
+				.map_err(|_| "cannot decode pre-migration state")?
+			{
+				Some(user_reserve_info) => user_reserve_info,
+				None => return Ok(()),
+			};
 
-		reserve_status.activated_unstaked_reserves =
-			reserve_status.activated_unstaked_reserves.saturating_add(*amount);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"storage version was not updated"
+			);
 
-		log::info!(
-			target: "mpl",
-			"Reserve status after processing activation of account: {:?} on liquidity token: {:?} with amount: {:?} := {:?}",
-			account,
-			liquidity_token,
-			amount,
-			reserve_status,
-		);
+			// Check consistency of xyk storage, staking storage and orml reserves
+			for ((account, liquidity_token), (xyk_reserve, staking_reserve, total_reserve)) in
+				user_reserve_info.iter()
+			{
+				ensure!(
+					T::Tokens::reserved_balance((*liquidity_token).into(), account) ==
+						*total_reserve,
+					"reserved balance does not match xyk and staking reserves"
+				);
+				let reserve_status = ReserveStatus::<T>::get(account, liquidity_token);
+				ensure!(
+					reserve_status.staked_and_activated_reserves.is_zero() &&
+						reserve_status.activated_unstaked_reserves == *xyk_reserve &&
+						reserve_status.staked_unactivated_reserves == *staking_reserve &&
+						reserve_status.unspent_reserves.is_zero() &&
+						reserve_status.relock_amount.is_zero(),
+					"reserve status does not match xyk and staking reserves"
+				);
+				ensure!(
+					Pallet::<T>::get_relock_status(account, liquidity_token).is_empty(),
+					"relock status is set"
+				);
+			}
 
-		activation_processed_count = activation_processed_count.saturating_add(1u32);
+			Ok(())
+		}
+	}
+}
 
-		ReserveStatus::<T>::insert(account, liquidity_token, reserve_status);
+pub mod v2 {
+	use super::*;
+
+	/// Reserve status used before v2, without a dedicated bucket for liquidity activated for
+	/// 3rdparty rewards
+	#[derive(Encode, Decode, Default)]
+	pub struct OldReserveStatusInfo<Balance> {
+		pub staked_unactivated_reserves: Balance,
+		pub activated_unstaked_reserves: Balance,
+		pub staked_and_activated_reserves: Balance,
+		pub unspent_reserves: Balance,
+		pub relock_amount: Balance,
 	}
 
-	StorageVersion::new(1).put::<P>();
-
-	log::info!(
-		target: "mpl",
-		"xyk-staking-mpl consistency migration to mpl completed successfully, storage version is now {:?}",
-		<P as GetStorageVersion>::on_chain_storage_version(),
-	);
-
-	let reads_to_collect_info = collator_storage
-		.len()
-		.saturating_add(delegator_storage.len())
-		.saturating_add(activation_storage.len());
-	let reads_to_modify_state = collators_processed_count
-		.saturating_add(delegations_processed_count)
-		.saturating_add(activation_processed_count);
-	let writes_to_modify_state = collators_processed_count
-		.saturating_add(delegations_processed_count)
-		.saturating_add(activation_processed_count);
-
-	T::DbWeight::get().reads_writes(
-		reads_to_collect_info.saturating_add(reads_to_modify_state as usize) as Weight + 1,
-		writes_to_modify_state as Weight + 1,
-	)
-}
+	/// Translates [`ReserveStatus`] to the representation with
+	/// [`ReserveStatusInfo::activated_3rdparty_rewards_reserves`] and
+	/// [`ReserveStatusInfo::staked_and_activated_3rdparty_rewards_reserves`]. Liquidity
+	/// activated for 3rdparty rewards before the upgrade stays in the buckets it was activated
+	/// into and is deactivated from there once the new buckets are exhausted.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply reserve status migration to mpl but failed because storage version is {:?}, and not 1",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut migrated = 0u64;
+			ReserveStatus::<T>::translate::<OldReserveStatusInfo<BalanceOf<T>>, _>(|_, _, old| {
+				migrated += 1;
+				Some(ReserveStatusInfo {
+					staked_unactivated_reserves: old.staked_unactivated_reserves,
+					activated_unstaked_reserves: old.activated_unstaked_reserves,
+					staked_and_activated_reserves: old.staked_and_activated_reserves,
+					unspent_reserves: old.unspent_reserves,
+					relock_amount: old.relock_amount,
+					activated_3rdparty_rewards_reserves: Zero::zero(),
+					staked_and_activated_3rdparty_rewards_reserves: Zero::zero(),
+				})
+			});
+			StorageVersion::new(2).put::<Pallet<T>>();
 
-#[cfg(feature = "try-runtime")]
-pub fn migrate_from_v0_post_runtime_upgrade<T: Config, P: GetStorageVersion + PalletInfoAccess>(
-) -> Result<(), &'static str> {
-	match Pallet::<T>::get_temp_storage("is_pre_migration") {
-		None | Some(false) => {
 			log::info!(
-				target: "mpl",
-				"Attempted to apply xyk-staking-mpl consistency post-migration to mpl but failed because pre-migration failed to run"
+				target: LOG_TARGET,
+				"Migrated {} reserve statuses of mpl to storage version 2",
+				migrated,
 			);
-			return Ok(())
-		},
-		Some(true) => {},
-	};
-
-	let on_chain_storage_version = <P as GetStorageVersion>::on_chain_storage_version();
-
-	// If on chain version is still 0 then the migration failed
-	if on_chain_storage_version == 0 {
-		log::info!(
-			target: "mpl",
-			"Attempted to apply xyk-staking-mpl consistency post-migration to mpl but failed because storage version is still 0"
-		);
-		return Ok(())
-	}
 
-	log::info!(
-		target: "mpl",
-		"Running xyk-staking-mpl consistency post-migration to mpl with storage version {:?}",
-		on_chain_storage_version,
-	);
-
-	// Check consistency of xyk storage, staking storage and orml reserves
-
-	let user_reserve_info: BTreeMap<(T::AccountId, TokenId), (Balance, Balance, Balance)> =
-		Pallet::<T>::get_temp_storage("user_reserve_info").expect("temp storage was set");
-
-	for ((account, liquidity_token), (xyk_reserve, staking_reserve, total_reserve)) in
-		user_reserve_info.iter()
-	{
-		assert_eq!(xyk_reserve.saturating_add(*staking_reserve), *total_reserve);
-		assert_eq!(
-			T::Tokens::reserved_balance({ *liquidity_token }.into(), account).into(),
-			*total_reserve
-		);
-		let reserve_status = Pallet::<T>::get_reserve_status(account, liquidity_token);
-		assert!(reserve_status.staked_and_activated_reserves.is_zero());
-		assert_eq!(reserve_status.activated_unstaked_reserves, *xyk_reserve);
-		assert_eq!(reserve_status.staked_unactivated_reserves, *staking_reserve);
-		assert!(reserve_status.unspent_reserves.is_zero());
-		assert!(reserve_status.relock_amount.is_zero());
-		assert!(Pallet::<T>::get_relock_status(account, liquidity_token).is_empty());
+			T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+		}
 	}
-
-	log::info!(
-		target: "mpl",
-		"xyk-staking-mpl consistency post-migration to mpl with storage version {:?} completed successfully",
-		on_chain_storage_version,
-	);
-
-	Ok(())
 }
@@ -1,6 +1,6 @@
 use super::*;
 use crate::mock::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok, traits::OnRuntimeUpgrade};
 use frame_system::RawOrigin;
 use pallet_vesting_mangata::VestingInfo;
 
@@ -750,3 +750,637 @@ fn vested_mpl_vested_transition_works_for_native_tokens() {
 		assert_eq!(orml_tokens::Accounts::<Test>::get(ALICE, MGX).frozen, MILLION);
 	})
 }
+
+#[test]
+fn get_relock_unlocked_amount_at_works() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let asset_id: TokenId = 1;
+
+		RelockStatus::<Test>::insert(
+			caller,
+			asset_id,
+			BoundedVec::truncate_from(vec![
+				RelockStatusInfo::<Balance, BlockNumberFor<Test>> {
+					amount: 100_000__u128,
+					starting_block: 100,
+					ending_block_as_balance: 200__u128,
+				},
+				RelockStatusInfo::<Balance, BlockNumberFor<Test>> {
+					amount: 50_000__u128,
+					starting_block: 150,
+					ending_block_as_balance: 250__u128,
+				},
+			]),
+		);
+
+		assert_eq!(MultiPurposeLiquidity::get_relock_unlocked_amount_at(&caller, asset_id, 50), 0);
+		assert_eq!(
+			MultiPurposeLiquidity::get_relock_unlocked_amount_at(&caller, asset_id, 150),
+			50_000__u128
+		);
+		assert_eq!(
+			MultiPurposeLiquidity::get_relock_unlocked_amount_at(&caller, asset_id, 200),
+			125_000__u128
+		);
+		assert_eq!(
+			MultiPurposeLiquidity::get_relock_unlocked_amount_at(&caller, asset_id, 300),
+			150_000__u128
+		);
+		assert_eq!(MultiPurposeLiquidity::get_relock_unlocked_amount_at(&1u64, asset_id, 300), 0);
+	})
+}
+
+#[test]
+fn vested_liquidity_can_be_activated_for_3rdparty_rewards() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let initial_amount: Balance = 1_000_000__u128;
+		let asset_id: TokenId = <Test as Config>::Tokens::create(&caller, initial_amount).unwrap();
+		let vested_amount: Balance = 200_000__u128;
+		let native_amount: Balance = 100_000__u128;
+
+		<Test as Config>::VestingProvider::lock_tokens(
+			&caller,
+			asset_id,
+			500_000__u128,
+			None,
+			1_000__u128,
+		)
+		.unwrap();
+		assert_ok!(MultiPurposeLiquidity::do_reserve_vesting_liquidity_tokens(
+			caller,
+			asset_id,
+			vested_amount
+		));
+
+		assert_ok!(<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::activate(
+			asset_id,
+			&caller,
+			native_amount,
+			None
+		));
+		assert!(MultiPurposeLiquidity::can_activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			vested_amount,
+			Some(ActivateKind::UnspentReserves)
+		));
+		assert!(!MultiPurposeLiquidity::can_activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			vested_amount + 1,
+			Some(ActivateKind::UnspentReserves)
+		));
+		assert_ok!(MultiPurposeLiquidity::activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			vested_amount,
+			Some(ActivateKind::UnspentReserves)
+		));
+
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.unspent_reserves, 0);
+		assert_eq!(reserve_status.activated_unstaked_reserves, native_amount);
+		assert_eq!(reserve_status.activated_3rdparty_rewards_reserves, vested_amount);
+
+		// native deactivation does not touch liquidity activated for 3rdparty rewards
+		assert_eq!(
+			<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::deactivate(
+				asset_id,
+				&caller,
+				native_amount
+			),
+			0
+		);
+		assert_eq!(<Test as Config>::Tokens::free_balance(asset_id, &caller), 800_000__u128);
+		assert_eq!(
+			MultiPurposeLiquidity::get_reserve_status(caller, asset_id)
+				.activated_3rdparty_rewards_reserves,
+			vested_amount
+		);
+
+		// relocked liquidity goes back to unspent reserves
+		assert_eq!(
+			MultiPurposeLiquidity::deactivate_from_3rdparty_rewards(
+				asset_id,
+				&caller,
+				vested_amount
+			),
+			0
+		);
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.unspent_reserves, vested_amount);
+		assert_eq!(reserve_status.activated_3rdparty_rewards_reserves, 0);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), vested_amount);
+
+		assert_ok!(MultiPurposeLiquidity::unreserve_and_relock_instance(
+			RawOrigin::Signed(caller).into(),
+			asset_id,
+			0u32
+		));
+		assert_eq!(<Test as Config>::Tokens::free_balance(asset_id, &caller), initial_amount);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 0);
+	})
+}
+
+#[test]
+fn staked_liquidity_activated_for_3rdparty_rewards_is_kept_apart_from_native_activations() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let initial_amount: Balance = 1_000_000__u128;
+		let asset_id: TokenId = <Test as Config>::Tokens::create(&caller, initial_amount).unwrap();
+		let native_amount: Balance = 100_000__u128;
+		let staked_amount: Balance = 300_000__u128;
+		let activated_amount: Balance = 200_000__u128;
+
+		assert_ok!(<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::activate(
+			asset_id,
+			&caller,
+			native_amount,
+			None
+		));
+		assert_ok!(<MultiPurposeLiquidity as StakingReservesProviderTrait<_, _, _>>::bond(
+			asset_id,
+			&caller,
+			staked_amount,
+			None
+		));
+		assert!(MultiPurposeLiquidity::can_activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			activated_amount,
+			Some(ActivateKind::StakedUnactivatedReserves)
+		));
+		assert_ok!(MultiPurposeLiquidity::activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			activated_amount,
+			Some(ActivateKind::StakedUnactivatedReserves)
+		));
+
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.activated_unstaked_reserves, native_amount);
+		assert_eq!(reserve_status.staked_and_activated_reserves, 0);
+		assert_eq!(reserve_status.staked_unactivated_reserves, staked_amount - activated_amount);
+		assert_eq!(reserve_status.staked_and_activated_3rdparty_rewards_reserves, activated_amount);
+
+		// staked liquidity goes back to staked reserves, native activation is not touched
+		assert_eq!(
+			MultiPurposeLiquidity::deactivate_from_3rdparty_rewards(
+				asset_id,
+				&caller,
+				activated_amount
+			),
+			0
+		);
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.activated_unstaked_reserves, native_amount);
+		assert_eq!(reserve_status.staked_unactivated_reserves, staked_amount);
+		assert_eq!(reserve_status.staked_and_activated_3rdparty_rewards_reserves, 0);
+		assert_eq!(
+			<Test as Config>::Tokens::reserved_balance(asset_id, &caller),
+			native_amount + staked_amount
+		);
+
+		// unbonding keeps staked liquidity activated for 3rdparty rewards in the 3rdparty bucket
+		assert_ok!(MultiPurposeLiquidity::activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			activated_amount,
+			Some(ActivateKind::StakedUnactivatedReserves)
+		));
+		assert_eq!(
+			<MultiPurposeLiquidity as StakingReservesProviderTrait<_, _, _>>::unbond(
+				asset_id,
+				&caller,
+				staked_amount
+			),
+			0
+		);
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.staked_unactivated_reserves, 0);
+		assert_eq!(reserve_status.staked_and_activated_3rdparty_rewards_reserves, 0);
+		assert_eq!(reserve_status.activated_3rdparty_rewards_reserves, activated_amount);
+		assert_eq!(reserve_status.activated_unstaked_reserves, native_amount);
+
+		assert_eq!(
+			<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::deactivate(
+				asset_id,
+				&caller,
+				native_amount
+			),
+			0
+		);
+		assert_eq!(
+			MultiPurposeLiquidity::get_reserve_status(caller, asset_id)
+				.activated_3rdparty_rewards_reserves,
+			activated_amount
+		);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), activated_amount);
+		assert_eq!(
+			<Test as Config>::Tokens::free_balance(asset_id, &caller),
+			initial_amount - activated_amount
+		);
+	})
+}
+
+#[test]
+fn migration_adds_3rdparty_rewards_reserves_bucket() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let asset_id: TokenId = 1;
+
+		StorageVersion::new(1).put::<MultiPurposeLiquidity>();
+		frame_support::storage::unhashed::put(
+			&ReserveStatus::<Test>::hashed_key_for(caller, asset_id),
+			&crate::migration::v2::OldReserveStatusInfo::<Balance> {
+				staked_unactivated_reserves: 1,
+				activated_unstaked_reserves: 2,
+				staked_and_activated_reserves: 3,
+				unspent_reserves: 4,
+				relock_amount: 5,
+			},
+		);
+
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			MultiPurposeLiquidity::get_reserve_status(caller, asset_id),
+			ReserveStatusInfo {
+				staked_unactivated_reserves: 1,
+				activated_unstaked_reserves: 2,
+				staked_and_activated_reserves: 3,
+				unspent_reserves: 4,
+				relock_amount: 5,
+				activated_3rdparty_rewards_reserves: 0,
+				staked_and_activated_3rdparty_rewards_reserves: 0,
+			}
+		);
+		assert_eq!(MultiPurposeLiquidity::on_chain_storage_version(), StorageVersion::new(2));
+	})
+}
+
+#[test]
+fn migrations_initialize_reserve_status_from_xyk_activations() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let asset_id: TokenId = 1;
+
+		StorageVersion::new(0).put::<MultiPurposeLiquidity>();
+		frame_support::migration::put_storage_value(
+			b"Xyk",
+			b"LiquidityMiningActiveUser",
+			&<Twox64Concat as frame_support::StorageHasher>::hash(&(caller, asset_id).encode()),
+			1_000__u128,
+		);
+
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(MultiPurposeLiquidity::on_chain_storage_version(), StorageVersion::new(1));
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			MultiPurposeLiquidity::get_reserve_status(caller, asset_id),
+			ReserveStatusInfo { activated_unstaked_reserves: 1_000__u128, ..Default::default() }
+		);
+		assert_eq!(MultiPurposeLiquidity::on_chain_storage_version(), StorageVersion::new(2));
+	})
+}
+
+#[test]
+fn activated_reserves_are_transferred_together_with_relocks() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let destination = 1u64;
+		let initial_amount: Balance = 1_000_000__u128;
+		let asset_id: TokenId = <Test as Config>::Tokens::create(&caller, initial_amount).unwrap();
+		let vested_amount: Balance = 200_000__u128;
+		let native_amount: Balance = 100_000__u128;
+		let third_party_amount: Balance = 50_000__u128;
+
+		<Test as Config>::VestingProvider::lock_tokens(
+			&caller,
+			asset_id,
+			500_000__u128,
+			None,
+			1_000__u128,
+		)
+		.unwrap();
+		assert_ok!(MultiPurposeLiquidity::do_reserve_vesting_liquidity_tokens(
+			caller,
+			asset_id,
+			vested_amount
+		));
+		assert_ok!(<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::activate(
+			asset_id,
+			&caller,
+			native_amount,
+			Some(ActivateKind::UnspentReserves)
+		));
+		assert_ok!(MultiPurposeLiquidity::activate_for_3rdparty_rewards(
+			asset_id,
+			&caller,
+			third_party_amount,
+			None
+		));
+
+		assert_ok!(MultiPurposeLiquidity::transfer_activated_reserves(
+			asset_id,
+			&caller,
+			&destination,
+			native_amount,
+			third_party_amount
+		));
+
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.activated_unstaked_reserves, 0);
+		assert_eq!(reserve_status.activated_3rdparty_rewards_reserves, 0);
+		assert_eq!(reserve_status.unspent_reserves, vested_amount - native_amount);
+		assert_eq!(reserve_status.relock_amount, vested_amount - native_amount);
+		assert_eq!(
+			<Test as Config>::Tokens::reserved_balance(asset_id, &caller),
+			vested_amount - native_amount
+		);
+		assert_eq!(
+			MultiPurposeLiquidity::get_relock_status(caller, asset_id)
+				.into_iter()
+				.map(|relock| relock.amount)
+				.collect::<Vec<_>>(),
+			vec![vested_amount - native_amount]
+		);
+
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(destination, asset_id);
+		assert_eq!(reserve_status.activated_unstaked_reserves, native_amount);
+		assert_eq!(reserve_status.activated_3rdparty_rewards_reserves, third_party_amount);
+		assert_eq!(reserve_status.relock_amount, native_amount);
+		assert_eq!(
+			<Test as Config>::Tokens::reserved_balance(asset_id, &destination),
+			native_amount + third_party_amount
+		);
+		let relocks = MultiPurposeLiquidity::get_relock_status(destination, asset_id);
+		assert_eq!(relocks.len(), 1);
+		assert_eq!(relocks[0].amount, native_amount);
+		assert_eq!(relocks[0].ending_block_as_balance, 1_000__u128);
+		assert!(RelockedAccounts::<Test>::contains_key((destination, asset_id)));
+
+		// relocked liquidity goes back to unspent reserves of the destination
+		assert_eq!(
+			<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::deactivate(
+				asset_id,
+				&destination,
+				native_amount
+			),
+			0
+		);
+		assert_eq!(
+			MultiPurposeLiquidity::get_reserve_status(destination, asset_id).unspent_reserves,
+			native_amount
+		);
+	})
+}
+
+#[test]
+fn activated_reserves_backed_by_stake_cannot_be_transferred() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let destination = 1u64;
+		let asset_id: TokenId = <Test as Config>::Tokens::create(&caller, 1_000_000__u128).unwrap();
+
+		assert_ok!(<MultiPurposeLiquidity as StakingReservesProviderTrait<_, _, _>>::bond(
+			asset_id,
+			&caller,
+			100_000__u128,
+			None
+		));
+		assert_ok!(<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::activate(
+			asset_id,
+			&caller,
+			100_000__u128,
+			Some(ActivateKind::StakedUnactivatedReserves)
+		));
+
+		assert_noop!(
+			MultiPurposeLiquidity::transfer_activated_reserves(
+				asset_id,
+				&caller,
+				&destination,
+				100_000__u128,
+				0
+			),
+			Error::<Test>::NotEnoughTokens
+		);
+	})
+}
+
+fn setup_relocks(
+	caller: AccountId,
+	relocks: Vec<RelockStatusInfo<Balance, BlockNumberFor<Test>>>,
+) -> TokenId {
+	let asset_id: TokenId = <Test as Config>::Tokens::create(&caller, 1_000_000__u128).unwrap();
+	let relock_amount = relocks.iter().map(|relock| relock.amount).sum::<Balance>();
+	<Test as Config>::Tokens::reserve(asset_id, &caller, relock_amount).unwrap();
+	let mut reserve_status = ReserveStatusInfo::default();
+	reserve_status.unspent_reserves = relock_amount;
+	reserve_status.relock_amount = relock_amount;
+	ReserveStatus::<Test>::insert(caller, asset_id, reserve_status);
+	RelockStatus::<Test>::insert(caller, asset_id, BoundedVec::truncate_from(relocks));
+	RelockedAccounts::<Test>::insert((caller, asset_id), ());
+	asset_id
+}
+
+#[test]
+fn expired_relocks_are_released_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let caller = 0u64;
+		let asset_id = setup_relocks(
+			caller,
+			vec![
+				RelockStatusInfo {
+					amount: 100_000__u128,
+					starting_block: 1,
+					ending_block_as_balance: 10,
+				},
+				RelockStatusInfo {
+					amount: 50_000__u128,
+					starting_block: 1,
+					ending_block_as_balance: 100,
+				},
+			],
+		);
+
+		MultiPurposeLiquidity::on_idle(5, Weight::MAX);
+		assert_eq!(MultiPurposeLiquidity::get_relock_status(caller, asset_id).len(), 2);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 150_000__u128);
+
+		MultiPurposeLiquidity::on_idle(10, Weight::MAX);
+		assert_eq!(
+			MultiPurposeLiquidity::get_relock_status(caller, asset_id).into_inner(),
+			vec![RelockStatusInfo {
+				amount: 50_000__u128,
+				starting_block: 1,
+				ending_block_as_balance: 100
+			}]
+		);
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.unspent_reserves, 50_000__u128);
+		assert_eq!(reserve_status.relock_amount, 50_000__u128);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 50_000__u128);
+		System::assert_last_event(RuntimeEvent::MultiPurposeLiquidity(
+			Event::ExpiredRelockReleased(caller, asset_id, 100_000__u128, 10),
+		));
+
+		MultiPurposeLiquidity::on_idle(100, Weight::MAX);
+		assert_eq!(MultiPurposeLiquidity::get_relock_status(caller, asset_id).len(), 0);
+		assert!(!RelockedAccounts::<Test>::contains_key((caller, asset_id)));
+		assert_eq!(MultiPurposeLiquidity::get_reserve_status(caller, asset_id), Default::default());
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 0);
+		assert_eq!(<Test as Config>::Tokens::free_balance(asset_id, &caller), 1_000_000__u128);
+	})
+}
+
+#[test]
+fn expired_relock_activated_for_rewards_is_released_on_deactivation() {
+	new_test_ext().execute_with(|| {
+		let caller = 0u64;
+		let asset_id = setup_relocks(
+			caller,
+			vec![RelockStatusInfo {
+				amount: 100_000__u128,
+				starting_block: 1,
+				ending_block_as_balance: 10,
+			}],
+		);
+		assert_ok!(<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::activate(
+			asset_id,
+			&caller,
+			60_000__u128,
+			Some(ActivateKind::UnspentReserves)
+		));
+
+		MultiPurposeLiquidity::on_idle(10, Weight::MAX);
+		let reserve_status = MultiPurposeLiquidity::get_reserve_status(caller, asset_id);
+		assert_eq!(reserve_status.relock_amount, 0);
+		assert_eq!(reserve_status.unspent_reserves, 0);
+		assert_eq!(reserve_status.activated_unstaked_reserves, 60_000__u128);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 60_000__u128);
+
+		<MultiPurposeLiquidity as ActivationReservesProviderTrait<_, _, _>>::deactivate(
+			asset_id,
+			&caller,
+			60_000__u128,
+		);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 0);
+	})
+}
+
+#[test]
+fn expired_relocks_processing_resumes_from_cursor() {
+	new_test_ext().execute_with(|| {
+		let relock = RelockStatusInfo {
+			amount: 1_000__u128,
+			starting_block: 1,
+			ending_block_as_balance: 10,
+		};
+		let relocked = (0u64..3)
+			.map(|caller| (caller, setup_relocks(caller, vec![relock.clone()])))
+			.collect::<Vec<_>>();
+
+		let single_iteration =
+			<Test as Config>::WeightInfo::release_expired_relocks(MaxLocks::get()) +
+				<Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+		assert_eq!(
+			MultiPurposeLiquidity::process_expired_relocks(10, single_iteration),
+			single_iteration
+		);
+		assert!(RelockExpiryCursor::<Test>::get().is_some());
+		assert_eq!(
+			relocked
+				.iter()
+				.filter(|key| RelockedAccounts::<Test>::contains_key(*key))
+				.count(),
+			2
+		);
+
+		MultiPurposeLiquidity::process_expired_relocks(10, single_iteration);
+		MultiPurposeLiquidity::process_expired_relocks(10, single_iteration);
+		assert_eq!(RelockedAccounts::<Test>::iter_keys().count(), 0);
+
+		// the end of the index was reached
+		MultiPurposeLiquidity::process_expired_relocks(10, single_iteration);
+		assert!(RelockExpiryCursor::<Test>::get().is_none());
+		for (caller, asset_id) in relocked {
+			assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 0);
+		}
+	})
+}
+
+#[test]
+fn expired_relocks_processing_is_limited_by_proof_size() {
+	new_test_ext().execute_with(|| {
+		let relock = RelockStatusInfo {
+			amount: 1_000__u128,
+			starting_block: 1,
+			ending_block_as_balance: 10,
+		};
+		for caller in 0u64..3 {
+			setup_relocks(caller, vec![relock.clone()]);
+		}
+
+		let single_iteration =
+			<Test as Config>::WeightInfo::release_expired_relocks(MaxLocks::get()) +
+				<Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1);
+		let weight_limit = Weight::from_parts(u64::MAX, single_iteration.proof_size());
+		assert_eq!(
+			MultiPurposeLiquidity::process_expired_relocks(10, weight_limit),
+			single_iteration
+		);
+		assert_eq!(RelockedAccounts::<Test>::iter_keys().count(), 2);
+
+		assert_eq!(
+			MultiPurposeLiquidity::process_expired_relocks(
+				10,
+				Weight::from_parts(u64::MAX, single_iteration.proof_size() - 1)
+			),
+			Weight::from_parts(0, 0)
+		);
+		assert_eq!(RelockedAccounts::<Test>::iter_keys().count(), 2);
+	})
+}
+
+#[test]
+fn index_relocked_accounts_tracks_relocks_created_before_the_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let caller = 0u64;
+		let asset_id = setup_relocks(
+			caller,
+			vec![RelockStatusInfo {
+				amount: 100_000__u128,
+				starting_block: 1,
+				ending_block_as_balance: 10,
+			}],
+		);
+		// relock created before the index was introduced
+		RelockedAccounts::<Test>::remove((caller, asset_id));
+
+		assert_noop!(
+			MultiPurposeLiquidity::index_relocked_accounts(
+				RawOrigin::Signed(caller).into(),
+				vec![(caller, asset_id)]
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(MultiPurposeLiquidity::index_relocked_accounts(
+			RawOrigin::Root.into(),
+			vec![(caller, asset_id), (1u64, asset_id)]
+		));
+		assert!(RelockedAccounts::<Test>::contains_key((caller, asset_id)));
+		assert!(!RelockedAccounts::<Test>::contains_key((1u64, asset_id)));
+
+		MultiPurposeLiquidity::on_idle(10, Weight::MAX);
+		assert_eq!(MultiPurposeLiquidity::get_relock_status(caller, asset_id).len(), 0);
+		assert_eq!(<Test as Config>::Tokens::reserved_balance(asset_id, &caller), 0);
+	})
+}
@@ -56,6 +56,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn reserve_vesting_liquidity_tokens() -> Weight;
 	fn unreserve_and_relock_instance() -> Weight;
+	fn release_expired_relocks(x: u32, ) -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -70,4 +71,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	fn release_expired_relocks(x: u32, ) -> Weight {
+		Weight::from_parts(38_120_000, 12_029)
+			.saturating_add(Weight::from_parts(1_310_000, 0).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
 }
@@ -3377,6 +3377,31 @@ pub mod pallet {
 			}
 		}
 
+		/// Value of the collator self bond and delegations of the account, liquidity tokens are
+		/// valuated by their native token share of the pool
+		pub fn get_bonded_value(acc: &T::AccountId) -> BalanceOf<T> {
+			let valuate = |liquidity_token: CurrencyIdOf<T>, amount: BalanceOf<T>| {
+				if liquidity_token == T::NativeTokenId::get() {
+					amount
+				} else {
+					T::StakingLiquidityTokenValuator::valuate_liquidity_token(
+						liquidity_token.into(),
+						amount,
+					)
+				}
+			};
+			let self_bond = <CandidateState<T>>::get(acc)
+				.map(|state| valuate(state.liquidity_token, state.bond))
+				.unwrap_or_default();
+			<DelegatorState<T>>::get(acc)
+				.map(|state| {
+					state.delegations.0.iter().fold(self_bond, |total, bond| {
+						total.saturating_add(valuate(bond.liquidity_token, bond.amount))
+					})
+				})
+				.unwrap_or(self_bond)
+		}
+
 		fn do_payout_collator_rewards(
 			collator: T::AccountId,
 			number_of_sesisons: Option<u32>,
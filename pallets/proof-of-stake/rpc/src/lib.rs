@@ -7,8 +7,13 @@ use jsonrpsee::{
 	types::error::{CallError, ErrorObject},
 };
 pub use proof_of_stake_runtime_api::ProofOfStakeApi as ProofOfStakeRuntimeApi;
-use sp_api::ProvideRuntimeApi;
+use proof_of_stake_runtime_api::{
+	RpcClaimReceipt, RpcRewardAmount, RpcRewardsEstimation, RpcScheduleInfo, RpcScheduleRate,
+	RpcSessionRewards, RpcThirdPartyActivation, RpcUserActivations,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::U256;
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
 use std::sync::Arc;
@@ -53,6 +58,70 @@ pub trait ProofOfStakeApi<BlockHash, Balance, TokenId, AccountId> {
 		account: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<(TokenId, TokenId, NumberOrHex)>>;
+
+	/// Returns list of active 3rdparty rewards schedules for given liquidity token
+	///
+	/// * `liquidity_token` - liquidity token id
+	/// * `at` - optional block hash
+	#[method(name = "pos_get_rewards_schedules")]
+	fn get_rewards_schedules(
+		&self,
+		liquidity_token: TokenId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcScheduleInfo<NumberOrHex, TokenId>>>;
+
+	/// Returns all native and 3rdparty activations of given account together with amount of
+	/// rewards available for claiming
+	///
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "pos_get_user_activations")]
+	fn get_user_activations(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcUserActivations<NumberOrHex, TokenId>>>;
+
+	/// Estimates rewards (valuated in native token) and APR of hypothetical activation
+	///
+	/// * `liquidity_token` - liquidity token id
+	/// * `amount` - amount of liquidity tokens to be activated
+	/// * `at` - optional block hash
+	#[method(name = "pos_estimate_rewards")]
+	fn estimate_rewards(
+		&self,
+		liquidity_token: TokenId,
+		amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcRewardsEstimation<NumberOrHex, TokenId>>;
+
+	/// Returns native and 3rdparty rewards distributed to given liquidity token in every
+	/// session from the range, only the most recent sessions are available
+	///
+	/// * `liquidity_token` - liquidity token id
+	/// * `from_session` - first session of the range
+	/// * `to_session` - last session of the range
+	/// * `at` - optional block hash
+	#[method(name = "pos_get_rewards_history")]
+	fn get_rewards_history(
+		&self,
+		liquidity_token: TokenId,
+		from_session: u32,
+		to_session: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcSessionRewards<NumberOrHex, TokenId>>>;
+
+	/// Returns receipts of rewards claimed for given account activations, they are only stored
+	/// if account opted in for them
+	///
+	/// * `account` - user account address
+	/// * `at` - optional block hash
+	#[method(name = "pos_get_claim_receipts")]
+	fn get_claim_receipts(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcClaimReceipt<NumberOrHex, TokenId>>>;
 }
 
 pub struct ProofOfStake<C, M> {
@@ -66,6 +135,56 @@ impl<C, P> ProofOfStake<C, P> {
 	}
 }
 
+trait TryIntoBalance<Balance> {
+	fn try_into_balance(self) -> RpcResult<Balance>;
+}
+
+impl<T: TryFrom<U256>> TryIntoBalance<T> for NumberOrHex {
+	fn try_into_balance(self) -> RpcResult<T> {
+		self.into_u256().try_into().or(Err(JsonRpseeError::Call(CallError::Custom(
+			ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(String::from("input parameter doesnt fit into u128")),
+			),
+		))))
+	}
+}
+
+/// Methods added in version 2 of [`ProofOfStakeRuntimeApi`] can not be called on runtimes
+/// implementing older version of the api
+fn ensure_api_version<Block, Api, Balance, TokenId, AccountId>(
+	api: &Api,
+	at: <Block as BlockT>::Hash,
+	version: u32,
+) -> RpcResult<()>
+where
+	Block: BlockT,
+	Api: ApiExt<Block> + ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+	TokenId: Codec + MaybeDisplay + MaybeFromStr,
+	AccountId: Codec + MaybeDisplay + MaybeFromStr,
+{
+	let runtime_version = api
+		.api_version::<dyn ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>>(at)
+		.map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				1,
+				"Unable to serve the request",
+				Some(format!("{:?}", e)),
+			)))
+		})?;
+
+	if runtime_version.map_or(true, |runtime_version| runtime_version < version) {
+		return Err(JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+			1,
+			"Unable to serve the request",
+			Some(format!("ProofOfStakeApi version {} is not supported by the runtime", version)),
+		))))
+	}
+	Ok(())
+}
+
 #[async_trait]
 impl<C, Block, Balance, TokenId, AccountId>
 	ProofOfStakeApiServer<<Block as BlockT>::Hash, Balance, TokenId, AccountId>
@@ -76,7 +195,7 @@ where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block>,
 	C::Api: ProofOfStakeRuntimeApi<Block, Balance, TokenId, AccountId>,
-	Balance: Codec + MaybeDisplay + MaybeFromStr + Into<NumberOrHex>,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + TryFrom<U256> + Into<NumberOrHex>,
 	TokenId: Codec + MaybeDisplay + MaybeFromStr,
 	AccountId: Codec + MaybeDisplay + MaybeFromStr,
 {
@@ -145,4 +264,171 @@ where
 				)))
 			})
 	}
+
+	fn get_rewards_schedules(
+		&self,
+		liquidity_token: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcScheduleInfo<NumberOrHex, TokenId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+		ensure_api_version::<Block, _, Balance, TokenId, AccountId>(&*api, at, 2)?;
+
+		api.get_rewards_schedules(at, liquidity_token)
+			.map(|vec| {
+				vec.into_iter()
+					.map(|schedule| RpcScheduleInfo {
+						reward_token: schedule.reward_token,
+						amount_per_session: schedule.amount_per_session.into(),
+						remaining_sessions: schedule.remaining_sessions,
+					})
+					.collect()
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
+
+	fn get_user_activations(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcUserActivations<NumberOrHex, TokenId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+		ensure_api_version::<Block, _, Balance, TokenId, AccountId>(&*api, at, 2)?;
+
+		api.get_user_activations(at, account)
+			.map(|vec| {
+				vec.into_iter()
+					.map(|activations| RpcUserActivations {
+						liquidity_token: activations.liquidity_token,
+						native_activated_amount: activations.native_activated_amount.into(),
+						native_reactivated_amount: activations.native_reactivated_amount.into(),
+						native_claimable_amount: activations.native_claimable_amount.into(),
+						third_party: activations
+							.third_party
+							.into_iter()
+							.map(|activation| RpcThirdPartyActivation {
+								reward_token: activation.reward_token,
+								activated_amount: activation.activated_amount.into(),
+								claimable_amount: activation.claimable_amount.into(),
+							})
+							.collect(),
+					})
+					.collect()
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
+
+	fn estimate_rewards(
+		&self,
+		liquidity_token: TokenId,
+		amount: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcRewardsEstimation<NumberOrHex, TokenId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+		ensure_api_version::<Block, _, Balance, TokenId, AccountId>(&*api, at, 2)?;
+
+		api.estimate_rewards(at, liquidity_token, amount.try_into_balance()?)
+			.map(|estimation| RpcRewardsEstimation {
+				native_rewards_per_session: estimation.native_rewards_per_session.into(),
+				third_party_rewards_per_session: estimation
+					.third_party_rewards_per_session
+					.into_iter()
+					.map(|rate| RpcScheduleRate {
+						reward_token: rate.reward_token,
+						amount_per_session: rate.amount_per_session.into(),
+						valuation_per_session: rate.valuation_per_session.into(),
+					})
+					.collect(),
+				activation_valuation: estimation.activation_valuation.into(),
+				estimated_yearly_rewards: estimation.estimated_yearly_rewards.into(),
+				estimated_apr_bps: estimation.estimated_apr_bps,
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
+
+	fn get_rewards_history(
+		&self,
+		liquidity_token: TokenId,
+		from_session: u32,
+		to_session: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcSessionRewards<NumberOrHex, TokenId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+		ensure_api_version::<Block, _, Balance, TokenId, AccountId>(&*api, at, 2)?;
+
+		api.get_rewards_history(at, liquidity_token, from_session, to_session)
+			.map(|vec| {
+				vec.into_iter()
+					.map(|rewards| RpcSessionRewards {
+						session: rewards.session,
+						native_rewards: rewards.native_rewards.into(),
+						third_party_rewards: rewards
+							.third_party_rewards
+							.into_iter()
+							.map(|reward| RpcRewardAmount {
+								reward_token: reward.reward_token,
+								amount: reward.amount.into(),
+							})
+							.collect(),
+					})
+					.collect()
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
+
+	fn get_claim_receipts(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcClaimReceipt<NumberOrHex, TokenId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(self.client.info().best_hash);
+		ensure_api_version::<Block, _, Balance, TokenId, AccountId>(&*api, at, 2)?;
+
+		api.get_claim_receipts(at, account)
+			.map(|vec| {
+				vec.into_iter()
+					.map(|receipt| RpcClaimReceipt {
+						session: receipt.session,
+						liquidity_token: receipt.liquidity_token,
+						reward_token: receipt.reward_token,
+						amount: receipt.amount.into(),
+					})
+					.collect()
+			})
+			.map_err(|e| {
+				JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+					1,
+					"Unable to serve the request",
+					Some(format!("{:?}", e)),
+				)))
+			})
+	}
 }
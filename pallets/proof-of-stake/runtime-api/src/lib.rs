@@ -1,10 +1,97 @@
 // Copyright (C) 2021 Mangata team
 #![cfg_attr(not(feature = "std"), no_std)]
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
 use sp_std::vec::Vec;
 
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcScheduleInfo<Balance, TokenId> {
+	pub reward_token: TokenId,
+	pub amount_per_session: Balance,
+	/// number of sessions that will be rewarded after the current one
+	pub remaining_sessions: u32,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcThirdPartyActivation<Balance, TokenId> {
+	pub reward_token: TokenId,
+	pub activated_amount: Balance,
+	pub claimable_amount: Balance,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcUserActivations<Balance, TokenId> {
+	pub liquidity_token: TokenId,
+	pub native_activated_amount: Balance,
+	/// part of native activated amount that is reused for 3rdparty rewards
+	pub native_reactivated_amount: Balance,
+	pub native_claimable_amount: Balance,
+	pub third_party: Vec<RpcThirdPartyActivation<Balance, TokenId>>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcScheduleRate<Balance, TokenId> {
+	pub reward_token: TokenId,
+	pub amount_per_session: Balance,
+	/// amount per session valuated in native token
+	pub valuation_per_session: Balance,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcRewardsEstimation<Balance, TokenId> {
+	pub native_rewards_per_session: Balance,
+	pub third_party_rewards_per_session: Vec<RpcScheduleRate<Balance, TokenId>>,
+	/// valuation of estimated activation in native token
+	pub activation_valuation: Balance,
+	/// yearly rewards of estimated activation valuated in native token
+	pub estimated_yearly_rewards: Balance,
+	/// estimated APR expressed in basis points
+	pub estimated_apr_bps: u128,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcRewardAmount<Balance, TokenId> {
+	pub reward_token: TokenId,
+	pub amount: Balance,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcSessionRewards<Balance, TokenId> {
+	pub session: u32,
+	pub native_rewards: Balance,
+	pub third_party_rewards: Vec<RpcRewardAmount<Balance, TokenId>>,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct RpcClaimReceipt<Balance, TokenId> {
+	/// session that rewards were claimed in
+	pub session: u32,
+	pub liquidity_token: TokenId,
+	pub reward_token: TokenId,
+	pub amount: Balance,
+}
+
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait ProofOfStakeApi<Balance, TokenId, AccountId> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 		TokenId: Codec + MaybeDisplay + MaybeFromStr,
@@ -24,5 +111,33 @@ sp_api::decl_runtime_apis! {
 		fn calculate_3rdparty_rewards_all(
 			user: AccountId,
 		) -> Vec<(TokenId, TokenId, Balance)>;
+
+		#[api_version(2)]
+		fn get_rewards_schedules(
+			liquidity_asset_id: TokenId,
+		) -> Vec<RpcScheduleInfo<Balance, TokenId>>;
+
+		#[api_version(2)]
+		fn get_user_activations(
+			user: AccountId,
+		) -> Vec<RpcUserActivations<Balance, TokenId>>;
+
+		#[api_version(2)]
+		fn estimate_rewards(
+			liquidity_asset_id: TokenId,
+			amount: Balance,
+		) -> RpcRewardsEstimation<Balance, TokenId>;
+
+		#[api_version(2)]
+		fn get_rewards_history(
+			liquidity_asset_id: TokenId,
+			from_session: u32,
+			to_session: u32,
+		) -> Vec<RpcSessionRewards<Balance, TokenId>>;
+
+		#[api_version(2)]
+		fn get_claim_receipts(
+			user: AccountId,
+		) -> Vec<RpcClaimReceipt<Balance, TokenId>>;
 	}
 }
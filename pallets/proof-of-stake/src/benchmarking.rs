@@ -287,16 +287,16 @@ benchmarks! {
 		let reward_token = token_id + One::one();
 
 		assert_eq!(
-			PoS::<T>::list_metadata().count,
-			(schedules_limit - 1 ) as u64
+			ActiveSchedulesCount::<T>::get(),
+			schedules_limit - 2
 		);
 
 	}: reward_pool(RawOrigin::Signed(caller.clone().into()), (native_asset_id,token_id), reward_token.into(), REWARDS_AMOUNT.to_balance::<T>(), 10u32.into())
 	verify {
 
 		assert_eq!(
-			PoS::<T>::list_metadata().count,
-			(schedules_limit as u64)
+			ActiveSchedulesCount::<T>::get(),
+			schedules_limit - 1
 		);
 
 	}
@@ -511,5 +511,21 @@ benchmarks! {
 		assert_eq!(balance_after - balance_before, REWARDS_AMOUNT);
 	}
 
+	set_rewards_destination{
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		let destination: <T as frame_system::Config>::AccountId = account("destination", 0, 0);
+	}: set_rewards_destination(RawOrigin::Signed(caller.clone().into()), Some(destination.clone()))
+	verify {
+		assert_eq!(RewardsDestination::<T>::get(&caller), Some(destination));
+	}
+
+	set_rewards_claimer{
+		let caller: <T as frame_system::Config>::AccountId = whitelisted_caller();
+		let claimer: <T as frame_system::Config>::AccountId = account("claimer", 0, 0);
+	}: set_rewards_claimer(RawOrigin::Signed(caller.clone().into()), Some(claimer.clone()))
+	verify {
+		assert_eq!(RewardsClaimer::<T>::get(&caller), Some(claimer));
+	}
+
 	impl_benchmark_test_suite!(PoS, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -30,6 +30,8 @@
 //! - [`PromotedPoolRewards`] - Stores information about the total amount of rewards for each liquidity
 //! token.
 //! - [`RewardsInfo`] - Stores information about rewards for liquidity mining.
+//! - [`ActivatedPools`] - Stores pools that account has liquidity activated in, limited by
+//! `MaxActivatedPoolsPerUser`.
 //! - [`ThirdPartyActivationKind`] - Wrapper over origin ActivateKind that is used in
 //!
 //! #### Extrinsics
@@ -51,7 +53,13 @@
 //!
 //! - [`RewardsInfoForScheduleRewards`] - Stores information about rewards for scheduled rewards.
 //! - [`ScheduleRewardsTotal`] - Stores the amount of rewards per single liquidity token.
-//! - [`RewardsSchedules`] - Stores information about scheduled rewards.
+//! - [`ActiveSchedules`] - Stores information about scheduled rewards.
+//! - [`SchedulesChanges`] - Stores schedules that start or finish in particular session, so
+//! only schedules that change are processed at the beginning of the session.
+//! - [`SchedulesChangesCount`] - Stores the number of schedules that start or finish in
+//! particular session, limited by `MaxSchedulesChangesPerSession`.
+//! - [`ScheduleRewardsRate`] - Stores the amount of rewards distributed per session by all active
+//! schedules of particular liquidity token and reward token.
 //! - [`ScheduleId`] - Stores the unique id of the schedule.
 //! - [`RewardTokensPerPool`] - Stores information about which reward tokens are used for a particular
 //! liquidity token.
@@ -68,6 +76,7 @@
 //! - [`Pallet::reward_pool`] - Schedules rewards for the selected liquidity token.
 //! - [`Pallet::activate_liquidity_for_3rdparty_rewards`] - Activates liquidity for scheduled rewards.
 //! - [`Pallet::deactivate_liquidity_for_3rdparty_rewards`] - Deactivates liquidity for scheduled rewards.
+//! - [`Pallet::batch_deactivate_liquidity_for_3rdparty_rewards`] - Deactivates liquidity for multiple scheduled rewards at once.
 //! - [`Pallet::claim_3rdparty_rewards`] - Claims all scheduled rewards for all liquidity tokens.
 //!
 //! ## Reusing a Single Liquidity Token for Multiple Rewards
@@ -103,14 +112,44 @@
 //! - If liq tokens are `reactivated` (see [`ThirdPartyActivationKind::NativeRewardsLiquidity`])
 //! then:
 //! 	* remaining( **not reactivated**) liq tokens that were not reactivated can be unlocked using [`Pallet::deactivate_liquidity_for_native_rewards`]
-//! 	* reactivated liq tokens can be unlocked only after all of liquidity tokens ( **not only reactivated part** ) are deactivated from 3rdparty tokens and then [`Pallet::deactivate_liquidity_for_native_rewards`] is used
+//! 	* reactivated liq tokens can be unlocked only after they are not used by any of 3rdparty rewards schedules anymore and then [`Pallet::deactivate_liquidity_for_native_rewards`] is used
 //!
 //! ### 3rdparty rewards
-//! If liq tokens are used for 3rdparty rewards they are locked in the moment of activation. To get
-//! them unlocked you need to unlock all of them(liq tokens) for every 3rdparty rewards schedule/token they were used/activated.
-//! Even if you want to deactivate only part of it, all of them needs to be deactivated, afterwards they
-//! can be reactivated with no penalty in terms of recevied rewards (you will get same amount of rewards as you would
-//! not be deactivating them)
+//! If liq tokens are used for 3rdparty rewards they are locked in the moment of activation. Single
+//! liq token can be activated for multiple reward tokens, so amount that stays locked is equal to
+//! the biggest activation among all reward tokens of given liquidity token. To unlock part of liq
+//! tokens you need to deactivate that part from every 3rdparty rewards schedule/token they were
+//! used/activated for. [`Pallet::batch_deactivate_liquidity_for_3rdparty_rewards`] does that in a
+//! single call. Whatever is not used by any of the schedules anymore is unlocked immediately:
+//! 	* liq tokens locked exclusively for 3rdparty rewards become transferable
+//! 	* reactivated liq tokens (see [`ThirdPartyActivationKind::NativeRewardsLiquidity`]) can be
+//! 	deactivated with [`Pallet::deactivate_liquidity_for_native_rewards`]
+//!
+//! ## Transferring activated liquidity
+//!
+//! Activated liquidity (together with rewards curve progress and not yet claimed rewards) can be
+//! moved to another account at once with [`Pallet::transfer_activated_liquidity`], so there is no
+//! need to deactivate and reactivate it. Only liquidity that was activated from available balance
+//! can be moved this way, destination account can not have any activations of the same liquidity
+//! token.
+//!
+//! ## Rewards destination
+//!
+//! By default claimed rewards (both native and 3rdparty) are transferred to the account that owns
+//! the activated liquidity. Owner can redirect them to any other account with
+//! [`Pallet::set_rewards_destination`] (see [`RewardsDestination`]). Owner can also designate an
+//! account (see [`RewardsClaimer`]) that is allowed to trigger claims on owner's behalf using
+//! [`Pallet::claim_native_rewards_for`] and [`Pallet::claim_3rdparty_rewards_for`] - rewards are
+//! still transferred to owner's rewards destination. Rewards claimed for compounding
+//! purposes are always transferred to the owner.
+//!
+//! ## Rewards history
+//!
+//! Native rewards distributed to every pool ([`NativeRewardsHistory`]) and amount of 3rdparty
+//! rewards distributed per session ([`ScheduleRewardsHistory`]) are kept for the last
+//! `RewardsHistoryLength` sessions, see [`Pallet::get_rewards_history`]. Accounts can also opt in
+//! for storing receipts of claimed rewards with [`Pallet::set_claim_receipts`] (see
+//! [`ClaimReceipts`]).
 //!
 //! ### 3rdparty Rewards
 //!
@@ -149,12 +188,55 @@ pub struct Schedule<T: Config> {
 	amount_per_session: BalanceOf<T>,
 }
 
-#[derive(Encode, Decode, Default, TypeInfo)]
-pub struct SchedulesList {
-	pub head: Option<ScheduleId>,
-	pub tail: Option<ScheduleId>,
-	pub pos: Option<ScheduleId>,
-	pub count: u64,
+/// Summary of user activations of a single liquidity token together with rewards that are
+/// available for claiming
+#[derive(Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct UserActivations<CurrencyId, Balance> {
+	pub liquidity_token: CurrencyId,
+	/// liquidity activated for native rewards
+	pub native_activated: Balance,
+	/// part of `native_activated` that is reused for 3rdparty rewards
+	pub native_reactivated: Balance,
+	/// native rewards available for claiming
+	pub native_claimable: Balance,
+	/// (reward token, activated liquidity, rewards available for claiming)
+	pub third_party: Vec<(CurrencyId, Balance, Balance)>,
+}
+
+/// Rewards distributed to a single liquidity token in particular session
+#[derive(Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct SessionRewards<CurrencyId, Balance> {
+	pub session: SessionId,
+	pub native_rewards: Balance,
+	/// (reward token, amount) for every 3rdparty reward token
+	pub third_party_rewards: Vec<(CurrencyId, Balance)>,
+}
+
+/// Record of rewards claimed for particular activation
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ClaimReceipt<CurrencyId, Balance> {
+	/// session that rewards were claimed in
+	pub session: SessionId,
+	pub liquidity_token: CurrencyId,
+	pub reward_token: CurrencyId,
+	pub amount: Balance,
+}
+
+/// Rewards provided to a single liquidity token valuated in native token, together with
+/// estimation of rewards for hypothetical activation
+#[derive(Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct RewardsEstimation<CurrencyId, Balance> {
+	/// native rewards distributed to the pool per session
+	pub native_rewards_per_session: Balance,
+	/// (reward token, amount per session, amount per session valuated in native token) for every
+	/// active schedule
+	pub third_party_rewards_per_session: Vec<(CurrencyId, Balance, Balance)>,
+	/// valuation of the hypothetical activation in native token
+	pub activation_valuation: Balance,
+	/// rewards (valuated in native token) that hypothetical activation would receive in a year
+	pub estimated_yearly_rewards: Balance,
+	/// estimated APR expressed in basis points
+	pub estimated_apr_bps: u128,
 }
 
 use frame_support::{
@@ -187,21 +269,24 @@ use mangata_support::traits::XykFunctionsTrait;
 use sp_std::collections::btree_map::BTreeMap;
 
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, SaturatedConversion, Zero},
+	traits::{CheckedAdd, CheckedSub, SaturatedConversion, Saturating, Zero},
 	DispatchError, Perbill,
 };
 use sp_std::{convert::TryInto, prelude::*};
 
 mod reward_info;
-use reward_info::{RewardInfo, RewardsCalculator};
+use reward_info::{AsymptoticCurveRewards, ConstCurveRewards, RewardInfo, RewardsCalculator};
 
 mod schedule_rewards_calculator;
 use schedule_rewards_calculator::{
 	ActivatedLiquidityPerSchedule, ScheduleRewards, ScheduleRewardsCalculator,
+	ScheduleRewardsPerSession,
 };
 
 mod benchmarking;
 
+pub mod migration;
+
 #[cfg(test)]
 mod mock;
 
@@ -248,6 +333,82 @@ pub enum ThirdPartyActivationKind<CurrencyId> {
 	NativeRewardsLiquidity,
 }
 
+/// Reserves of liquidity activated for 3rdparty rewards, kept apart from liquidity activated for
+/// native rewards so deactivating one of them never releases tokens held by the other one and
+/// relocked (vested) liquidity returns to the reserves it was taken from
+pub trait ThirdPartyActivationReservesProviderTrait<AccountId, Balance, CurrencyId> {
+	fn can_activate_for_3rdparty_rewards(
+		token_id: CurrencyId,
+		account_id: &AccountId,
+		amount: Balance,
+		use_balance_from: Option<ActivateKind>,
+	) -> bool;
+
+	fn activate_for_3rdparty_rewards(
+		token_id: CurrencyId,
+		account_id: &AccountId,
+		amount: Balance,
+		use_balance_from: Option<ActivateKind>,
+	) -> DispatchResult;
+
+	/// Returns the amount that could not be deactivated
+	fn deactivate_from_3rdparty_rewards(
+		token_id: CurrencyId,
+		account_id: &AccountId,
+		amount: Balance,
+	) -> Balance;
+
+	/// Moves reserves of liquidity activated for native (`amount`) and 3rdparty
+	/// (`amount_3rdparty`) rewards to another account, keeping them activated
+	fn transfer_activated_reserves(
+		token_id: CurrencyId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		amount_3rdparty: Balance,
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance, CurrencyId>
+	ThirdPartyActivationReservesProviderTrait<AccountId, Balance, CurrencyId> for ()
+{
+	fn can_activate_for_3rdparty_rewards(
+		_token_id: CurrencyId,
+		_account_id: &AccountId,
+		_amount: Balance,
+		_use_balance_from: Option<ActivateKind>,
+	) -> bool {
+		false
+	}
+
+	fn activate_for_3rdparty_rewards(
+		_token_id: CurrencyId,
+		_account_id: &AccountId,
+		_amount: Balance,
+		_use_balance_from: Option<ActivateKind>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("3rdparty rewards activations are not supported"))
+	}
+
+	fn deactivate_from_3rdparty_rewards(
+		_token_id: CurrencyId,
+		_account_id: &AccountId,
+		amount: Balance,
+	) -> Balance {
+		amount
+	}
+
+	fn transfer_activated_reserves(
+		_token_id: CurrencyId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_amount: Balance,
+		_amount_3rdparty: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("activated reserves can not be transferred"))
+	}
+}
+
 const PALLET_ID: frame_support::PalletId = frame_support::PalletId(*b"rewards!");
 pub type SessionId = u32;
 #[frame_support::pallet]
@@ -255,129 +416,61 @@ pub mod pallet {
 
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			let session_id = Self::session_index();
-
-			// NOTE: 1R
-			if Self::is_new_session() {
-				SchedulesListMetadata::<T>::mutate(|s| s.pos = None);
+			// NOTE: schedules are only started/finished at the beginning of the session
+			if !Self::is_new_session() {
 				return Default::default()
 			}
 
-			for _ in 0..T::SchedulesPerBlock::get() {
-				// READS PER ITERTION
-				//
-				// 			ON VALID SCHEDULE  (AVERAGE)                        ====> 1 RW + N*R + N*W
-				// 				1 x READ/WRITE SCHEDULE META(HEAD,TAIL,POS)            : ALWAYS
-				// 				PER ITER:
-				// 					- READ RewardsSchedulesList   : ALWAYS
-				// 					- WRITE ScheduleRewardsTotal  : ALWAYS (pesemisitic)
-
-				// 			ON OUTDATED SCHEDULE (PESIMITIC)                   =====> 1 RW + (N-1)*W + 1W
-				// 				1 x READ/WRITE SCHEDULE META(HEAD,TAIL,POS)            : ALWAYS
-				// 				REMOVE N-1 SCHEDULES IN THE MIDDDLE
-				// 					- 1 x WRITE update previous schedudle `next`  : ALWAYS (pesemisitic)
-				// 				REMOVE LAST ELEM:
-				// 					- 1 x write update list tail (already counted in)
-				// 					- 1 x WRITE update elem before last : ALWAYS (pesemisitic)
-
-				// NOTE: 1R
-				let s = SchedulesListMetadata::<T>::get();
-				let last_valid = s.pos;
-				// NOTE: 1R
-				let pos = match (last_valid, s.head) {
-					(Some(pos), _) => {
-						if let Some((_schedule, next)) = RewardsSchedulesList::<T>::get(pos) {
-							next
-						} else {
-							None
-						}
-					},
-					(None, Some(head)) => Some(head),
-					_ => None,
-				};
-
-				if let Some(pos_val) = pos {
-					// NOTE: 1R
-					if let Some((schedule, next)) = RewardsSchedulesList::<T>::get(pos_val) {
-						if schedule.last_session >= session_id {
-							if schedule.scheduled_at < session_id {
-								// NOTE: 1R 1W
-								ScheduleRewardsTotal::<T>::mutate(
-									(schedule.liq_token, schedule.reward_token),
-									|s| s.provide_rewards(session_id, schedule.amount_per_session),
-								);
-							}
-							// NOTE: 1W
-							SchedulesListMetadata::<T>::mutate(|s| s.pos = Some(pos_val));
-						} else {
-							// NOTE: 2R
-							//
-							let meta = Self::list_metadata();
-							match (meta.head, meta.tail) {
-								(Some(head), Some(tail)) if head == pos_val && head != tail =>
-									if let Some(next) = next {
-										// NOTE: 1W
-										SchedulesListMetadata::<T>::mutate(|s| {
-											s.head = Some(next);
-											s.count -= 1;
-										});
-									},
-								(Some(head), Some(tail)) if tail == pos_val && head == tail => {
-									// NOTE: 3W
-									SchedulesListMetadata::<T>::mutate(|s| {
-										s.tail = None;
-										s.head = None;
-										s.pos = None;
-										s.count = 0;
-									});
-								},
-								(Some(head), Some(tail)) if tail == pos_val && head != tail =>
-									if let Some(last_valid) = last_valid {
-										// NOTE: 1W
-										SchedulesListMetadata::<T>::mutate(|s| {
-											s.tail = Some(last_valid);
-											s.count -= 1;
-										});
-										// NOTE: 1R 1W
-										RewardsSchedulesList::<T>::mutate(last_valid, |data| {
-											if let Some((_schedule, next)) = data.as_mut() {
-												*next = None
-											}
-										});
-									},
-								(Some(_head), Some(_tail)) =>
-									if let Some(last_valid) = last_valid {
-										SchedulesListMetadata::<T>::mutate(|s| {
-											s.count -= 1;
-										});
-										// NOTE: 1R 1W
-										RewardsSchedulesList::<T>::mutate(last_valid, |data| {
-											if let Some((_schedule, prev_next)) = data.as_mut() {
-												*prev_next = next
-											}
-										});
-									},
-								_ => {},
-							}
-						}
+			let session_id = Self::session_index();
+			let mut changes = 0u64;
+
+			// NOTE: number of changes is limited by `MaxSchedulesChangesPerSession`
+			SchedulesChangesCount::<T>::remove(session_id);
+			for (schedule_id, liq_token) in SchedulesChanges::<T>::drain_prefix(session_id) {
+				changes += 1;
+				if let Some(schedule) = ActiveSchedules::<T>::get(liq_token, schedule_id) {
+					let started = schedule.last_session >= session_id;
+					ScheduleRewardsCalculator::<T>::update_rewards_rate(
+						schedule.liq_token,
+						schedule.reward_token,
+						schedule.amount_per_session,
+						started,
+					);
+					let pair = (schedule.liq_token, schedule.reward_token);
+					ScheduleRewardsHistory::<T>::insert(
+						session_id,
+						pair,
+						ScheduleRewardsRate::<T>::get(pair).amount,
+					);
+					if !started {
+						ActiveSchedules::<T>::remove(liq_token, schedule_id);
+						ActiveSchedulesCount::<T>::mutate(|count| *count = count.saturating_sub(1));
 					}
-				} else {
-					break
 				}
 			}
 
-			// always use same amount of block space even if no schedules were processed
-			T::DbWeight::get().reads(1) +
-				T::DbWeight::get().writes(1) +
-				T::DbWeight::get().reads(T::SchedulesPerBlock::get().into()) +
-				T::DbWeight::get().writes(T::SchedulesPerBlock::get().into())
+			// NOTE: per change:
+			// 	- READ/WRITE SchedulesChanges
+			// 	- READ ActiveSchedules
+			// 	- READ/WRITE ScheduleRewardsRate
+			// 	- READ/WRITE ScheduleRewardsTotal
+			// 	- WRITE ScheduleRewardsHistory
+			// 	- WRITE ActiveSchedules, READ/WRITE ActiveSchedulesCount (on schedule end)
+			let pruned = Self::prune_rewards_history(session_id);
+
+			T::DbWeight::get().reads_writes(1, 1) +
+				T::DbWeight::get().reads_writes(5, 6).saturating_mul(changes) +
+				T::DbWeight::get().reads_writes(2, 2).saturating_mul(pruned)
 		}
 	}
 
@@ -426,6 +519,12 @@ pub mod pallet {
 			BalanceOf<Self>,
 			CurrencyIdOf<Self>,
 		>;
+		/// Reserves used by liquidity activated for 3rdparty rewards
+		type ThirdPartyActivationReservesProvider: ThirdPartyActivationReservesProviderTrait<
+			Self::AccountId,
+			BalanceOf<Self>,
+			CurrencyIdOf<Self>,
+		>;
 		type NativeCurrencyId: Get<CurrencyIdOf<Self>>;
 		type Currency: MultiTokenCurrencyExtended<Self::AccountId>
 			+ MultiTokenReservableCurrency<Self::AccountId>;
@@ -436,10 +535,26 @@ pub mod pallet {
 		type RewardsDistributionPeriod: Get<u32>;
 		/// The maximum number of schedules that can be active at one moment
 		type RewardsSchedulesLimit: Get<u32>;
+		/// The maximum number of distinct tokens that a single pool can be rewarded with
+		#[pallet::constant]
+		type MaxRewardTokensPerPool: Get<u32>;
+		/// The maximum number of schedules that can start or finish in a single session, bounds
+		/// the work done at the beginning of the session
+		#[pallet::constant]
+		type MaxSchedulesChangesPerSession: Get<u32>;
+		/// The maximum number of pools that a single account can have liquidity activated for
+		/// native rewards in
+		#[pallet::constant]
+		type MaxActivatedPoolsPerUser: Get<u32>;
 		/// The minimum number of rewards per session for schedule rewards
 		type Min3rdPartyRewardValutationPerSession: Get<u128>;
 		type Min3rdPartyRewardVolume: Get<u128>;
-		type SchedulesPerBlock: Get<u32>;
+		/// Number of the most recent sessions that rewards history and claim receipts are kept for
+		#[pallet::constant]
+		type RewardsHistoryLength: Get<u32>;
+		/// The maximum number of claim receipts stored per account
+		#[pallet::constant]
+		type MaxClaimReceipts: Get<u32>;
 
 		type WeightInfo: WeightInfo;
 		type ValuationApi: ValutationApiTrait<Self>;
@@ -480,6 +595,23 @@ pub mod pallet {
 		NoThirdPartyPartyRewardsToClaim,
 		// cannot promote solo token
 		SoloTokenPromotionForbiddenError,
+		/// Sender is not allowed to claim rewards on behalf of given account
+		NotARewardsClaimer,
+		/// Account has no activated liquidity to transfer
+		NoActivatedLiquidityToTransfer,
+		/// Destination account already has liquidity activated (or rewards to claim) for given
+		/// liquidity token
+		DestinationAlreadyActivated,
+		/// Activated liquidity is backed by staked reserves
+		ActivatedLiquidityNotTransferable,
+		/// Pool is already rewarded with `MaxRewardTokensPerPool` distinct tokens
+		TooManyRewardTokens,
+		/// Too many schedules start or finish in the requested session
+		TooManySchedulesChanges,
+		/// None of the provided reward tokens has liquidity activated for it
+		NoLiquidityToDeactivate,
+		/// Account has liquidity activated in `MaxActivatedPoolsPerUser` pools already
+		TooManyActivatedPools,
 	}
 
 	#[pallet::event]
@@ -488,8 +620,14 @@ pub mod pallet {
 		PoolPromotionUpdated(CurrencyIdOf<T>, Option<u8>),
 		LiquidityActivated(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
 		LiquidityDeactivated(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
-		RewardsClaimed(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
-		ThirdPartyRewardsClaimed(T::AccountId, CurrencyIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>),
+		RewardsClaimed(T::AccountId, CurrencyIdOf<T>, BalanceOf<T>, T::AccountId),
+		ThirdPartyRewardsClaimed(
+			T::AccountId,
+			CurrencyIdOf<T>,
+			CurrencyIdOf<T>,
+			BalanceOf<T>,
+			T::AccountId,
+		),
 		ThirdPartyLiquidityActivated(T::AccountId, CurrencyIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>),
 		ThirdPartyLiquidityDeactivated(
 			T::AccountId,
@@ -503,6 +641,10 @@ pub mod pallet {
 			CurrencyIdOf<T>,
 			BalanceOf<T>,
 		),
+		RewardsDestinationSet(T::AccountId, Option<T::AccountId>),
+		RewardsClaimerSet(T::AccountId, Option<T::AccountId>),
+		ActivatedLiquidityTransferred(T::AccountId, T::AccountId, CurrencyIdOf<T>, BalanceOf<T>),
+		ClaimReceiptsSet(T::AccountId, bool),
 	}
 
 	#[pallet::storage]
@@ -517,6 +659,16 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Pools that account has liquidity activated for native rewards in
+	#[pallet::storage]
+	pub type ActivatedPools<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AccountIdOf<T>,
+		BoundedVec<CurrencyIdOf<T>, T::MaxActivatedPoolsPerUser>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	/// Stores information about pool weight and accumulated rewards. The accumulated
 	/// rewards amount is the number of rewards that can be claimed per liquidity
@@ -585,13 +737,55 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Schedules that are not yet finished, grouped by liquidity token
+	#[pallet::storage]
+	pub type ActiveSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		Twox64Concat,
+		ScheduleId,
+		Schedule<T>,
+		OptionQuery,
+	>;
+
+	/// Number of schedules stored in [`ActiveSchedules`], limited by `RewardsSchedulesLimit`
 	#[pallet::storage]
-	#[pallet::getter(fn list_metadata)]
-	pub type SchedulesListMetadata<T: Config> = StorageValue<_, SchedulesList, ValueQuery>;
+	pub type ActiveSchedulesCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
-	pub type RewardsSchedulesList<T: Config> =
-		StorageMap<_, Twox64Concat, ScheduleId, (Schedule<T>, Option<ScheduleId>), OptionQuery>;
+	pub type NextScheduleId<T: Config> = StorageValue<_, ScheduleId, ValueQuery>;
+
+	/// Schedules (together with their liquidity token) that start or stop distributing rewards
+	/// in given session. Every schedule is put there twice - for the first session it is
+	/// rewarded in and for the first session after it finishes.
+	#[pallet::storage]
+	pub type SchedulesChanges<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionId,
+		Twox64Concat,
+		ScheduleId,
+		CurrencyIdOf<T>,
+		OptionQuery,
+	>;
+
+	/// Number of schedules stored in [`SchedulesChanges`] for given session, limited by
+	/// `MaxSchedulesChangesPerSession`
+	#[pallet::storage]
+	pub type SchedulesChangesCount<T: Config> =
+		StorageMap<_, Twox64Concat, SessionId, u32, ValueQuery>;
+
+	/// Sum of rewards distributed per session by all active schedules of given (liquidity token,
+	/// reward token) pair
+	#[pallet::storage]
+	pub type ScheduleRewardsRate<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(CurrencyIdOf<T>, CurrencyIdOf<T>),
+		ScheduleRewardsPerSession<BalanceOf<T>>,
+		ValueQuery,
+	>;
 
 	/// Maps liquidity token to list of tokens that it ever was rewarded with
 	#[pallet::storage]
@@ -649,6 +843,60 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Account that claimed rewards are transferred to, if not set rewards are transferred to the
+	/// owner of activated liquidity
+	#[pallet::storage]
+	pub type RewardsDestination<T: Config> =
+		StorageMap<_, Twox64Concat, AccountIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+	/// Account that is allowed to claim rewards on behalf of the owner of activated liquidity
+	#[pallet::storage]
+	pub type RewardsClaimer<T: Config> =
+		StorageMap<_, Twox64Concat, AccountIdOf<T>, AccountIdOf<T>, OptionQuery>;
+
+	/// Amount of native rewards distributed between all promoted pools in the most recent session
+	#[pallet::storage]
+	pub type LiquidityMiningRewardsPerSession<T: Config> =
+		StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Amount of native rewards distributed to particular pool in given session. Kept only for
+	/// the last `RewardsHistoryLength` sessions
+	#[pallet::storage]
+	pub type NativeRewardsHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionId,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// Amount of 3rdparty rewards distributed per session for (liquidity token, reward token)
+	/// pair starting from given session. Only sessions that amount changes in are stored, the
+	/// oldest kept session stores amounts of all the pairs that were rewarded at that time.
+	#[pallet::storage]
+	pub type ScheduleRewardsHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionId,
+		Twox64Concat,
+		(CurrencyIdOf<T>, CurrencyIdOf<T>),
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// Receipts of rewards claimed for activations of accounts that opted in using
+	/// [`Pallet::set_claim_receipts`]
+	#[pallet::storage]
+	pub type ClaimReceipts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AccountIdOf<T>,
+		BoundedVec<ClaimReceipt<CurrencyIdOf<T>, BalanceOf<T>>, T::MaxClaimReceipts>,
+		OptionQuery,
+	>;
+
 	/// Tracks how much of the liquidity was activated for schedule rewards and not yet
 	/// liquidity mining rewards. That information is essential to properly handle token unlcocks
 	/// when liquidity is deactivated.
@@ -676,10 +924,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			<Self as ProofOfStakeRewardsApi<_, _, _>>::claim_rewards_all(
-				sender,
-				liquidity_token_id,
-			)?;
+			Self::claim_native_rewards_impl(sender, liquidity_token_id)?;
 
 			Ok(())
 		}
@@ -922,11 +1167,177 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			<Self as ProofOfStakeRewardsApi<_, _, _>>::claim_rewards_all(
+			Self::claim_native_rewards_impl(sender, liquidity_token_id)?;
+
+			Ok(())
+		}
+
+		/// Decreases number of tokens used for 3rdparty rewards for every of the provided reward
+		/// tokens at once. If less than `amount` was activated for particular reward token, all of
+		/// it is deactivated, reward tokens without activated liquidity are skipped. Liquidity that
+		/// is no longer used by any of the schedules is unlocked immediately, so there is no need
+		/// to deactivate all of it to unlock only a part. Fails (and charges fees) if nothing was
+		/// deactivated.
+		///
+		/// Parameters:
+		/// - liquidity_token_id - id of the token
+		/// - amount - amount of the token
+		/// - reward_tokens - list of reward tokens that liquidity should be deactivated from
+		#[transactional]
+		#[pallet::call_index(11)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::deactivate_liquidity_for_3rdparty_rewards().saturating_mul(reward_tokens.len() as u64))]
+		pub fn batch_deactivate_liquidity_for_3rdparty_rewards(
+			origin: OriginFor<T>,
+			liquidity_token_id: CurrencyIdOf<T>,
+			amount: BalanceOf<T>,
+			reward_tokens: BoundedVec<CurrencyIdOf<T>, T::MaxRewardTokensPerPool>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let weight = <<T as Config>::WeightInfo>::deactivate_liquidity_for_3rdparty_rewards()
+				.saturating_mul(reward_tokens.len() as u64);
+
+			Self::batch_deactivate_liquidity_for_3rdparty_rewards_impl(
 				sender,
 				liquidity_token_id,
-			)?;
+				amount,
+				reward_tokens.into_inner(),
+			)
+			.map_err(|err| DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo { actual_weight: Some(weight), pays_fee: Pays::Yes },
+				error: err,
+			})?;
+			Ok(Pays::No.into())
+		}
 
+		/// Sets account that all rewards claimed for sender's activations are transferred to.
+		///
+		/// Parameters:
+		/// - destination - beneficiary of claimed rewards, `None` restores default behaviour
+		/// (rewards are transferred to the sender)
+		#[pallet::call_index(12)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::set_rewards_destination())]
+		pub fn set_rewards_destination(
+			origin: OriginFor<T>,
+			destination: Option<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			RewardsDestination::<T>::set(sender.clone(), destination.clone());
+			Self::deposit_event(Event::RewardsDestinationSet(sender, destination));
+			Ok(())
+		}
+
+		/// Sets account that is allowed to claim rewards on behalf of the sender.
+		///
+		/// Parameters:
+		/// - claimer - designated claimer, `None` revokes permissions of the current one
+		#[pallet::call_index(13)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::set_rewards_claimer())]
+		pub fn set_rewards_claimer(
+			origin: OriginFor<T>,
+			claimer: Option<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			RewardsClaimer::<T>::set(sender.clone(), claimer.clone());
+			Self::deposit_event(Event::RewardsClaimerSet(sender, claimer));
+			Ok(())
+		}
+
+		/// Claims liquidity mining rewards on behalf of `owner`, sender needs to be designated
+		/// as owner's rewards claimer. Rewards are transferred to owner's rewards destination.
+		#[transactional]
+		#[pallet::call_index(14)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_native_rewards().saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn claim_native_rewards_for(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			liquidity_token_id: CurrencyIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_rewards_claimer(&owner, &sender)?;
+
+			Self::claim_native_rewards_impl(owner, liquidity_token_id)?;
+
+			Ok(())
+		}
+
+		/// Claims 3rdparty rewards on behalf of `owner`, sender needs to be designated as
+		/// owner's rewards claimer. Rewards are transferred to owner's rewards destination.
+		/// - liquidity_token_id - id of the rewarded liquidity token
+		/// - reward_token - id of the token that is rewarded
+		#[transactional]
+		#[pallet::call_index(15)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::claim_3rdparty_rewards().saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn claim_3rdparty_rewards_for(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			liquidity_token_id: CurrencyIdOf<T>,
+			reward_token: CurrencyIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_rewards_claimer(&owner, &sender)?;
+
+			ScheduleRewardsCalculator::<T>::update_cumulative_rewards(
+				liquidity_token_id,
+				reward_token,
+			);
+			Self::claim_schedule_rewards_all_impl(owner, liquidity_token_id, reward_token)
+				.map_err(|err| DispatchErrorWithPostInfo {
+					post_info: PostDispatchInfo {
+						actual_weight: Some(<<T as Config>::WeightInfo>::claim_3rdparty_rewards()),
+						pays_fee: Pays::Yes,
+					},
+					error: err,
+				})?;
+			Ok(Pays::No.into())
+		}
+
+		/// Moves whole activated position (native and 3rdparty activations together with rewards
+		/// that were not yet claimed) of given liquidity token to another account. Position is
+		/// moved without loosing progress on rewards curve.
+		///
+		/// Parameters:
+		/// - liquidity_token_id - id of the liquidity token
+		/// - destination - account that position is transferred to, it can not have any
+		/// activations of `liquidity_token_id`
+		///
+		/// Reserves backing the position are moved together with relocks holding them back,
+		/// positions activated from staked reserves can not be transferred as the stake stays
+		/// bonded by the sender.
+		#[transactional]
+		#[pallet::call_index(16)]
+		#[pallet::weight(<<T as Config>::WeightInfo>::deactivate_liquidity_for_native_rewards()
+			.saturating_add(<<T as Config>::WeightInfo>::activate_liquidity_for_native_rewards())
+			.saturating_add(T::DbWeight::get().reads_writes(4, 6))
+			.saturating_add(T::DbWeight::get().reads_writes(5, 4).saturating_mul(T::MaxRewardTokensPerPool::get().into())))]
+		pub fn transfer_activated_liquidity(
+			origin: OriginFor<T>,
+			liquidity_token_id: CurrencyIdOf<T>,
+			destination: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::transfer_activated_liquidity_impl(sender, destination, liquidity_token_id)
+		}
+
+		/// Enables/disables storing receipts of rewards claimed for sender's activations (see
+		/// [`ClaimReceipts`]). Only receipts from the last `RewardsHistoryLength` sessions are
+		/// kept, disabling removes all of them.
+		///
+		/// Parameters:
+		/// - enabled - whether receipts should be stored
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_claim_receipts(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			if !enabled {
+				ClaimReceipts::<T>::remove(&sender);
+			} else if !ClaimReceipts::<T>::contains_key(&sender) {
+				ClaimReceipts::<T>::insert(&sender, BoundedVec::default());
+			}
+			Self::deposit_event(Event::ClaimReceiptsSet(sender, enabled));
 			Ok(())
 		}
 	}
@@ -1017,7 +1428,7 @@ impl<T: Config> Pallet<T> {
 			// 1R 1W
 			ThirdPartyActivationKind::ActivateKind(ref use_balance_from) => {
 				ensure!(
-					<T as Config>::ActivationReservesProvider::can_activate(
+					<T as Config>::ThirdPartyActivationReservesProvider::can_activate_for_3rdparty_rewards(
 						liquidity_asset_id,
 						&user,
 						amount,
@@ -1075,7 +1486,7 @@ impl<T: Config> Pallet<T> {
 
 		match use_balance_from {
 			ThirdPartyActivationKind::ActivateKind(use_balance_from) => {
-				<T as Config>::ActivationReservesProvider::activate(
+				<T as Config>::ThirdPartyActivationReservesProvider::activate_for_3rdparty_rewards(
 					liquidity_asset_id,
 					&user,
 					amount,
@@ -1118,6 +1529,327 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn batch_deactivate_liquidity_for_3rdparty_rewards_impl(
+		user: AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+		amount: BalanceOf<T>,
+		mut reward_tokens: Vec<CurrencyIdOf<T>>,
+	) -> DispatchResult {
+		reward_tokens.sort();
+		reward_tokens.dedup();
+
+		let mut deactivated = 0u32;
+		for reward_token in reward_tokens {
+			let to_deactivate = match ActivatedLiquidityForSchedules::<T>::get((
+				user.clone(),
+				liquidity_asset_id,
+				reward_token,
+			)) {
+				Some(activated) => amount.min(activated),
+				None => continue,
+			};
+			if to_deactivate.is_zero() {
+				continue
+			}
+
+			Self::deactivate_liquidity_for_3rdparty_rewards_impl(
+				user.clone(),
+				liquidity_asset_id,
+				to_deactivate,
+				reward_token,
+			)?;
+			deactivated += 1;
+		}
+
+		ensure!(deactivated > 0, Error::<T>::NoLiquidityToDeactivate);
+		Ok(())
+	}
+
+	/// Returns list of schedules that are still active for particular liquidity token in form of
+	/// (reward token, amount per session, number of sessions left after the current one)
+	pub fn get_rewards_schedules(
+		liquidity_asset_id: CurrencyIdOf<T>,
+	) -> Vec<(CurrencyIdOf<T>, BalanceOf<T>, SessionId)> {
+		let session_id = Self::session_index();
+		let mut schedules = ActiveSchedules::<T>::iter_prefix(liquidity_asset_id)
+			.filter(|(_, schedule)| schedule.last_session >= session_id)
+			.collect::<Vec<_>>();
+		schedules.sort_by_key(|(id, _)| *id);
+
+		schedules
+			.into_iter()
+			.map(|(_, schedule)| {
+				(
+					schedule.reward_token,
+					schedule.amount_per_session,
+					schedule.last_session.saturating_sub(session_id.max(schedule.scheduled_at)),
+				)
+			})
+			.collect()
+	}
+
+	/// Returns rewards distributed to particular liquidity token in every session from the given
+	/// range. Only the last `RewardsHistoryLength` sessions are available.
+	pub fn get_rewards_history(
+		liquidity_asset_id: CurrencyIdOf<T>,
+		from_session: SessionId,
+		to_session: SessionId,
+	) -> Vec<SessionRewards<CurrencyIdOf<T>, BalanceOf<T>>> {
+		let oldest_session = Self::oldest_history_session();
+		let from_session = from_session.max(oldest_session);
+		let to_session = to_session.min(Self::session_index());
+
+		// amount is only stored in sessions when it changed, so the last known amount of every
+		// reward token is carried forward starting from the oldest session
+		let mut amounts = RewardTokensPerPool::<T>::iter_key_prefix(liquidity_asset_id)
+			.map(|reward_token| (reward_token, BalanceOf::<T>::zero()))
+			.collect::<BTreeMap<_, _>>();
+
+		let mut history = Vec::new();
+		for session in oldest_session..=to_session {
+			for (reward_token, amount) in amounts.iter_mut() {
+				if let Some(changed) =
+					ScheduleRewardsHistory::<T>::get(session, (liquidity_asset_id, *reward_token))
+				{
+					*amount = changed;
+				}
+			}
+
+			if session < from_session {
+				continue
+			}
+
+			history.push(SessionRewards {
+				session,
+				native_rewards: NativeRewardsHistory::<T>::get(session, liquidity_asset_id)
+					.unwrap_or_default(),
+				third_party_rewards: amounts
+					.iter()
+					.filter(|(_, amount)| !amount.is_zero())
+					.map(|(reward_token, amount)| (*reward_token, *amount))
+					.collect(),
+			});
+		}
+		history
+	}
+
+	/// Returns receipts of rewards claimed for activations of particular user, empty if user did
+	/// not opt in for storing them
+	pub fn get_claim_receipts(
+		user: AccountIdOf<T>,
+	) -> Vec<ClaimReceipt<CurrencyIdOf<T>, BalanceOf<T>>> {
+		let oldest_session = Self::oldest_history_session();
+		ClaimReceipts::<T>::get(user)
+			.map(|receipts| receipts.into_inner())
+			.unwrap_or_default()
+			.into_iter()
+			.filter(|receipt| receipt.session >= oldest_session)
+			.collect()
+	}
+
+	/// Returns all the activations (native and 3rdparty) of particular user with rewards that are
+	/// available for claiming, grouped by liquidity token
+	pub fn get_user_activations(
+		user: AccountIdOf<T>,
+	) -> Vec<UserActivations<CurrencyIdOf<T>, BalanceOf<T>>> {
+		let mut result: BTreeMap<CurrencyIdOf<T>, UserActivations<CurrencyIdOf<T>, BalanceOf<T>>> =
+			BTreeMap::new();
+		let empty = |liquidity_token| UserActivations {
+			liquidity_token,
+			native_activated: BalanceOf::<T>::zero(),
+			native_reactivated: BalanceOf::<T>::zero(),
+			native_claimable: BalanceOf::<T>::zero(),
+			third_party: Vec::new(),
+		};
+
+		for (liq_token, info) in RewardsInfo::<T>::iter_prefix(user.clone()) {
+			let claimable =
+				Self::calculate_native_rewards_amount(user.clone(), liq_token).unwrap_or_default();
+			if info.activated_amount.is_zero() && claimable.is_zero() {
+				continue
+			}
+			let entry = result.entry(liq_token).or_insert_with(|| empty(liq_token));
+			entry.native_activated = info.activated_amount;
+			entry.native_reactivated = ActivatedNativeRewardsLiq::<T>::get(user.clone(), liq_token);
+			entry.native_claimable = claimable;
+		}
+
+		for ((liq_token, reward_token), info) in
+			RewardsInfoForScheduleRewards::<T>::iter_prefix(user.clone())
+		{
+			let claimable =
+				Self::calculate_3rdparty_rewards_amount(user.clone(), liq_token, reward_token)
+					.unwrap_or_default();
+			if info.activated_amount.is_zero() && claimable.is_zero() {
+				continue
+			}
+			result.entry(liq_token).or_insert_with(|| empty(liq_token)).third_party.push((
+				reward_token,
+				info.activated_amount,
+				claimable,
+			));
+		}
+
+		result
+			.into_values()
+			.map(|mut activations| {
+				activations.third_party.sort();
+				activations
+			})
+			.collect()
+	}
+
+	/// Returns amount of native rewards that particular pool receives per session
+	pub fn get_native_rewards_per_session(liquidity_asset_id: CurrencyIdOf<T>) -> BalanceOf<T> {
+		let promoted_pools = PromotedPoolRewards::<T>::get();
+		let weight = match promoted_pools.get(&liquidity_asset_id) {
+			Some(info) if info.weight > 0 => info.weight,
+			_ => return BalanceOf::<T>::zero(),
+		};
+
+		// rewards are distributed only between pools that have some liquidity activated, assume
+		// that requested pool is one of them
+		let total_weight: u64 = promoted_pools
+			.iter()
+			.filter(|(token_id, info)| {
+				info.weight > 0 &&
+					(**token_id == liquidity_asset_id ||
+						Self::total_activated_amount(**token_id) > BalanceOf::<T>::zero())
+			})
+			.map(|(_, info)| u64::from(info.weight))
+			.sum();
+
+		Perbill::from_rational(weight.into(), total_weight)
+			.mul_floor(LiquidityMiningRewardsPerSession::<T>::get())
+	}
+
+	/// Returns rewards provided to particular pool valuated in native token and estimates how
+	/// much rewards `amount` of liquidity activated (for native and all 3rdparty rewards) right now
+	/// would receive during a year. Native rewards estimation takes into account the asymptotic
+	/// curve that rewards for liquidity mining are based on.
+	pub fn estimate_rewards(
+		liquidity_asset_id: CurrencyIdOf<T>,
+		amount: BalanceOf<T>,
+		sessions_per_year: u32,
+	) -> RewardsEstimation<CurrencyIdOf<T>, BalanceOf<T>> {
+		let native_rewards_per_session = Self::get_native_rewards_per_session(liquidity_asset_id);
+		let mut estimated_yearly_rewards = BalanceOf::<T>::zero();
+
+		if !native_rewards_per_session.is_zero() && !amount.is_zero() {
+			let total_activated =
+				Self::total_activated_amount(liquidity_asset_id).saturating_add(amount);
+			let pool_ratio = U256::from(native_rewards_per_session.into())
+				.saturating_mul(U256::from(sessions_per_year))
+				.saturating_mul(U256::from(u128::MAX))
+				.checked_div(U256::from(total_activated.into()))
+				.unwrap_or_default();
+			let native_rewards =
+				RewardsCalculator::<AsymptoticCurveRewards<BalanceOf<T>>, _>::estimate_rewards(
+					amount,
+					sessions_per_year,
+					pool_ratio,
+				)
+				.unwrap_or_default();
+			estimated_yearly_rewards = estimated_yearly_rewards.saturating_add(native_rewards);
+		}
+
+		let third_party_rewards_per_session = Self::get_rewards_schedules(liquidity_asset_id)
+			.into_iter()
+			.map(|(reward_token, amount_per_session, remaining_sessions)| {
+				let valuation = Self::valuate_in_native_token(reward_token, amount_per_session);
+
+				if !amount.is_zero() {
+					let total_activated =
+						ScheduleRewardsCalculator::<T>::total_activated_liquidity(
+							liquidity_asset_id,
+							reward_token,
+						)
+						.saturating_add(amount);
+					let sessions = remaining_sessions.min(sessions_per_year);
+					let pool_ratio = U256::from(valuation.into())
+						.saturating_mul(U256::from(sessions))
+						.saturating_mul(U256::from(u128::MAX))
+						.checked_div(U256::from(total_activated.into()))
+						.unwrap_or_default();
+					let rewards =
+						RewardsCalculator::<ConstCurveRewards<BalanceOf<T>>, _>::estimate_rewards(
+							amount, sessions, pool_ratio,
+						)
+						.unwrap_or_default();
+					estimated_yearly_rewards = estimated_yearly_rewards.saturating_add(rewards);
+				}
+
+				(reward_token, amount_per_session, valuation)
+			})
+			.collect();
+
+		let activation_valuation = Self::valuate_in_native_token(liquidity_asset_id, amount);
+		let estimated_apr_bps = if activation_valuation.is_zero() {
+			0u128
+		} else {
+			U256::from(estimated_yearly_rewards.into())
+				.saturating_mul(U256::from(10_000u128))
+				.checked_div(U256::from(activation_valuation.into()))
+				.and_then(|v| TryInto::<u128>::try_into(v).ok())
+				.unwrap_or(u128::MAX)
+		};
+
+		RewardsEstimation {
+			native_rewards_per_session,
+			third_party_rewards_per_session,
+			activation_valuation,
+			estimated_yearly_rewards,
+			estimated_apr_bps,
+		}
+	}
+
+	/// Returns value of all native rewards activations of particular user valuated in native token
+	pub fn get_activated_liquidity_value(user: &AccountIdOf<T>) -> BalanceOf<T> {
+		ActivatedPools::<T>::get(user).into_iter().fold(
+			BalanceOf::<T>::zero(),
+			|total, liq_token| {
+				let activated = RewardsInfo::<T>::get(user.clone(), liq_token).activated_amount;
+				total.saturating_add(Self::valuate_in_native_token(liq_token, activated))
+			},
+		)
+	}
+
+	fn add_activated_pool(
+		user: &AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+	) -> DispatchResult {
+		ActivatedPools::<T>::try_mutate(user, |pools| {
+			if !pools.contains(&liquidity_asset_id) {
+				pools
+					.try_push(liquidity_asset_id)
+					.map_err(|_| Error::<T>::TooManyActivatedPools)?;
+			}
+			Ok(())
+		})
+	}
+
+	fn remove_activated_pool(user: &AccountIdOf<T>, liquidity_asset_id: CurrencyIdOf<T>) {
+		ActivatedPools::<T>::mutate_exists(user, |maybe_pools| {
+			let is_empty = maybe_pools.as_mut().map_or(true, |pools| {
+				pools.retain(|id| *id != liquidity_asset_id);
+				pools.is_empty()
+			});
+			if is_empty {
+				*maybe_pools = None;
+			}
+		});
+	}
+
+	fn valuate_in_native_token(token_id: CurrencyIdOf<T>, amount: BalanceOf<T>) -> BalanceOf<T> {
+		if token_id == Self::native_token_id() {
+			amount
+		} else if <T as Config>::ValuationApi::is_liquidity_token(token_id) {
+			<T as Config>::ValuationApi::valuate_liquidity_token(token_id, amount)
+		} else {
+			<T as Config>::ValuationApi::valuate_non_liquidity_token(token_id, amount)
+		}
+	}
+
 	pub fn calculate_3rdparty_rewards_all(
 		user: AccountIdOf<T>,
 	) -> Vec<(CurrencyIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>)> {
@@ -1231,6 +1963,9 @@ impl<T: Config> Pallet<T> {
 				.activate_more(liquidity_assets_added)
 				.map_err(|err| Into::<Error<T>>::into(err))?;
 
+			if !rewards_info.activated_amount.is_zero() {
+				Self::add_activated_pool(&user, liquidity_asset_id)?;
+			}
 			RewardsInfo::<T>::insert(user.clone(), liquidity_asset_id, rewards_info);
 		}
 
@@ -1328,6 +2063,9 @@ impl<T: Config> Pallet<T> {
 			.activate_less(liquidity_assets_burned)
 			.map_err(|err| Into::<Error<T>>::into(err))?;
 
+		if rewards_info.activated_amount.is_zero() {
+			Self::remove_activated_pool(&user, liquidity_asset_id);
+		}
 		RewardsInfo::<T>::insert(user.clone(), liquidity_asset_id, rewards_info);
 
 		TotalActivatedLiquidity::<T>::try_mutate(liquidity_asset_id, |active_amount| {
@@ -1398,39 +2136,293 @@ impl<T: Config> Pallet<T> {
 			},
 		)?;
 
-		if let None = ActivatedLiquidityForSchedules::<T>::iter_prefix_values((
+		Self::release_unused_3rdparty_liquidity(user, liquidity_asset_id);
+
+		Ok(())
+	}
+
+	/// Liquidity activated for 3rdparty rewards can be shared between multiple reward tokens, so
+	/// the amount that has to stay locked equals to the biggest activation among all reward
+	/// tokens of particular liquidity token. Whatever exceeds that amount is released - first
+	/// the part that was locked exclusively for 3rdparty rewards (so it becomes transferable)
+	/// and then the part that was reused from native rewards liquidity.
+	fn release_unused_3rdparty_liquidity(
+		user: AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+	) {
+		let required = ActivatedLiquidityForSchedules::<T>::iter_prefix_values((
 			user.clone(),
 			liquidity_asset_id,
 		))
-		.next()
-		{
-			let amount = ActivatedLockedLiquidityForSchedules::<T>::mutate(
+		.max()
+		.unwrap_or_default();
+
+		let native_reused = ActivatedNativeRewardsLiq::<T>::get(user.clone(), liquidity_asset_id);
+		let native_reused_new = native_reused.min(required);
+
+		let locked =
+			ActivatedLockedLiquidityForSchedules::<T>::get(user.clone(), liquidity_asset_id);
+		let locked_new = locked.min(required.saturating_sub(native_reused_new));
+
+		if native_reused_new != native_reused {
+			ActivatedNativeRewardsLiq::<T>::insert(
 				user.clone(),
 				liquidity_asset_id,
-				|val| {
-					let prev = *val;
-					*val = BalanceOf::<T>::zero();
-					prev
-				},
+				native_reused_new,
 			);
+		}
 
-			<T as Config>::ActivationReservesProvider::deactivate(
+		if locked_new != locked {
+			ActivatedLockedLiquidityForSchedules::<T>::insert(
+				user.clone(),
+				liquidity_asset_id,
+				locked_new,
+			);
+			<T as Config>::ThirdPartyActivationReservesProvider::deactivate_from_3rdparty_rewards(
 				liquidity_asset_id,
 				&user,
-				amount,
+				locked.saturating_sub(locked_new),
 			);
+		}
+	}
 
-			let _ =
-				ActivatedNativeRewardsLiq::<T>::mutate(user.clone(), liquidity_asset_id, |val| {
-					let prev = *val;
-					*val = BalanceOf::<T>::zero();
-					prev
-				});
+	fn has_activated_liquidity(user: &AccountIdOf<T>, liquidity_asset_id: CurrencyIdOf<T>) -> bool {
+		!RewardsInfo::<T>::get(user.clone(), liquidity_asset_id)
+			.activated_amount
+			.is_zero() ||
+			ActivatedLiquidityForSchedules::<T>::iter_prefix((user.clone(), liquidity_asset_id))
+				.next()
+				.is_some()
+	}
+
+	fn has_unclaimed_rewards(user: &AccountIdOf<T>, liquidity_asset_id: CurrencyIdOf<T>) -> bool {
+		!Self::calculate_native_rewards_amount(user.clone(), liquidity_asset_id)
+			.unwrap_or_default()
+			.is_zero() ||
+			RewardTokensPerPool::<T>::iter_key_prefix(liquidity_asset_id).any(|reward_token| {
+				!Self::calculate_3rdparty_rewards_amount(
+					user.clone(),
+					liquidity_asset_id,
+					reward_token,
+				)
+				.unwrap_or_default()
+				.is_zero()
+			})
+	}
+
+	fn transfer_activated_liquidity_impl(
+		from: AccountIdOf<T>,
+		to: AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			Self::has_activated_liquidity(&from, liquidity_asset_id),
+			Error::<T>::NoActivatedLiquidityToTransfer
+		);
+		ensure!(
+			from != to &&
+				!Self::has_activated_liquidity(&to, liquidity_asset_id) &&
+				!Self::has_unclaimed_rewards(&to, liquidity_asset_id),
+			Error::<T>::DestinationAlreadyActivated
+		);
+
+		let reward_tokens = RewardTokensPerPool::<T>::iter_key_prefix(liquidity_asset_id)
+			.filter(|reward_token| {
+				RewardsInfoForScheduleRewards::<T>::contains_key(
+					from.clone(),
+					(liquidity_asset_id, *reward_token),
+				)
+			})
+			.collect::<Vec<_>>();
+
+		// liquidity reserved on behalf of the position, reactivated native liquidity is already
+		// included in native activation
+		let native_reserved =
+			RewardsInfo::<T>::get(from.clone(), liquidity_asset_id).activated_amount;
+		let locked_reserved =
+			ActivatedLockedLiquidityForSchedules::<T>::get(from.clone(), liquidity_asset_id);
+		let reserved = native_reserved.saturating_add(locked_reserved);
+
+		if !reserved.is_zero() {
+			<T as Config>::ThirdPartyActivationReservesProvider::transfer_activated_reserves(
+				liquidity_asset_id,
+				&from,
+				&to,
+				native_reserved,
+				locked_reserved,
+			)
+			.map_err(|_| Error::<T>::ActivatedLiquidityNotTransferable)?;
+		}
+
+		if RewardsInfo::<T>::contains_key(from.clone(), liquidity_asset_id) {
+			RewardsInfo::<T>::insert(
+				to.clone(),
+				liquidity_asset_id,
+				RewardsInfo::<T>::take(from.clone(), liquidity_asset_id),
+			);
+		}
+		if !native_reserved.is_zero() {
+			Self::remove_activated_pool(&from, liquidity_asset_id);
+			Self::add_activated_pool(&to, liquidity_asset_id)?;
+		}
+
+		for reward_token in reward_tokens {
+			RewardsInfoForScheduleRewards::<T>::insert(
+				to.clone(),
+				(liquidity_asset_id, reward_token),
+				RewardsInfoForScheduleRewards::<T>::take(
+					from.clone(),
+					(liquidity_asset_id, reward_token),
+				),
+			);
+			if let Some(activated) = ActivatedLiquidityForSchedules::<T>::take((
+				from.clone(),
+				liquidity_asset_id,
+				reward_token,
+			)) {
+				ActivatedLiquidityForSchedules::<T>::insert(
+					(to.clone(), liquidity_asset_id, reward_token),
+					activated,
+				);
+			}
+		}
+
+		let locked =
+			ActivatedLockedLiquidityForSchedules::<T>::take(from.clone(), liquidity_asset_id);
+		if !locked.is_zero() {
+			ActivatedLockedLiquidityForSchedules::<T>::insert(
+				to.clone(),
+				liquidity_asset_id,
+				locked,
+			);
+		}
+		let reactivated = ActivatedNativeRewardsLiq::<T>::take(from.clone(), liquidity_asset_id);
+		if !reactivated.is_zero() {
+			ActivatedNativeRewardsLiq::<T>::insert(to.clone(), liquidity_asset_id, reactivated);
 		}
 
+		Pallet::<T>::deposit_event(Event::ActivatedLiquidityTransferred(
+			from,
+			to,
+			liquidity_asset_id,
+			reserved,
+		));
+
 		Ok(())
 	}
 
+	/// Returns account that rewards claimed for `user` activations should be transferred to
+	pub fn rewards_destination(user: &AccountIdOf<T>) -> AccountIdOf<T> {
+		RewardsDestination::<T>::get(user).unwrap_or_else(|| user.clone())
+	}
+
+	/// First session that rewards history and claim receipts are still kept for
+	fn oldest_history_session() -> SessionId {
+		(Self::session_index() + 1).saturating_sub(T::RewardsHistoryLength::get().max(1))
+	}
+
+	/// Removes rewards history of the session that is not kept anymore. Amounts of 3rdparty
+	/// rewards that did not change in the next session are moved there, so the oldest kept
+	/// session always knows about all rewarded pairs. Returns number of removed entries.
+	fn prune_rewards_history(session_id: SessionId) -> u64 {
+		let history_length = T::RewardsHistoryLength::get().max(1);
+		if session_id < history_length {
+			return 0
+		}
+		let pruned_session = session_id - history_length;
+
+		let mut pruned =
+			NativeRewardsHistory::<T>::clear_prefix(pruned_session, u32::MAX, None).unique as u64;
+		for (pair, amount) in ScheduleRewardsHistory::<T>::drain_prefix(pruned_session) {
+			pruned += 1;
+			if !amount.is_zero() &&
+				!ScheduleRewardsHistory::<T>::contains_key(pruned_session + 1, pair)
+			{
+				ScheduleRewardsHistory::<T>::insert(pruned_session + 1, pair, amount);
+			}
+		}
+		pruned
+	}
+
+	fn store_claim_receipt(
+		user: &AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+		reward_token: CurrencyIdOf<T>,
+		amount: BalanceOf<T>,
+	) {
+		ClaimReceipts::<T>::mutate_exists(user, |maybe_receipts| {
+			if let Some(receipts) = maybe_receipts {
+				let oldest_session = Self::oldest_history_session();
+				receipts.retain(|receipt| receipt.session >= oldest_session);
+				if !receipts.is_empty() && receipts.len() >= T::MaxClaimReceipts::get() as usize {
+					receipts.remove(0);
+				}
+				let _ = receipts.try_push(ClaimReceipt {
+					session: Self::session_index(),
+					liquidity_token: liquidity_asset_id,
+					reward_token,
+					amount,
+				});
+			}
+		});
+	}
+
+	fn ensure_rewards_claimer(owner: &AccountIdOf<T>, claimer: &AccountIdOf<T>) -> DispatchResult {
+		ensure!(
+			RewardsClaimer::<T>::get(owner).as_ref() == Some(claimer),
+			Error::<T>::NotARewardsClaimer
+		);
+		Ok(())
+	}
+
+	fn claim_native_rewards_impl(
+		user: AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let beneficiary = Self::rewards_destination(&user);
+		Self::claim_native_rewards_to(user, liquidity_asset_id, beneficiary)
+	}
+
+	fn claim_native_rewards_to(
+		user: AccountIdOf<T>,
+		liquidity_asset_id: CurrencyIdOf<T>,
+		beneficiary: AccountIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::ensure_native_rewards_enabled(liquidity_asset_id)?;
+
+		let calc = RewardsCalculator::mining_rewards::<T>(user.clone(), liquidity_asset_id)?;
+		let (rewards_info, total_available_rewards) =
+			calc.claim_rewards().map_err(|err| Into::<Error<T>>::into(err))?;
+
+		<T as Config>::Currency::transfer(
+			Self::native_token_id().into(),
+			&<T as Config>::LiquidityMiningIssuanceVault::get(),
+			&beneficiary,
+			total_available_rewards,
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		RewardsInfo::<T>::insert(user.clone(), liquidity_asset_id, rewards_info);
+
+		if !total_available_rewards.is_zero() {
+			Self::store_claim_receipt(
+				&user,
+				liquidity_asset_id,
+				Self::native_token_id(),
+				total_available_rewards,
+			);
+		}
+
+		Pallet::<T>::deposit_event(Event::RewardsClaimed(
+			user,
+			liquidity_asset_id,
+			total_available_rewards,
+			beneficiary,
+		));
+
+		Ok(total_available_rewards)
+	}
+
 	fn claim_schedule_rewards_all_impl(
 		user: T::AccountId,
 		liquidity_asset_id: CurrencyIdOf<T>,
@@ -1451,10 +2443,11 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::NoThirdPartyPartyRewardsToClaim
 		);
 
+		let beneficiary = Self::rewards_destination(&user);
 		<T as Config>::Currency::transfer(
 			reward_token.into(),
 			&Self::pallet_account(),
-			&user,
+			&beneficiary,
 			total_available_rewards,
 			ExistenceRequirement::KeepAlive,
 		)?;
@@ -1465,11 +2458,14 @@ impl<T: Config> Pallet<T> {
 			rewards_info,
 		);
 
+		Self::store_claim_receipt(&user, liquidity_asset_id, reward_token, total_available_rewards);
+
 		Pallet::<T>::deposit_event(Event::ThirdPartyRewardsClaimed(
 			user,
 			liquidity_asset_id,
 			reward_token,
 			total_available_rewards,
+			beneficiary,
 		));
 
 		Ok(total_available_rewards)
@@ -1506,7 +2502,33 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(Self::verify_rewards_min_volume(token_id), Error::<T>::TooSmallVolume);
 
-		RewardTokensPerPool::<T>::insert(liquidity_token_id, token_id, ());
+		ensure!(
+			ActiveSchedulesCount::<T>::get() < T::RewardsSchedulesLimit::get(),
+			Error::<T>::TooManySchedules
+		);
+
+		// rewards are distributed starting from the next session up to (including)
+		// `schedule_end`
+		let start_session = current_session + 1;
+		let end_session = schedule_end.saturating_add(1);
+		ensure!(
+			SchedulesChangesCount::<T>::get(start_session) <
+				T::MaxSchedulesChangesPerSession::get() &&
+				SchedulesChangesCount::<T>::get(end_session) <
+					T::MaxSchedulesChangesPerSession::get(),
+			Error::<T>::TooManySchedulesChanges
+		);
+
+		if !RewardTokensPerPool::<T>::contains_key(liquidity_token_id, token_id) {
+			ensure!(
+				(RewardTokensPerPool::<T>::iter_key_prefix(liquidity_token_id)
+					.take(T::MaxRewardTokensPerPool::get() as usize)
+					.count() as u32) <
+					T::MaxRewardTokensPerPool::get(),
+				Error::<T>::TooManyRewardTokens
+			);
+			RewardTokensPerPool::<T>::insert(liquidity_token_id, token_id, ());
+		}
 
 		T::Currency::transfer(
 			token_id.into(),
@@ -1516,46 +2538,25 @@ impl<T: Config> Pallet<T> {
 			ExistenceRequirement::KeepAlive,
 		)?;
 
-		let head = SchedulesListMetadata::<T>::get().head;
-		let tail = SchedulesListMetadata::<T>::get().tail;
-
 		let schedule = Schedule {
-			scheduled_at: Self::session_index(),
+			scheduled_at: current_session,
 			last_session: schedule_end,
 			liq_token: liquidity_token_id,
 			reward_token: token_id,
 			amount_per_session,
 		};
 
-		match (head, tail) {
-			(None, None) => {
-				// first schedule
-				RewardsSchedulesList::<T>::insert(0, (schedule, None::<ScheduleId>));
-				SchedulesListMetadata::<T>::mutate(|s| {
-					s.head = Some(0);
-					s.tail = Some(0);
-					s.count = 1;
-				});
-			},
-			(Some(_head), Some(tail)) => {
-				RewardsSchedulesList::<T>::mutate(tail, |info| {
-					if let Some((_schedule, next)) = info.as_mut() {
-						*next = Some(tail + 1u64)
-					}
-				});
-				RewardsSchedulesList::<T>::insert(tail + 1, (schedule, None::<ScheduleId>));
-				SchedulesListMetadata::<T>::try_mutate(|s| {
-					if s.count < T::RewardsSchedulesLimit::get().into() {
-						s.tail = Some(tail + 1);
-						s.count += 1;
-						Ok(s.count)
-					} else {
-						Err(Error::<T>::TooManySchedules)
-					}
-				})?;
-			},
-			_ => {}, // invariant assures this will never happen
-		}
+		let schedule_id = NextScheduleId::<T>::mutate(|id| {
+			let schedule_id = *id;
+			*id = id.saturating_add(1);
+			schedule_id
+		});
+		SchedulesChanges::<T>::insert(start_session, schedule_id, liquidity_token_id);
+		SchedulesChangesCount::<T>::mutate(start_session, |count| *count += 1);
+		SchedulesChanges::<T>::insert(end_session, schedule_id, liquidity_token_id);
+		SchedulesChangesCount::<T>::mutate(end_session, |count| *count += 1);
+		ActiveSchedules::<T>::insert(liquidity_token_id, schedule_id, schedule);
+		ActiveSchedulesCount::<T>::mutate(|count| *count += 1);
 
 		Pallet::<T>::deposit_event(Event::ThirdPartySuccessfulPoolPromotion(
 			sender,
@@ -1672,33 +2673,13 @@ impl<T: Config> ProofOfStakeRewardsApi<T::AccountId, BalanceOf<T>, CurrencyIdOf<
 		PromotedPoolRewards::<T>::get().contains_key(&liquidity_token_id)
 	}
 
+	/// Claims native rewards to the `user` account regardless of configured rewards destination,
+	/// used for compounding rewards
 	fn claim_rewards_all(
 		user: T::AccountId,
 		liquidity_asset_id: CurrencyIdOf<T>,
 	) -> Result<BalanceOf<T>, DispatchError> {
-		Self::ensure_native_rewards_enabled(liquidity_asset_id)?;
-
-		let calc = RewardsCalculator::mining_rewards::<T>(user.clone(), liquidity_asset_id)?;
-		let (rewards_info, total_available_rewards) =
-			calc.claim_rewards().map_err(|err| Into::<Error<T>>::into(err))?;
-
-		<T as Config>::Currency::transfer(
-			Self::native_token_id().into(),
-			&<T as Config>::LiquidityMiningIssuanceVault::get(),
-			&user,
-			total_available_rewards,
-			ExistenceRequirement::KeepAlive,
-		)?;
-
-		RewardsInfo::<T>::insert(user.clone(), liquidity_asset_id, rewards_info);
-
-		Pallet::<T>::deposit_event(Event::RewardsClaimed(
-			user,
-			liquidity_asset_id,
-			total_available_rewards,
-		));
-
-		Ok(total_available_rewards)
+		Self::claim_native_rewards_to(user.clone(), liquidity_asset_id, user)
 	}
 
 	fn activate_liquidity(
@@ -1734,6 +2715,7 @@ impl<T: Config> ProofOfStakeRewardsApi<T::AccountId, BalanceOf<T>, CurrencyIdOf<
 impl<T: Config> LiquidityMiningApi<BalanceOf<T>> for Pallet<T> {
 	/// Distributs liquidity mining rewards between all the activated tokens based on their weight
 	fn distribute_rewards(liquidity_mining_rewards: BalanceOf<T>) {
+		LiquidityMiningRewardsPerSession::<T>::put(liquidity_mining_rewards);
 		let _ = PromotedPoolRewards::<T>::try_mutate(|promoted_pools| -> DispatchResult {
 			// benchmark with max of X prom pools
 			let activated_pools: Vec<_> = promoted_pools
@@ -1756,6 +2738,7 @@ impl<T: Config> LiquidityMiningApi<BalanceOf<T>> for Pallet<T> {
 				},
 			);
 
+			let mut distributed = Vec::new();
 			for (token_id, weight, rewards, activated_amount) in activated_pools {
 				let liquidity_mining_issuance_for_pool = match maybe_total_weight {
 					Some(total_weight) if !total_weight.is_zero() =>
@@ -1774,6 +2757,12 @@ impl<T: Config> LiquidityMiningApi<BalanceOf<T>> for Pallet<T> {
 				promoted_pools
 					.entry(token_id.clone())
 					.and_modify(|info| info.rewards = rewards_for_liquidity);
+				distributed.push((token_id, liquidity_mining_issuance_for_pool));
+			}
+
+			let session_id = Self::session_index();
+			for (token_id, amount) in distributed {
+				NativeRewardsHistory::<T>::insert(session_id, token_id, amount);
 			}
 			Ok(())
 		});
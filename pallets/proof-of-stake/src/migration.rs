@@ -0,0 +1,173 @@
+use super::*;
+use frame_support::{
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Cursor over linked list of schedules used before v1
+	#[derive(Encode, Decode, Default, TypeInfo)]
+	pub struct SchedulesList {
+		pub head: Option<ScheduleId>,
+		pub tail: Option<ScheduleId>,
+		pub pos: Option<ScheduleId>,
+		pub count: u64,
+	}
+
+	#[storage_alias]
+	pub(crate) type SchedulesListMetadata<T: Config> =
+		StorageValue<Pallet<T>, SchedulesList, ValueQuery>;
+
+	#[storage_alias]
+	pub(crate) type RewardsSchedulesList<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ScheduleId, (Schedule<T>, Option<ScheduleId>)>;
+
+	/// Moves schedules from the linked list (processed `SchedulesPerBlock` at a time) into
+	/// [`ActiveSchedules`] and registers their start/end in [`SchedulesChanges`]. Rewards of
+	/// schedules that were not yet processed in the current session are provided immediately so
+	/// every active (liquidity token, reward token) pair is accounted up to the current session.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply schedules migration to pos but failed because storage version is {:?}, and not 0",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let session_id = Pallet::<T>::session_index();
+			let metadata = SchedulesListMetadata::<T>::take();
+			// schedules after `pos` were not yet visited by `on_initialize` in the current
+			// session, at the beginning of the session none of them were
+			let mut pending = metadata.pos.is_none() || Pallet::<T>::is_new_session();
+
+			let mut rates: BTreeMap<(CurrencyIdOf<T>, CurrencyIdOf<T>), BalanceOf<T>> =
+				BTreeMap::new();
+			let mut next_id: ScheduleId = 0;
+			let mut migrated = 0u32;
+			let mut reads = 2u64;
+			let mut writes = 1u64;
+
+			let mut pos = metadata.head;
+			while let Some(id) = pos {
+				reads += 1;
+				let (schedule, next) = match RewardsSchedulesList::<T>::get(id) {
+					Some(entry) => entry,
+					None => break,
+				};
+				let is_pending = pending;
+				if Some(id) == metadata.pos {
+					pending = true;
+				}
+				pos = next;
+
+				if schedule.last_session < session_id {
+					continue
+				}
+
+				let pair = (schedule.liq_token, schedule.reward_token);
+				let rate = rates.entry(pair).or_insert_with(Zero::zero);
+				if schedule.scheduled_at < session_id {
+					*rate = rate.saturating_add(schedule.amount_per_session);
+					if is_pending {
+						ScheduleRewardsTotal::<T>::mutate(pair, |s| {
+							s.provide_rewards(session_id, schedule.amount_per_session)
+						});
+						reads += 1;
+						writes += 1;
+					}
+				} else {
+					let start_session = schedule.scheduled_at.saturating_add(1);
+					SchedulesChanges::<T>::insert(start_session, id, schedule.liq_token);
+					SchedulesChangesCount::<T>::mutate(start_session, |count| *count += 1);
+					reads += 1;
+					writes += 2;
+				}
+				let end_session = schedule.last_session.saturating_add(1);
+				SchedulesChanges::<T>::insert(end_session, id, schedule.liq_token);
+				SchedulesChangesCount::<T>::mutate(end_session, |count| *count += 1);
+				ActiveSchedules::<T>::insert(schedule.liq_token, id, schedule);
+				reads += 1;
+				writes += 3;
+
+				next_id = next_id.max(id.saturating_add(1));
+				migrated += 1;
+			}
+
+			for (pair, amount) in rates.iter() {
+				ScheduleRewardsRate::<T>::insert(
+					pair,
+					ScheduleRewardsPerSession { amount: *amount, provided_until: session_id },
+				);
+			}
+			writes += rates.len() as u64;
+
+			ActiveSchedulesCount::<T>::put(migrated);
+			NextScheduleId::<T>::put(next_id);
+			let removed = RewardsSchedulesList::<T>::clear(u32::MAX, None).unique as u64;
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 3 + removed;
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated {} active schedules of pos to storage version 1",
+				migrated,
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Fills [`ActivatedPools`] with pools that accounts have liquidity activated for native
+	/// rewards in. Pools above `MaxActivatedPoolsPerUser` are not tracked, but their liquidity
+	/// can still be deactivated.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_storage_version != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Attempted to apply activated pools migration to pos but failed because storage version is {:?}, and not 1",
+					on_chain_storage_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+			for (user, liquidity_asset_id, info) in RewardsInfo::<T>::iter() {
+				reads += 1;
+				if info.activated_amount.is_zero() {
+					continue
+				}
+				ActivatedPools::<T>::mutate(user, |pools| {
+					let _ = pools.try_push(liquidity_asset_id);
+				});
+				reads += 1;
+				writes += 1;
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Migrated activated pools of pos to storage version 2",
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
@@ -9,8 +9,8 @@ use core::convert::TryFrom;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		tokens::currency::MultiTokenCurrency, ConstU128, ConstU32, Contains, Everything, Nothing,
-		WithdrawReasons,
+		tokens::currency::MultiTokenCurrency, BalanceStatus, ConstU128, ConstU32, Contains,
+		Everything, Nothing, WithdrawReasons,
 	},
 	PalletId,
 };
@@ -278,38 +278,55 @@ impl pallet_xyk::Config for Test {
 	type DisabledTokens = Nothing;
 	type VestingProvider = Vesting;
 	type AssetMetadataMutation = AssetMetadataMutation;
+	type MaxValuationPathLength = ConstU32<3>;
+	type FeeLockWeight = ();
+}
+
+parameter_types! {
+	pub static MaxSchedulesChangesPerSession: u32 = 10;
+	pub static MaxActivatedPoolsPerUser: u32 = 10;
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
 impl pos::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = TokensActivationPassthrough<Test>;
+	type ThirdPartyActivationReservesProvider = TokensActivationPassthrough<Test>;
 	type NativeCurrencyId = NativeCurrencyId;
 	type Currency = MultiTokenCurrencyAdapter<Test>;
 	type LiquidityMiningIssuanceVault = FakeLiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = ConstU32<10>;
 	type RewardsSchedulesLimit = ConstU32<10>;
+	type MaxRewardTokensPerPool = ConstU32<2>;
+	type MaxSchedulesChangesPerSession = MaxSchedulesChangesPerSession;
+	type MaxActivatedPoolsPerUser = MaxActivatedPoolsPerUser;
 	type Min3rdPartyRewardValutationPerSession = ConstU128<10>;
 	type Min3rdPartyRewardVolume = ConstU128<10>;
+	type RewardsHistoryLength = ConstU32<10>;
+	type MaxClaimReceipts = ConstU32<5>;
 	type WeightInfo = ();
 	type ValuationApi = MockValuationApi;
-	type SchedulesPerBlock = ConstU32<5>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
 impl pos::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = TokensActivationPassthrough<Test>;
+	type ThirdPartyActivationReservesProvider = TokensActivationPassthrough<Test>;
 	type NativeCurrencyId = NativeCurrencyId;
 	type Currency = MultiTokenCurrencyAdapter<Test>;
 	type LiquidityMiningIssuanceVault = FakeLiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = ConstU32<10>;
 	type RewardsSchedulesLimit = ConstU32<10>;
+	type MaxRewardTokensPerPool = ConstU32<2>;
+	type MaxSchedulesChangesPerSession = MaxSchedulesChangesPerSession;
+	type MaxActivatedPoolsPerUser = MaxActivatedPoolsPerUser;
 	type Min3rdPartyRewardValutationPerSession = ConstU128<100_000>;
 	type Min3rdPartyRewardVolume = ConstU128<10>;
+	type RewardsHistoryLength = ConstU32<10>;
+	type MaxClaimReceipts = ConstU32<5>;
 	type WeightInfo = ();
 	type ValuationApi = Xyk;
-	type SchedulesPerBlock = ConstU32<5>;
 }
 
 pub struct TokensActivationPassthrough<T: Config>(PhantomData<T>);
@@ -346,6 +363,55 @@ where
 	}
 }
 
+impl<T: Config> ThirdPartyActivationReservesProviderTrait<AccountId, Balance, TokenId>
+	for TokensActivationPassthrough<T>
+where
+	T::Currency: MultiTokenReservableCurrency<AccountId, Balance = Balance, CurrencyId = TokenId>,
+{
+	fn can_activate_for_3rdparty_rewards(
+		token_id: TokenId,
+		account_id: &AccountId,
+		amount: Balance,
+		_use_balance_from: Option<ActivateKind>,
+	) -> bool {
+		<T as pallet::Config>::Currency::can_reserve(token_id, account_id, amount)
+	}
+
+	fn activate_for_3rdparty_rewards(
+		token_id: TokenId,
+		account_id: &AccountId,
+		amount: Balance,
+		_use_balance_from: Option<ActivateKind>,
+	) -> DispatchResult {
+		<T as pallet::Config>::Currency::reserve(token_id, account_id, amount)
+	}
+
+	fn deactivate_from_3rdparty_rewards(
+		token_id: TokenId,
+		account_id: &AccountId,
+		amount: Balance,
+	) -> Balance {
+		<T as pallet::Config>::Currency::unreserve(token_id, account_id, amount)
+	}
+	fn transfer_activated_reserves(
+		token_id: TokenId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		amount_3rdparty: Balance,
+	) -> DispatchResult {
+		let not_moved = <T as pallet::Config>::Currency::repatriate_reserved(
+			token_id,
+			from,
+			to,
+			amount + amount_3rdparty,
+			BalanceStatus::Reserved,
+		)?;
+		ensure!(not_moved == 0, DispatchError::Other("not enough reserved tokens"));
+		Ok(())
+	}
+}
+
 impl<T: Config> Pallet<T>
 where
 	T::Currency: MultiTokenReservableCurrency<AccountId, Balance = Balance, CurrencyId = TokenId>
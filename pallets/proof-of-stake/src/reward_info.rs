@@ -385,6 +385,27 @@ where
 		self.calculate_rewards_impl()
 	}
 
+	/// Calculates amount of rewards that would be received for `amount` of liquidity activated
+	/// for `sessions` sessions, assuming cumulative rewards per single liquidity token grow by
+	/// `pool_ratio` during that time
+	pub fn estimate_rewards(
+		amount: Balance,
+		sessions: u32,
+		pool_ratio: U256,
+	) -> sp_std::result::Result<Balance, RewardsCalcError> {
+		let rewards_context =
+			RewardsContext { current_time: sessions, pool_ratio_current: pool_ratio };
+		let rewards_info = RewardInfo {
+			activated_amount: amount,
+			last_checkpoint: 0,
+			pool_ratio_at_last_checkpoint: U256::zero(),
+			missing_at_last_checkpoint: U256::from(amount.into()),
+			..Default::default()
+		};
+		T::calculate_curve_rewards(&rewards_context, &rewards_info)
+			.ok_or(RewardsCalcError::CheckpointMathError)
+	}
+
 	fn calculate_rewards_impl(&self) -> sp_std::result::Result<Balance, RewardsCalcError> {
 		T::calculate_curve_rewards(&self.rewards_context, &self.rewards_info)
 			.ok_or(RewardsCalcError::CheckpointMathError)
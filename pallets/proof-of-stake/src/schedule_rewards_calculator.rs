@@ -1,10 +1,10 @@
 use crate::{
-	BalanceOf, Config, CurrencyIdOf, Pallet, ScheduleRewardsPerLiquidity, ScheduleRewardsTotal,
-	SessionId, TotalActivatedLiquidityForSchedules,
+	BalanceOf, Config, CurrencyIdOf, Pallet, ScheduleRewardsPerLiquidity, ScheduleRewardsRate,
+	ScheduleRewardsTotal, SessionId, TotalActivatedLiquidityForSchedules,
 };
 use core::marker::PhantomData;
 use frame_support::pallet_prelude::*;
-use sp_arithmetic::traits::{AtLeast32BitUnsigned, Zero};
+use sp_arithmetic::traits::{AtLeast32BitUnsigned, Saturating, Zero};
 use sp_core::U256;
 
 #[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -99,6 +99,38 @@ impl<Balance: AtLeast32BitUnsigned> ScheduleRewards<Balance> {
 	}
 }
 
+/// Amount of rewards that all active schedules of single (liquidity token, reward token) pair
+/// distribute per session. Rewards are provided to [`ScheduleRewards`] lazily, `provided_until`
+/// points to the last session that was already accounted for
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ScheduleRewardsPerSession<Balance: AtLeast32BitUnsigned> {
+	pub amount: Balance,
+	pub provided_until: SessionId,
+}
+
+impl<Balance: AtLeast32BitUnsigned> ScheduleRewardsPerSession<Balance> {
+	/// provides rewards for all the sessions in range `(provided_until, now]`, rewards for
+	/// sessions before `now` are already moved to `total` as they would be if they were provided
+	/// session by session
+	pub fn provide_rewards(&mut self, rewards: &mut ScheduleRewards<Balance>, now: SessionId) {
+		if now <= self.provided_until {
+			return
+		}
+
+		if !self.amount.is_zero() {
+			let sessions = now - self.provided_until;
+			if sessions > 1 {
+				rewards.provide_rewards(
+					now - 1,
+					self.amount.clone().saturating_mul((sessions - 1).into()),
+				);
+			}
+			rewards.provide_rewards(now, self.amount.clone());
+		}
+		self.provided_until = now;
+	}
+}
+
 pub struct ScheduleRewardsCalculator<T> {
 	data: PhantomData<T>,
 }
@@ -113,6 +145,7 @@ impl<T: Config> ScheduleRewardsCalculator<T> {
 		liquidity_assets_reward: CurrencyIdOf<T>,
 	) {
 		let session_id = Pallet::<T>::session_index();
+		Self::provide_schedule_rewards(liquidity_asset_id, liquidity_assets_reward, session_id);
 
 		let (cumulative, idx) =
 			ScheduleRewardsPerLiquidity::<T>::get((liquidity_asset_id, liquidity_assets_reward));
@@ -167,8 +200,51 @@ impl<T: Config> ScheduleRewardsCalculator<T> {
 		liquidity_asset_id: CurrencyIdOf<T>,
 		liquidity_assets_reward: CurrencyIdOf<T>,
 	) -> BalanceOf<T> {
-		ScheduleRewardsTotal::<T>::get((liquidity_asset_id, liquidity_assets_reward))
-			.total_rewards(Pallet::<T>::session_index())
+		let session_id = Pallet::<T>::session_index();
+		let mut rewards =
+			ScheduleRewardsTotal::<T>::get((liquidity_asset_id, liquidity_assets_reward));
+		ScheduleRewardsRate::<T>::get((liquidity_asset_id, liquidity_assets_reward))
+			.provide_rewards(&mut rewards, session_id);
+		rewards.total_rewards(session_id)
+	}
+
+	/// provides rewards distributed by active schedules in all the sessions up to `now` that were
+	/// not accounted for yet
+	pub fn provide_schedule_rewards(
+		liquidity_asset_id: CurrencyIdOf<T>,
+		liquidity_assets_reward: CurrencyIdOf<T>,
+		now: SessionId,
+	) {
+		let mut rate = ScheduleRewardsRate::<T>::get((liquidity_asset_id, liquidity_assets_reward));
+		if now > rate.provided_until {
+			ScheduleRewardsTotal::<T>::mutate((liquidity_asset_id, liquidity_assets_reward), |s| {
+				rate.provide_rewards(s, now)
+			});
+			ScheduleRewardsRate::<T>::insert((liquidity_asset_id, liquidity_assets_reward), rate);
+		}
+	}
+
+	/// changes amount of rewards distributed per session starting from the current session,
+	/// rewards from the previous sessions are provided with the old rate first
+	pub fn update_rewards_rate(
+		liquidity_asset_id: CurrencyIdOf<T>,
+		liquidity_assets_reward: CurrencyIdOf<T>,
+		diff: BalanceOf<T>,
+		change: bool,
+	) {
+		let session_id = Pallet::<T>::session_index();
+		Self::provide_schedule_rewards(
+			liquidity_asset_id,
+			liquidity_assets_reward,
+			session_id.saturating_sub(1),
+		);
+		ScheduleRewardsRate::<T>::mutate((liquidity_asset_id, liquidity_assets_reward), |rate| {
+			if change {
+				rate.amount = rate.amount.saturating_add(diff);
+			} else {
+				rate.amount = rate.amount.saturating_sub(diff);
+			}
+		});
 	}
 
 	/// returns amount of schedule rewards that has been accumulated since last update of `ScheduleRewardsPerLiquidity`
@@ -43,22 +43,6 @@ fn initialize_liquidity_rewards() {
 		.unwrap();
 }
 
-fn process_all_schedules_in_current_session() {
-	let session = ProofOfStake::session_index();
-	loop {
-		if ProofOfStake::session_index() > session {
-			panic!("couldnt process all schedules within the session");
-		}
-
-		if !ProofOfStake::is_new_session() &&
-			ProofOfStake::list_metadata().pos == ProofOfStake::list_metadata().tail
-		{
-			break
-		}
-		roll_to_next_block::<Test>();
-	}
-}
-
 #[test]
 #[serial]
 fn liquidity_rewards_single_user_mint_W() {
@@ -1364,26 +1348,24 @@ fn rewards_schedule_is_stored() {
 
 			let rewards_per_session = REWARD_AMOUNT / 5;
 			assert_eq!(
-				RewardsSchedulesList::<Test>::get(0).unwrap(),
-				(
-					Schedule {
-						scheduled_at: 0u32,
-						last_session: 5u32,
-						liq_token: LIQUIDITY_TOKEN,
-						reward_token: REWARD_TOKEN,
-						amount_per_session: rewards_per_session,
-					},
-					None
-				)
+				ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 0).unwrap(),
+				Schedule {
+					scheduled_at: 0u32,
+					last_session: 5u32,
+					liq_token: LIQUIDITY_TOKEN,
+					reward_token: REWARD_TOKEN,
+					amount_per_session: rewards_per_session,
+				}
 			);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 1);
+			assert_eq!(SchedulesChanges::<Test>::get(1, 0), Some(LIQUIDITY_TOKEN));
+			assert_eq!(SchedulesChanges::<Test>::get(6, 0), Some(LIQUIDITY_TOKEN));
 		});
 }
 
 #[test]
 #[serial]
-fn rewards_linked_list_insert_multiple_schedules() {
+fn rewards_schedules_insert_multiple_schedules() {
 	ExtBuilder::new()
 		.issue(ALICE, REWARD_TOKEN, 2 * REWARD_AMOUNT)
 		.execute_with_default_mocks(|| {
@@ -1396,7 +1378,7 @@ fn rewards_linked_list_insert_multiple_schedules() {
 				REWARD_AMOUNT,
 				1u32.into()
 			),);
-			assert_eq!(ProofOfStake::list_metadata().count, 1);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 1);
 
 			assert_ok!(ProofOfStake::reward_pool(
 				RuntimeOrigin::signed(ALICE),
@@ -1405,45 +1387,40 @@ fn rewards_linked_list_insert_multiple_schedules() {
 				REWARD_AMOUNT,
 				2u32.into()
 			),);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
 
 			assert_eq!(
-				RewardsSchedulesList::<Test>::get(0).unwrap(),
-				(
-					Schedule {
-						scheduled_at: 0u32,
-						last_session: 1u32,
-						liq_token: LIQUIDITY_TOKEN,
-						reward_token: REWARD_TOKEN,
-						amount_per_session: REWARD_AMOUNT / 1,
-					},
-					Some(1)
-				)
+				ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 0).unwrap(),
+				Schedule {
+					scheduled_at: 0u32,
+					last_session: 1u32,
+					liq_token: LIQUIDITY_TOKEN,
+					reward_token: REWARD_TOKEN,
+					amount_per_session: REWARD_AMOUNT / 1,
+				}
 			);
 
 			assert_eq!(
-				RewardsSchedulesList::<Test>::get(1).unwrap(),
-				(
-					Schedule {
-						scheduled_at: 0u32,
-						last_session: 2u32,
-						liq_token: LIQUIDITY_TOKEN,
-						reward_token: REWARD_TOKEN,
-						amount_per_session: REWARD_AMOUNT / 2,
-					},
-					None
-				)
+				ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 1).unwrap(),
+				Schedule {
+					scheduled_at: 0u32,
+					last_session: 2u32,
+					liq_token: LIQUIDITY_TOKEN,
+					reward_token: REWARD_TOKEN,
+					amount_per_session: REWARD_AMOUNT / 2,
+				}
 			);
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
+			assert_eq!(NextScheduleId::<Test>::get(), 2);
+			assert_eq!(SchedulesChanges::<Test>::iter_prefix(1).count(), 2);
+			assert_eq!(SchedulesChanges::<Test>::get(2, 0), Some(LIQUIDITY_TOKEN));
+			assert_eq!(SchedulesChanges::<Test>::get(3, 1), Some(LIQUIDITY_TOKEN));
 		});
 }
 
 #[test]
 #[serial]
-fn rewards_linked_list_removes_outdated_schedule_automatically() {
+fn rewards_schedules_removes_outdated_schedule_automatically() {
 	ExtBuilder::new()
 		.issue(ALICE, REWARD_TOKEN, 2 * REWARD_AMOUNT)
 		.execute_with_default_mocks(|| {
@@ -1464,53 +1441,35 @@ fn rewards_linked_list_removes_outdated_schedule_automatically() {
 				REWARD_AMOUNT,
 				2u32.into()
 			),);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-
-			forward_to_block::<Test>(2);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, Some(1u64));
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
+			assert_eq!(ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount, 0);
 
-			forward_to_block::<Test>(5);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, Some(1u64));
+			forward_to_block::<Test>(8);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
+			assert_eq!(ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount, 0);
 
-			forward_to_block::<Test>(11);
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, Some(1u64));
-
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
-			forward_to_block::<Test>(21);
-			assert_eq!(ProofOfStake::list_metadata().count, 1);
-			assert_eq!(ProofOfStake::list_metadata().head, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, Some(1u64));
+			forward_to_block::<Test>(9);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
+			assert_eq!(
+				ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount,
+				REWARD_AMOUNT + REWARD_AMOUNT / 2
+			);
+			assert_eq!(SchedulesChanges::<Test>::iter_prefix(1).count(), 0);
 
-			forward_to_block::<Test>(25);
-			assert_eq!(ProofOfStake::list_metadata().head, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, Some(1u64));
+			forward_to_block::<Test>(19);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 1);
+			assert_eq!(ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 0), None);
+			assert!(ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 1).is_some());
+			assert_eq!(
+				ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount,
+				REWARD_AMOUNT / 2
+			);
 
 			forward_to_block::<Test>(29);
-			assert_eq!(ProofOfStake::list_metadata().head, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-
-			forward_to_block::<Test>(30);
-			assert_eq!(ProofOfStake::list_metadata().head, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, None);
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 0);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 0);
+			assert_eq!(ActiveSchedules::<Test>::iter().count(), 0);
+			assert_eq!(SchedulesChanges::<Test>::iter().count(), 0);
+			assert_eq!(ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount, 0);
 		});
 }
 
@@ -1524,239 +1483,17 @@ fn insert_schedule_ending_at_session(n: u32) {
 	),);
 }
 
-#[test]
-#[serial]
-fn rewards_first_schedule_from_linked_list_of_four() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(1u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(1u64).unwrap().1, Some(2u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(2u64).unwrap().1, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(3u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 3);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_last_schedule_from_linked_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(2u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(1u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(1u64).unwrap().1, Some(2u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(2u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 3);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_middle_schedule_from_linked_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(2u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(2u64).unwrap().1, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(3u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 3);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_first_few_elems_at_once_from_linked_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-
-			forward_to_block::<Test>(20);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(2u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(2u64).unwrap().1, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(3u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_few_last_elems_at_once_from_linked_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(1);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(1u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(1u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_few_middle_elements_from_linkedd_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 4 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 4);
-
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(3u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
-		});
-}
-
-#[test]
-#[serial]
-fn remove_random_elements_from_linked_list() {
-	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 5 * REWARD_AMOUNT)
-		.execute_with_default_mocks(|| {
-			System::set_block_number(1);
-
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-			insert_schedule_ending_at_session(1);
-			insert_schedule_ending_at_session(2);
-
-			assert_ok!(RewardsSchedulesList::<Test>::get(0).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(1).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(2).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(3).ok_or(()));
-			assert_ok!(RewardsSchedulesList::<Test>::get(4).ok_or(()));
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(4u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 5);
-
-			forward_to_block::<Test>(21);
-
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(4u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(2u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(2u64).unwrap().1, Some(4u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(4u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 3);
-		});
+fn active_schedules_ids() -> Vec<ScheduleId> {
+	let mut ids = ActiveSchedules::<Test>::iter_prefix(LIQUIDITY_TOKEN)
+		.map(|(id, _)| id)
+		.collect::<Vec<_>>();
+	ids.sort();
+	ids
 }
 
 #[test]
 #[serial]
-fn remove_random_elements_from_linked_list_over_time() {
+fn remove_random_schedules_over_time() {
 	ExtBuilder::new()
 		.issue(ALICE, REWARD_TOKEN, 7 * REWARD_AMOUNT)
 		.execute_with_default_mocks(|| {
@@ -1770,68 +1507,65 @@ fn remove_random_elements_from_linked_list_over_time() {
 			insert_schedule_ending_at_session(1); // 5
 			insert_schedule_ending_at_session(3); // 6
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(6u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 7);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 7);
 
 			roll_to_session::<Test>(2);
-			process_all_schedules_in_current_session();
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(6u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(1u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(1u64).unwrap().1, Some(3u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(3u64).unwrap().1, Some(4u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(4u64).unwrap().1, Some(6u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(6u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 5);
+			assert_eq!(active_schedules_ids(), vec![0, 1, 3, 4, 6]);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 5);
 
 			roll_to_session::<Test>(3);
-			process_all_schedules_in_current_session();
+			assert_eq!(active_schedules_ids(), vec![0, 6]);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(6u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(6u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(6u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
+			roll_to_session::<Test>(4);
+			assert_eq!(active_schedules_ids(), Vec::<ScheduleId>::new());
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 0);
 		});
 }
 
 #[test]
 #[serial]
-fn remove_lot_of_schedules_from_linked_list_in_single_iteration() {
+fn all_schedules_are_processed_at_the_beginning_of_the_session() {
 	ExtBuilder::new()
-		.issue(ALICE, REWARD_TOKEN, 10 * REWARD_AMOUNT)
+		.issue(ALICE, REWARD_TOKEN, MILLION)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
 		.execute_with_default_mocks(|| {
 			System::set_block_number(1);
 
-			insert_schedule_ending_at_session(3); // 0
-			insert_schedule_ending_at_session(1); // 1
-			insert_schedule_ending_at_session(1); // 1
-			insert_schedule_ending_at_session(1); // 1
-			insert_schedule_ending_at_session(1); // 2
-			insert_schedule_ending_at_session(1); // 3
-			insert_schedule_ending_at_session(1); // 4
-			insert_schedule_ending_at_session(1); // 5
-			insert_schedule_ending_at_session(3); // 6
+			let max_schedules: u32 =
+				<<Test as Config>::RewardsSchedulesLimit as sp_core::Get<_>>::get();
+			for _ in 0..max_schedules {
+				insert_schedule_ending_at_session(10);
+			}
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().pos, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(8u64));
-			assert_eq!(ProofOfStake::list_metadata().count, 9);
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
 
-			forward_to_block::<Test>(24);
+			roll_to_session::<Test>(1);
+			assert_eq!(SchedulesChanges::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(
+				ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount,
+				max_schedules as u128 * REWARD_AMOUNT / 10
+			);
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(8u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(0u64).unwrap().1, Some(8u64));
-			assert_eq!(RewardsSchedulesList::<Test>::get(8u64).unwrap().1, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 2);
+			roll_to_session::<Test>(2);
+			assert_eq!(
+				ProofOfStake::calculate_3rdparty_rewards_amount(BOB, LIQUIDITY_TOKEN, REWARD_TOKEN),
+				Ok(max_schedules as u128 * REWARD_AMOUNT / 10)
+			);
 
-			forward_to_block::<Test>(100);
-			assert_eq!(ProofOfStake::list_metadata().head, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, None);
-			assert_eq!(ProofOfStake::list_metadata().count, 0);
+			roll_to_session::<Test>(20);
+			assert_eq!(
+				ProofOfStake::calculate_3rdparty_rewards_amount(BOB, LIQUIDITY_TOKEN, REWARD_TOKEN),
+				Ok(max_schedules as u128 * REWARD_AMOUNT)
+			);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 0);
 		});
 }
 
@@ -1878,6 +1612,66 @@ fn number_of_active_schedules_is_limited() {
 		});
 }
 
+#[test]
+#[serial]
+fn number_of_schedules_changes_per_session_is_limited() {
+	ExtBuilder::new()
+		.issue(ALICE, REWARD_TOKEN, MILLION)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+			MaxSchedulesChangesPerSession::set(2);
+
+			for _ in 0..2 {
+				assert_ok!(ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					REWARD_TOKEN,
+					REWARD_AMOUNT,
+					5u32.into()
+				));
+			}
+			assert_eq!(SchedulesChangesCount::<Test>::get(1), 2);
+			assert_eq!(SchedulesChangesCount::<Test>::get(6), 2);
+
+			// both schedules start in the next session
+			assert_err!(
+				ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					REWARD_TOKEN,
+					REWARD_AMOUNT,
+					10u32.into()
+				),
+				Error::<Test>::TooManySchedulesChanges
+			);
+
+			roll_to_session::<Test>(1);
+			assert!(!SchedulesChangesCount::<Test>::contains_key(1));
+
+			// both schedules finish in the same session
+			assert_err!(
+				ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					REWARD_TOKEN,
+					REWARD_AMOUNT,
+					5u32.into()
+				),
+				Error::<Test>::TooManySchedulesChanges
+			);
+
+			assert_ok!(ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into()
+			));
+
+			MaxSchedulesChangesPerSession::set(10);
+		});
+}
+
 #[test]
 #[serial]
 fn duplicated_schedules_works() {
@@ -1886,8 +1680,7 @@ fn duplicated_schedules_works() {
 		.execute_with_default_mocks(|| {
 			System::set_block_number(1);
 
-			assert_eq!(ProofOfStake::list_metadata().head, None);
-			assert_eq!(ProofOfStake::list_metadata().tail, None);
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 0);
 
 			assert_ok!(ProofOfStake::reward_pool(
 				RuntimeOrigin::signed(ALICE),
@@ -1897,8 +1690,7 @@ fn duplicated_schedules_works() {
 				5u32.into()
 			));
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(0u64));
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 1);
 
 			assert_ok!(ProofOfStake::reward_pool(
 				RuntimeOrigin::signed(ALICE),
@@ -1908,9 +1700,107 @@ fn duplicated_schedules_works() {
 				5u32.into()
 			));
 
-			assert_eq!(ProofOfStake::list_metadata().head, Some(0u64));
-			assert_eq!(ProofOfStake::list_metadata().tail, Some(1u64));
+			assert_eq!(ActiveSchedulesCount::<Test>::get(), 2);
+			assert_eq!(
+				ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 0),
+				ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 1)
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn migration_moves_schedules_from_linked_list() {
+	use crate::migration::v1::{
+		MigrateToV1, RewardsSchedulesList, SchedulesList, SchedulesListMetadata,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	ExtBuilder::new().execute_with_default_mocks(|| {
+		System::set_block_number(12);
+		StorageVersion::new(0).put::<ProofOfStake>();
+
+		let schedule = |scheduled_at, last_session, reward_token, amount_per_session| Schedule {
+			scheduled_at,
+			last_session,
+			liq_token: LIQUIDITY_TOKEN,
+			reward_token,
+			amount_per_session,
+		};
+
+		// outdated
+		RewardsSchedulesList::<Test>::insert(0, (schedule(0, 0, REWARD_TOKEN, 1000), Some(1)));
+		// already processed in current session
+		RewardsSchedulesList::<Test>::insert(1, (schedule(0, 3, REWARD_TOKEN, 100), Some(2)));
+		ScheduleRewardsTotal::<Test>::mutate((LIQUIDITY_TOKEN, REWARD_TOKEN), |s| {
+			s.provide_rewards(1, 100)
 		});
+		// not yet processed in current session
+		RewardsSchedulesList::<Test>::insert(2, (schedule(0, 2, SECOND_REWARD_TOKEN, 50), Some(3)));
+		// scheduled in current session
+		RewardsSchedulesList::<Test>::insert(3, (schedule(1, 4, REWARD_TOKEN, 10), None));
+		// removed from the list
+		RewardsSchedulesList::<Test>::insert(7, (schedule(0, 5, REWARD_TOKEN, 10), None));
+		SchedulesListMetadata::<Test>::put(SchedulesList {
+			head: Some(0),
+			tail: Some(3),
+			pos: Some(1),
+			count: 4,
+		});
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfStake::on_chain_storage_version(), 1);
+		assert_eq!(RewardsSchedulesList::<Test>::iter().count(), 0);
+		assert!(!SchedulesListMetadata::<Test>::exists());
+
+		assert_eq!(ActiveSchedulesCount::<Test>::get(), 3);
+		assert_eq!(NextScheduleId::<Test>::get(), 4);
+		assert_eq!(ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 0), None);
+		assert_eq!(
+			ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 2),
+			Some(schedule(0, 2, SECOND_REWARD_TOKEN, 50))
+		);
+		assert_eq!(ActiveSchedules::<Test>::get(LIQUIDITY_TOKEN, 7), None);
+
+		assert_eq!(SchedulesChanges::<Test>::iter().count(), 4);
+		assert_eq!(SchedulesChanges::<Test>::get(4, 1), Some(LIQUIDITY_TOKEN));
+		assert_eq!(SchedulesChanges::<Test>::get(3, 2), Some(LIQUIDITY_TOKEN));
+		assert_eq!(SchedulesChanges::<Test>::get(2, 3), Some(LIQUIDITY_TOKEN));
+		assert_eq!(SchedulesChanges::<Test>::get(5, 3), Some(LIQUIDITY_TOKEN));
+		assert_eq!(SchedulesChangesCount::<Test>::iter().count(), 4);
+		assert_eq!(SchedulesChangesCount::<Test>::get(2), 1);
+
+		assert_eq!(
+			ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)),
+			ScheduleRewardsPerSession { amount: 100, provided_until: 1 }
+		);
+		assert_eq!(
+			ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, SECOND_REWARD_TOKEN)),
+			ScheduleRewardsPerSession { amount: 50, provided_until: 1 }
+		);
+		assert_eq!(
+			ScheduleRewardsTotal::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).total_rewards(2),
+			100
+		);
+		assert_eq!(
+			ScheduleRewardsTotal::<Test>::get((LIQUIDITY_TOKEN, SECOND_REWARD_TOKEN))
+				.total_rewards(2),
+			50
+		);
+
+		roll_to_session::<Test>(2);
+		assert_eq!(ScheduleRewardsRate::<Test>::get((LIQUIDITY_TOKEN, REWARD_TOKEN)).amount, 110);
+
+		roll_to_session::<Test>(3);
+		assert_eq!(
+			ScheduleRewardsCalculator::<Test>::total_schedule_rewards(
+				LIQUIDITY_TOKEN,
+				SECOND_REWARD_TOKEN
+			),
+			100
+		);
+	});
 }
 
 #[test]
@@ -3260,6 +3150,77 @@ fn unsuccessul_activate_deactivate_calls_charges_fees() {
 		});
 }
 
+#[test]
+#[serial]
+fn batch_deactivate_charges_fees_when_nothing_is_deactivated() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				FIRST_REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+
+			let batch_deactivate_call = |amount, reward_tokens: Vec<TokenId>| {
+				mock::RuntimeCall::ProofOfStake(
+					Call::batch_deactivate_liquidity_for_3rdparty_rewards {
+						liquidity_token_id: LIQUIDITY_TOKEN,
+						amount,
+						reward_tokens: reward_tokens.try_into().unwrap(),
+					},
+				)
+			};
+
+			for call in [
+				batch_deactivate_call(100, vec![]),
+				batch_deactivate_call(100, vec![SECOND_REWARD_TOKEN]),
+				batch_deactivate_call(0, vec![FIRST_REWARD_TOKEN]),
+			] {
+				let err = call.dispatch(RuntimeOrigin::signed(BOB)).unwrap_err();
+				assert_eq!(err.error, Error::<Test>::NoLiquidityToDeactivate.into());
+				assert_eq!(err.post_info.pays_fee, Pays::Yes);
+			}
+
+			assert_eq!(
+				batch_deactivate_call(60, vec![SECOND_REWARD_TOKEN, FIRST_REWARD_TOKEN])
+					.dispatch(RuntimeOrigin::signed(BOB))
+					.unwrap()
+					.pays_fee,
+				Pays::No
+			);
+			assert_eq!(
+				ActivatedLiquidityForSchedules::<Test>::get((
+					BOB,
+					LIQUIDITY_TOKEN,
+					FIRST_REWARD_TOKEN
+				)),
+				Some(40)
+			);
+			assert_eq!(
+				events()
+					.into_iter()
+					.filter(|e| matches!(e, Event::ThirdPartyLiquidityDeactivated(..)))
+					.count(),
+				1
+			);
+		});
+}
+
 #[test]
 #[serial]
 fn claim_rewards_from_multiple_sessions_at_once() {
@@ -4519,3 +4480,1143 @@ fn test_event_is_emmited_when_pool_is_rewarded() {
 			));
 		});
 }
+
+#[test]
+#[serial]
+fn liquidity_is_partially_unlocked_when_partially_deactivated_from_all_3rdparty_rewards() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, 100_000u128)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				SECOND_REWARD_TOKEN,
+				2 * REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				FIRST_REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				SECOND_REWARD_TOKEN,
+				Some(ThirdPartyActivationKind::ActivatedLiquidity(FIRST_REWARD_TOKEN)),
+			)
+			.unwrap();
+
+			ProofOfStake::deactivate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				30,
+				FIRST_REWARD_TOKEN,
+			)
+			.unwrap();
+			assert_err!(
+				TokensOf::<Test>::transfer(
+					LIQUIDITY_TOKEN,
+					&BOB,
+					&CHARLIE,
+					1,
+					ExistenceRequirement::AllowDeath
+				),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+
+			ProofOfStake::deactivate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				30,
+				SECOND_REWARD_TOKEN,
+			)
+			.unwrap();
+
+			assert_eq!(
+				ActivatedLiquidityForSchedules::<Test>::get((
+					BOB,
+					LIQUIDITY_TOKEN,
+					FIRST_REWARD_TOKEN
+				)),
+				Some(70)
+			);
+			assert_eq!(
+				ActivatedLiquidityForSchedules::<Test>::get((
+					BOB,
+					LIQUIDITY_TOKEN,
+					SECOND_REWARD_TOKEN
+				)),
+				Some(70)
+			);
+			assert_eq!(ActivatedLockedLiquidityForSchedules::<Test>::get(BOB, LIQUIDITY_TOKEN), 70);
+			assert_err!(
+				TokensOf::<Test>::transfer(
+					LIQUIDITY_TOKEN,
+					&BOB,
+					&CHARLIE,
+					31,
+					ExistenceRequirement::AllowDeath
+				),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+			assert_ok!(TokensOf::<Test>::transfer(
+				LIQUIDITY_TOKEN,
+				&BOB,
+				&CHARLIE,
+				30,
+				ExistenceRequirement::AllowDeath
+			));
+		});
+}
+
+#[test]
+#[serial]
+fn batch_deactivate_liquidity_for_3rdparty_rewards_unlocks_part_of_liquidity() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, 100_000u128)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				SECOND_REWARD_TOKEN,
+				2 * REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				FIRST_REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				50,
+				SECOND_REWARD_TOKEN,
+				Some(ThirdPartyActivationKind::ActivatedLiquidity(FIRST_REWARD_TOKEN)),
+			)
+			.unwrap();
+
+			assert_ok!(ProofOfStake::batch_deactivate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				60,
+				vec![FIRST_REWARD_TOKEN, SECOND_REWARD_TOKEN].try_into().unwrap(),
+			));
+
+			assert_eq!(
+				ActivatedLiquidityForSchedules::<Test>::get((
+					BOB,
+					LIQUIDITY_TOKEN,
+					FIRST_REWARD_TOKEN
+				)),
+				Some(40)
+			);
+			assert_eq!(
+				ActivatedLiquidityForSchedules::<Test>::get((
+					BOB,
+					LIQUIDITY_TOKEN,
+					SECOND_REWARD_TOKEN
+				)),
+				None
+			);
+			assert_eq!(ActivatedLockedLiquidityForSchedules::<Test>::get(BOB, LIQUIDITY_TOKEN), 40);
+			assert_err!(
+				TokensOf::<Test>::transfer(
+					LIQUIDITY_TOKEN,
+					&BOB,
+					&CHARLIE,
+					61,
+					ExistenceRequirement::AllowDeath
+				),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+			assert_ok!(TokensOf::<Test>::transfer(
+				LIQUIDITY_TOKEN,
+				&BOB,
+				&CHARLIE,
+				60,
+				ExistenceRequirement::AllowDeath
+			));
+		});
+}
+
+#[test]
+#[serial]
+fn reactivated_native_liquidity_is_released_when_partially_deactivated_from_3rdparty_rewards() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				FIRST_REWARD_TOKEN,
+				Some(ThirdPartyActivationKind::NativeRewardsLiquidity),
+			)
+			.unwrap();
+
+			ProofOfStake::deactivate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				40,
+				FIRST_REWARD_TOKEN,
+			)
+			.unwrap();
+			assert_eq!(ActivatedNativeRewardsLiq::<Test>::get(BOB, LIQUIDITY_TOKEN), 60);
+
+			assert_err!(
+				ProofOfStake::deactivate_liquidity_for_native_rewards(
+					RuntimeOrigin::signed(BOB),
+					LIQUIDITY_TOKEN,
+					41,
+				),
+				Error::<Test>::LiquidityLockedIn3rdpartyRewards
+			);
+			assert_ok!(ProofOfStake::deactivate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				40,
+			));
+			assert_ok!(TokensOf::<Test>::transfer(
+				LIQUIDITY_TOKEN,
+				&BOB,
+				&CHARLIE,
+				40,
+				ExistenceRequirement::AllowDeath
+			));
+		});
+}
+
+#[test]
+#[serial]
+fn get_rewards_schedules_returns_only_active_schedules_of_given_liquidity_token() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, 100_000u128)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				SECOND_REWARD_TOKEN,
+				2 * REWARD_AMOUNT,
+				2u32.into(),
+			)
+			.unwrap();
+
+			assert_eq!(
+				ProofOfStake::get_rewards_schedules(LIQUIDITY_TOKEN),
+				vec![
+					(FIRST_REWARD_TOKEN, REWARD_AMOUNT / 10, 10),
+					(SECOND_REWARD_TOKEN, REWARD_AMOUNT, 2),
+				]
+			);
+			assert_eq!(ProofOfStake::get_rewards_schedules(LIQUIDITY_TOKEN + 1), vec![]);
+
+			roll_to_session::<Test>(3);
+
+			assert_eq!(
+				ProofOfStake::get_rewards_schedules(LIQUIDITY_TOKEN),
+				vec![(FIRST_REWARD_TOKEN, REWARD_AMOUNT / 10, 7)]
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn get_user_activations_returns_all_activations_and_claimable_rewards() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, 100_000u128)
+		.issue(BOB, LIQUIDITY_TOKEN, 200)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				SECOND_REWARD_TOKEN,
+				2 * REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				50,
+				FIRST_REWARD_TOKEN,
+				Some(ThirdPartyActivationKind::NativeRewardsLiquidity),
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				SECOND_REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+
+			roll_to_session::<Test>(2);
+
+			let native_claimable =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			assert_eq!(
+				ProofOfStake::get_user_activations(BOB),
+				vec![UserActivations {
+					liquidity_token: LIQUIDITY_TOKEN,
+					native_activated: 100,
+					native_reactivated: 50,
+					native_claimable,
+					third_party: vec![
+						(FIRST_REWARD_TOKEN, 50, 1000),
+						(SECOND_REWARD_TOKEN, 100, 2000),
+					],
+				}]
+			);
+			assert_eq!(ProofOfStake::get_user_activations(CHARLIE), vec![]);
+		});
+}
+
+#[test]
+#[serial]
+fn estimated_native_rewards_match_rewards_received_after_activation() {
+	ExtBuilder::new()
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			roll_to_session::<Test>(1);
+
+			let estimation = ProofOfStake::estimate_rewards(LIQUIDITY_TOKEN, 100, 5);
+			assert_eq!(estimation.native_rewards_per_session, 10000);
+			assert_eq!(estimation.third_party_rewards_per_session, vec![]);
+			assert!(estimation.estimated_yearly_rewards > 0);
+			assert!(estimation.estimated_yearly_rewards < 5 * 10000);
+
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			roll_to_session::<Test>(6);
+
+			assert_eq!(
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN),
+				Ok(estimation.estimated_yearly_rewards)
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn estimate_rewards_includes_3rdparty_schedules() {
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			assert_eq!(
+				ProofOfStake::estimate_rewards(LIQUIDITY_TOKEN, 5, 5),
+				RewardsEstimation {
+					native_rewards_per_session: 0,
+					third_party_rewards_per_session: vec![(FIRST_REWARD_TOKEN, 1000, 11)],
+					activation_valuation: 11,
+					estimated_yearly_rewards: 55,
+					estimated_apr_bps: 50000,
+				}
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn claimed_native_rewards_are_transferred_to_rewards_destination() {
+	ExtBuilder::new()
+		.issue(<Test as Config>::LiquidityMiningIssuanceVault::get(), NATIVE_CURRENCY_ID, MILLION)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::set_rewards_destination(RuntimeOrigin::signed(BOB), Some(CHARLIE))
+				.unwrap();
+			assert_event_emitted!(Event::<Test>::RewardsDestinationSet(BOB, Some(CHARLIE)));
+
+			roll_to_session::<Test>(2);
+			let rewards =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			assert!(rewards > 0);
+
+			ProofOfStake::claim_native_rewards(RuntimeOrigin::signed(BOB), LIQUIDITY_TOKEN)
+				.unwrap();
+			assert_eq!(TokensOf::<Test>::free_balance(NATIVE_CURRENCY_ID, &BOB), 0);
+			assert_eq!(TokensOf::<Test>::free_balance(NATIVE_CURRENCY_ID, &CHARLIE), rewards);
+			assert_event_emitted!(Event::<Test>::RewardsClaimed(
+				BOB,
+				LIQUIDITY_TOKEN,
+				rewards,
+				CHARLIE
+			));
+
+			roll_to_session::<Test>(3);
+			ProofOfStake::set_rewards_destination(RuntimeOrigin::signed(BOB), None).unwrap();
+			let rewards =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			ProofOfStake::claim_native_rewards(RuntimeOrigin::signed(BOB), LIQUIDITY_TOKEN)
+				.unwrap();
+			assert_eq!(TokensOf::<Test>::free_balance(NATIVE_CURRENCY_ID, &BOB), rewards);
+		});
+}
+
+#[test]
+#[serial]
+fn claimed_3rdparty_rewards_are_transferred_to_rewards_destination() {
+	ExtBuilder::new()
+		.issue(ALICE, REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			roll_to_session::<Test>(1);
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::set_rewards_destination(RuntimeOrigin::signed(BOB), Some(CHARLIE))
+				.unwrap();
+
+			roll_to_session::<Test>(2);
+			assert_eq!(
+				ProofOfStake::calculate_3rdparty_rewards_amount(BOB, LIQUIDITY_TOKEN, REWARD_TOKEN),
+				Ok(1000)
+			);
+			ProofOfStake::claim_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				REWARD_TOKEN,
+			)
+			.unwrap();
+
+			assert_eq!(TokensOf::<Test>::free_balance(REWARD_TOKEN, &BOB), 0);
+			assert_eq!(TokensOf::<Test>::free_balance(REWARD_TOKEN, &CHARLIE), 1000);
+			assert_event_emitted!(Event::<Test>::ThirdPartyRewardsClaimed(
+				BOB,
+				LIQUIDITY_TOKEN,
+				REWARD_TOKEN,
+				1000,
+				CHARLIE
+			));
+		});
+}
+
+#[test]
+#[serial]
+fn only_designated_claimer_can_claim_rewards_on_behalf_of_owner() {
+	ExtBuilder::new()
+		.issue(<Test as Config>::LiquidityMiningIssuanceVault::get(), NATIVE_CURRENCY_ID, MILLION)
+		.issue(ALICE, REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 200)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			roll_to_session::<Test>(1);
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::set_rewards_destination(RuntimeOrigin::signed(BOB), Some(CHARLIE))
+				.unwrap();
+			roll_to_session::<Test>(2);
+
+			assert_err!(
+				ProofOfStake::claim_native_rewards_for(
+					RuntimeOrigin::signed(EVE),
+					BOB,
+					LIQUIDITY_TOKEN
+				),
+				Error::<Test>::NotARewardsClaimer
+			);
+			assert_err_ignore_postinfo!(
+				ProofOfStake::claim_3rdparty_rewards_for(
+					RuntimeOrigin::signed(EVE),
+					BOB,
+					LIQUIDITY_TOKEN,
+					REWARD_TOKEN
+				),
+				Error::<Test>::NotARewardsClaimer
+			);
+
+			ProofOfStake::set_rewards_claimer(RuntimeOrigin::signed(BOB), Some(EVE)).unwrap();
+			assert_event_emitted!(Event::<Test>::RewardsClaimerSet(BOB, Some(EVE)));
+
+			let native_rewards =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			ProofOfStake::claim_native_rewards_for(
+				RuntimeOrigin::signed(EVE),
+				BOB,
+				LIQUIDITY_TOKEN,
+			)
+			.unwrap();
+			ProofOfStake::claim_3rdparty_rewards_for(
+				RuntimeOrigin::signed(EVE),
+				BOB,
+				LIQUIDITY_TOKEN,
+				REWARD_TOKEN,
+			)
+			.unwrap();
+
+			assert_eq!(
+				TokensOf::<Test>::free_balance(NATIVE_CURRENCY_ID, &CHARLIE),
+				native_rewards
+			);
+			assert_eq!(TokensOf::<Test>::free_balance(REWARD_TOKEN, &CHARLIE), 1000);
+			assert_eq!(TokensOf::<Test>::free_balance(NATIVE_CURRENCY_ID, &EVE), 0);
+			assert_eq!(TokensOf::<Test>::free_balance(REWARD_TOKEN, &EVE), 0);
+
+			ProofOfStake::set_rewards_claimer(RuntimeOrigin::signed(BOB), None).unwrap();
+			assert_err!(
+				ProofOfStake::claim_native_rewards_for(
+					RuntimeOrigin::signed(EVE),
+					BOB,
+					LIQUIDITY_TOKEN
+				),
+				Error::<Test>::NotARewardsClaimer
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn activated_liquidity_can_be_transferred_to_another_account() {
+	ExtBuilder::new()
+		.issue(<Test as Config>::LiquidityMiningIssuanceVault::get(), NATIVE_CURRENCY_ID, MILLION)
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 200)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			for reward_token in [FIRST_REWARD_TOKEN, SECOND_REWARD_TOKEN] {
+				ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					reward_token,
+					REWARD_AMOUNT,
+					10u32.into(),
+				)
+				.unwrap();
+			}
+
+			roll_to_session::<Test>(1);
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				FIRST_REWARD_TOKEN,
+				Some(ThirdPartyActivationKind::NativeRewardsLiquidity),
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				50,
+				SECOND_REWARD_TOKEN,
+				None,
+			)
+			.unwrap();
+
+			roll_to_session::<Test>(2);
+			let native_rewards =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			let rewards_info = ProofOfStake::get_rewards_info(BOB, LIQUIDITY_TOKEN);
+
+			ProofOfStake::transfer_activated_liquidity(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				CHARLIE,
+			)
+			.unwrap();
+			assert_event_emitted!(Event::<Test>::ActivatedLiquidityTransferred(
+				BOB,
+				CHARLIE,
+				LIQUIDITY_TOKEN,
+				150
+			));
+
+			assert_eq!(TokensOf::<Test>::free_balance(LIQUIDITY_TOKEN, &BOB), 50);
+			assert_eq!(TokensOf::<Test>::reserved_balance(LIQUIDITY_TOKEN, &BOB), 0);
+			assert_eq!(TokensOf::<Test>::free_balance(LIQUIDITY_TOKEN, &CHARLIE), 0);
+			assert_eq!(TokensOf::<Test>::reserved_balance(LIQUIDITY_TOKEN, &CHARLIE), 150);
+
+			assert_eq!(ProofOfStake::get_rewards_info(CHARLIE, LIQUIDITY_TOKEN), rewards_info);
+			assert_eq!(ProofOfStake::get_user_activations(BOB), vec![]);
+			assert!(!ActivatedPools::<Test>::contains_key(BOB));
+			assert_eq!(ActivatedPools::<Test>::get(CHARLIE).into_inner(), vec![LIQUIDITY_TOKEN]);
+			assert_eq!(
+				ProofOfStake::get_user_activations(CHARLIE),
+				vec![UserActivations {
+					liquidity_token: LIQUIDITY_TOKEN,
+					native_activated: 100,
+					native_reactivated: 100,
+					native_claimable: native_rewards,
+					third_party: vec![
+						(FIRST_REWARD_TOKEN, 100, 1000),
+						(SECOND_REWARD_TOKEN, 50, 1000)
+					],
+				}]
+			);
+
+			ProofOfStake::batch_deactivate_liquidity_for_3rdparty_rewards(
+				RuntimeOrigin::signed(CHARLIE),
+				LIQUIDITY_TOKEN,
+				100,
+				vec![FIRST_REWARD_TOKEN, SECOND_REWARD_TOKEN].try_into().unwrap(),
+			)
+			.unwrap();
+			ProofOfStake::deactivate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(CHARLIE),
+				LIQUIDITY_TOKEN,
+				100,
+			)
+			.unwrap();
+			assert_eq!(TokensOf::<Test>::free_balance(LIQUIDITY_TOKEN, &CHARLIE), 150);
+		});
+}
+
+#[test]
+#[serial]
+fn activated_liquidity_cannot_be_transferred_to_account_with_activations() {
+	ExtBuilder::new()
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.issue(CHARLIE, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			for user in [BOB, CHARLIE] {
+				ProofOfStake::activate_liquidity_for_native_rewards(
+					RuntimeOrigin::signed(user),
+					LIQUIDITY_TOKEN,
+					100,
+					None,
+				)
+				.unwrap();
+			}
+
+			assert_err!(
+				ProofOfStake::transfer_activated_liquidity(
+					RuntimeOrigin::signed(BOB),
+					LIQUIDITY_TOKEN,
+					CHARLIE
+				),
+				Error::<Test>::DestinationAlreadyActivated
+			);
+			assert_err!(
+				ProofOfStake::transfer_activated_liquidity(
+					RuntimeOrigin::signed(BOB),
+					LIQUIDITY_TOKEN,
+					BOB
+				),
+				Error::<Test>::DestinationAlreadyActivated
+			);
+			assert_err!(
+				ProofOfStake::transfer_activated_liquidity(
+					RuntimeOrigin::signed(EVE),
+					LIQUIDITY_TOKEN,
+					ALICE
+				),
+				Error::<Test>::NoActivatedLiquidityToTransfer
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn number_of_pools_with_activated_liquidity_is_limited_per_user() {
+	const SECOND_LIQUIDITY_TOKEN: u32 = 11u32;
+	ExtBuilder::new()
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.issue(BOB, SECOND_LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+			MaxActivatedPoolsPerUser::set(1);
+
+			for liquidity_token in [LIQUIDITY_TOKEN, SECOND_LIQUIDITY_TOKEN] {
+				ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), liquidity_token, 1u8)
+					.unwrap();
+			}
+
+			assert_ok!(ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			));
+			assert_eq!(ActivatedPools::<Test>::get(BOB).into_inner(), vec![LIQUIDITY_TOKEN]);
+			assert_eq!(ProofOfStake::get_activated_liquidity_value(&BOB), 11);
+
+			assert_err!(
+				ProofOfStake::activate_liquidity_for_native_rewards(
+					RuntimeOrigin::signed(BOB),
+					SECOND_LIQUIDITY_TOKEN,
+					100,
+					None,
+				),
+				Error::<Test>::TooManyActivatedPools
+			);
+
+			assert_ok!(ProofOfStake::deactivate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+			));
+			assert!(!ActivatedPools::<Test>::contains_key(BOB));
+			assert_eq!(ProofOfStake::get_activated_liquidity_value(&BOB), 0);
+
+			assert_ok!(ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				SECOND_LIQUIDITY_TOKEN,
+				100,
+				None,
+			));
+			assert_eq!(ActivatedPools::<Test>::get(BOB).into_inner(), vec![SECOND_LIQUIDITY_TOKEN]);
+
+			MaxActivatedPoolsPerUser::set(10);
+		});
+}
+
+#[test]
+#[serial]
+fn migration_fills_activated_pools() {
+	use crate::migration::v2::MigrateToV2;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	ExtBuilder::new().execute_with_default_mocks(|| {
+		StorageVersion::new(1).put::<ProofOfStake>();
+
+		RewardsInfo::<Test>::insert(
+			BOB,
+			LIQUIDITY_TOKEN,
+			RewardInfo { activated_amount: 100, ..Default::default() },
+		);
+		RewardsInfo::<Test>::insert(
+			BOB,
+			REWARD_TOKEN,
+			RewardInfo { rewards_not_yet_claimed: 100, ..Default::default() },
+		);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ProofOfStake::on_chain_storage_version(), 2);
+		assert_eq!(ActivatedPools::<Test>::get(BOB).into_inner(), vec![LIQUIDITY_TOKEN]);
+	});
+}
+
+#[test]
+#[serial]
+fn number_of_reward_tokens_per_pool_is_limited() {
+	const THIRD_REWARD_TOKEN: u32 = 7u32;
+	ExtBuilder::new()
+		.issue(ALICE, FIRST_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, SECOND_REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(ALICE, THIRD_REWARD_TOKEN, REWARD_AMOUNT)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			for reward_token in [FIRST_REWARD_TOKEN, SECOND_REWARD_TOKEN] {
+				assert_ok!(ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					reward_token,
+					REWARD_AMOUNT / 2,
+					10u32.into(),
+				));
+			}
+
+			assert_err!(
+				ProofOfStake::reward_pool(
+					RuntimeOrigin::signed(ALICE),
+					REWARDED_PAIR,
+					THIRD_REWARD_TOKEN,
+					REWARD_AMOUNT,
+					10u32.into(),
+				),
+				Error::<Test>::TooManyRewardTokens
+			);
+
+			// already used reward tokens can still be scheduled
+			assert_ok!(ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				FIRST_REWARD_TOKEN,
+				REWARD_AMOUNT / 2,
+				10u32.into(),
+			));
+		});
+}
+
+#[test]
+#[serial]
+fn rewards_history_contains_native_and_3rdparty_rewards() {
+	ExtBuilder::new()
+		.issue(ALICE, REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+
+			roll_to_session::<Test>(2);
+
+			assert_eq!(
+				ProofOfStake::get_rewards_history(LIQUIDITY_TOKEN, 0, 2),
+				vec![
+					SessionRewards { session: 0, native_rewards: 0, third_party_rewards: vec![] },
+					SessionRewards {
+						session: 1,
+						native_rewards: 10000,
+						third_party_rewards: vec![(REWARD_TOKEN, 1000)]
+					},
+					SessionRewards {
+						session: 2,
+						native_rewards: 10000,
+						third_party_rewards: vec![(REWARD_TOKEN, 1000)]
+					},
+				]
+			);
+			assert_eq!(ProofOfStake::get_rewards_history(LIQUIDITY_TOKEN, 3, 100), vec![]);
+		});
+}
+
+#[test]
+#[serial]
+fn rewards_history_is_pruned() {
+	ExtBuilder::new()
+		.issue(ALICE, REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				5u32.into(),
+			)
+			.unwrap();
+
+			let history_length: u32 =
+				<<Test as Config>::RewardsHistoryLength as sp_core::Get<_>>::get();
+			roll_to_session::<Test>(history_length + 4);
+
+			assert_eq!(NativeRewardsHistory::<Test>::get(4, LIQUIDITY_TOKEN), None);
+			assert_eq!(ScheduleRewardsHistory::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(
+				ScheduleRewardsHistory::<Test>::get(5, (LIQUIDITY_TOKEN, REWARD_TOKEN)),
+				Some(2000)
+			);
+
+			let history = ProofOfStake::get_rewards_history(LIQUIDITY_TOKEN, 0, history_length + 4);
+			assert_eq!(history.len(), history_length as usize);
+			assert_eq!(
+				history[0],
+				SessionRewards {
+					session: 5,
+					native_rewards: 10000,
+					third_party_rewards: vec![(REWARD_TOKEN, 2000)]
+				}
+			);
+			assert_eq!(
+				history[1],
+				SessionRewards { session: 6, native_rewards: 10000, third_party_rewards: vec![] }
+			);
+
+			roll_to_next_session::<Test>();
+			assert_eq!(
+				ProofOfStake::get_rewards_history(LIQUIDITY_TOKEN, 0, 6),
+				vec![SessionRewards {
+					session: 6,
+					native_rewards: 10000,
+					third_party_rewards: vec![]
+				}]
+			);
+		});
+}
+
+#[test]
+#[serial]
+fn claim_receipts_are_stored_for_accounts_that_opted_in() {
+	ExtBuilder::new()
+		.issue(ALICE, REWARD_TOKEN, REWARD_AMOUNT)
+		.issue(BOB, LIQUIDITY_TOKEN, 200)
+		.issue(CHARLIE, LIQUIDITY_TOKEN, 100)
+		.execute_with_default_mocks(|| {
+			System::set_block_number(1);
+
+			ProofOfStake::update_pool_promotion(RuntimeOrigin::root(), LIQUIDITY_TOKEN, 1u8)
+				.unwrap();
+			ProofOfStake::reward_pool(
+				RuntimeOrigin::signed(ALICE),
+				REWARDED_PAIR,
+				REWARD_TOKEN,
+				REWARD_AMOUNT,
+				10u32.into(),
+			)
+			.unwrap();
+			ProofOfStake::activate_liquidity_for_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				100,
+				None,
+			)
+			.unwrap();
+			for user in [BOB, CHARLIE] {
+				ProofOfStake::activate_liquidity_for_3rdparty_rewards(
+					RuntimeOrigin::signed(user),
+					LIQUIDITY_TOKEN,
+					100,
+					REWARD_TOKEN,
+					None,
+				)
+				.unwrap();
+			}
+
+			assert_ok!(ProofOfStake::set_claim_receipts(RuntimeOrigin::signed(BOB), true));
+			assert_eq!(ProofOfStake::get_claim_receipts(BOB), vec![]);
+
+			roll_to_session::<Test>(2);
+			let native_rewards =
+				ProofOfStake::calculate_native_rewards_amount(BOB, LIQUIDITY_TOKEN).unwrap();
+			assert_ok!(ProofOfStake::claim_native_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN
+			));
+			assert_ok!(ProofOfStake::claim_3rdparty_rewards(
+				RuntimeOrigin::signed(BOB),
+				LIQUIDITY_TOKEN,
+				REWARD_TOKEN
+			));
+			assert_ok!(ProofOfStake::claim_3rdparty_rewards(
+				RuntimeOrigin::signed(CHARLIE),
+				LIQUIDITY_TOKEN,
+				REWARD_TOKEN
+			));
+
+			assert_eq!(
+				ProofOfStake::get_claim_receipts(BOB),
+				vec![
+					ClaimReceipt {
+						session: 2,
+						liquidity_token: LIQUIDITY_TOKEN,
+						reward_token: NATIVE_CURRENCY_ID,
+						amount: native_rewards,
+					},
+					ClaimReceipt {
+						session: 2,
+						liquidity_token: LIQUIDITY_TOKEN,
+						reward_token: REWARD_TOKEN,
+						amount: 500,
+					},
+				]
+			);
+			assert_eq!(ProofOfStake::get_claim_receipts(CHARLIE), vec![]);
+			assert!(!ClaimReceipts::<Test>::contains_key(CHARLIE));
+
+			for session in 3..8 {
+				roll_to_session::<Test>(session);
+				assert_ok!(ProofOfStake::claim_3rdparty_rewards(
+					RuntimeOrigin::signed(BOB),
+					LIQUIDITY_TOKEN,
+					REWARD_TOKEN
+				));
+			}
+
+			let max_receipts: u32 = <<Test as Config>::MaxClaimReceipts as sp_core::Get<_>>::get();
+			let receipts = ProofOfStake::get_claim_receipts(BOB);
+			assert_eq!(receipts.len(), max_receipts as usize);
+			assert_eq!(
+				receipts.iter().map(|receipt| receipt.session).collect::<Vec<_>>(),
+				vec![3, 4, 5, 6, 7]
+			);
+
+			assert_ok!(ProofOfStake::set_claim_receipts(RuntimeOrigin::signed(BOB), false));
+			assert_eq!(ProofOfStake::get_claim_receipts(BOB), vec![]);
+			assert!(!ClaimReceipts::<Test>::contains_key(BOB));
+		});
+}
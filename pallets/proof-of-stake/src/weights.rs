@@ -63,6 +63,8 @@ pub trait WeightInfo {
 	fn deactivate_liquidity_for_3rdparty_rewards() -> Weight;
 	fn activate_liquidity_for_3rdparty_rewards() -> Weight;
 	fn reward_pool() -> Weight;
+	fn set_rewards_destination() -> Weight;
+	fn set_rewards_claimer() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -123,4 +125,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
+	// Storage: ProofOfStake RewardsDestination (r:0 w:1)
+	fn set_rewards_destination() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ProofOfStake RewardsClaimer (r:0 w:1)
+	fn set_rewards_claimer() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
@@ -450,6 +450,12 @@ pub mod pallet {
 			Moment = BlockNumberFor<Self>,
 		>;
 		type AssetMetadataMutation: AssetMetadataMutationTrait<CurrencyIdOf<Self>>;
+		/// Maximum number of intermediate tokens used to valuate a token in native token
+		#[pallet::constant]
+		type MaxValuationPathLength: Get<u32>;
+		/// Worst case weight of the fee lock processing (e.g. fee lock exemption checks) that is
+		/// done for swaps, included in the weight of swap extrinsics
+		type FeeLockWeight: Get<Weight>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -515,6 +521,10 @@ pub mod pallet {
 		/// Trading blocked by maintenance mode
 		TradingBlockedByMaintenanceMode,
 		PoolIsEmpty,
+		/// Valuation path does not lead to the native token through existing pools
+		InvalidValuationPath,
+		/// Valuation path is longer than MaxValuationPathLength
+		ValuationPathTooLong,
 	}
 
 	#[pallet::event]
@@ -566,6 +576,7 @@ pub mod pallet {
 			BalanceOf<T>,
 			ModuleError,
 		),
+		ValuationPathUpdated(CurrencyIdOf<T>, Option<Vec<CurrencyIdOf<T>>>),
 	}
 
 	#[pallet::storage]
@@ -598,6 +609,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Intermediate tokens used to valuate a token that has no pool with the native token. The
+	/// first one is paired with the valuated token and the last one with the native token.
+	#[pallet::storage]
+	#[pallet::getter(fn valuation_path)]
+	pub type ValuationPaths<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		BoundedVec<CurrencyIdOf<T>, T::MaxValuationPathLength>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub created_pools_for_staking: Vec<(
@@ -720,7 +743,7 @@ pub mod pallet {
 		/// - `sold_asset_amount`: The amount of the sold token being sold
 		/// - `min_amount_out` - The minimum amount of bought asset that must be bought in order to not fail on slippage. Slippage failures still charge exchange commission.
 		#[pallet::call_index(1)]
-		#[pallet::weight((<<T as Config>::WeightInfo>::sell_asset(), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((<<T as Config>::WeightInfo>::sell_asset().saturating_add(T::FeeLockWeight::get()), DispatchClass::Operational, Pays::No))]
 		#[deprecated(note = "multiswap_sell_asset should be used instead")]
 		pub fn sell_asset(
 			origin: OriginFor<T>,
@@ -764,7 +787,7 @@ pub mod pallet {
 		/// - `sold_asset_amount`: The amount of the first asset sold
 		/// - `min_amount_out` - The minimum amount of last asset that must be bought in order to not fail on slippage. Slippage failures still charge exchange commission.
 		#[pallet::call_index(2)]
-		#[pallet::weight((<<T as Config>::WeightInfo>::multiswap_sell_asset(swap_token_list.len() as u32), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((<<T as Config>::WeightInfo>::multiswap_sell_asset(swap_token_list.len() as u32).saturating_add(T::FeeLockWeight::get()), DispatchClass::Operational, Pays::No))]
 		pub fn multiswap_sell_asset(
 			origin: OriginFor<T>,
 			swap_token_list: Vec<CurrencyIdOf<T>>,
@@ -821,7 +844,7 @@ pub mod pallet {
 		/// - `bought_asset_amount`: The amount of the bought token being bought
 		/// - `max_amount_in` - The maximum amount of sold asset that must be sold in order to not fail on slippage. Slippage failures still charge exchange commission.
 		#[pallet::call_index(3)]
-		#[pallet::weight((<<T as Config>::WeightInfo>::buy_asset(), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((<<T as Config>::WeightInfo>::buy_asset().saturating_add(T::FeeLockWeight::get()), DispatchClass::Operational, Pays::No))]
 		#[deprecated(note = "multiswap_buy_asset should be used instead")]
 		pub fn buy_asset(
 			origin: OriginFor<T>,
@@ -868,7 +891,7 @@ pub mod pallet {
 		/// - `bought_asset_amount`: The amount of the last asset bought
 		/// - `max_amount_in` - The maximum amount of first asset that can be sold in order to not fail on slippage. Slippage failures still charge exchange commission.
 		#[pallet::call_index(4)]
-		#[pallet::weight((<<T as Config>::WeightInfo>::multiswap_buy_asset(swap_token_list.len() as u32), DispatchClass::Operational, Pays::No))]
+		#[pallet::weight((<<T as Config>::WeightInfo>::multiswap_buy_asset(swap_token_list.len() as u32).saturating_add(T::FeeLockWeight::get()), DispatchClass::Operational, Pays::No))]
 		pub fn multiswap_buy_asset(
 			origin: OriginFor<T>,
 			swap_token_list: Vec<CurrencyIdOf<T>>,
@@ -1126,6 +1149,53 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Sets tokens through which pools `token_id` is valuated in native token when it has no
+		/// direct pool with it, `None` removes the path
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(
+			T::MaxValuationPathLength::get().saturating_add(1).into(),
+			1,
+		))]
+		pub fn set_valuation_path(
+			origin: OriginFor<T>,
+			token_id: CurrencyIdOf<T>,
+			path: Option<Vec<CurrencyIdOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			match path.clone() {
+				Some(path) => {
+					let native_token_id = Self::native_token_id();
+					ensure!(
+						!path.is_empty() &&
+							token_id != native_token_id &&
+							!path.contains(&native_token_id) &&
+							!path.contains(&token_id),
+						Error::<T>::InvalidValuationPath
+					);
+					let bounded_path: BoundedVec<CurrencyIdOf<T>, T::MaxValuationPathLength> =
+						path.clone().try_into().map_err(|_| Error::<T>::ValuationPathTooLong)?;
+
+					let hops = sp_std::iter::once(token_id)
+						.chain(path.into_iter())
+						.chain(sp_std::iter::once(native_token_id))
+						.collect::<Vec<_>>();
+					ensure!(
+						hops.windows(2).all(|w| Pools::<T>::contains_key((w[0], w[1])) ||
+							Pools::<T>::contains_key((w[1], w[0]))),
+						Error::<T>::InvalidValuationPath
+					);
+
+					ValuationPaths::<T>::insert(token_id, bounded_path);
+				},
+				None => ValuationPaths::<T>::remove(token_id),
+			}
+
+			Self::deposit_event(Event::ValuationPathUpdated(token_id, path));
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -1467,6 +1537,100 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Reserves of the native token and `token_id` in their pool or, when there is no such pool,
+	/// virtual reserves composed along the [`ValuationPaths`] of the token. Virtual reserves
+	/// keep the native reserve of the last pool on the path and express the reserve of `token_id`
+	/// in the same scale, so their ratio is the spot price of the token in native token.
+	/// Used by the fee lock and by [`Valuate`] implementation to value tokens without a pool with
+	/// native token, real pool reserves are not affected.
+	pub fn get_valuation_reserves(
+		token_id: CurrencyIdOf<T>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let native_token_id = Self::native_token_id();
+		if let Ok(reserves) = Pallet::<T>::get_reserves(native_token_id, token_id) {
+			return Ok(reserves)
+		}
+
+		let path = ValuationPaths::<T>::get(token_id).ok_or(Error::<T>::NoSuchPool)?;
+		// walk from the native token towards the valuated one
+		let mut hops = path.into_iter().rev().chain(sp_std::iter::once(token_id));
+		let mut prev_token_id = hops.next().ok_or(Error::<T>::NoSuchPool)?;
+		let (native_reserve, mut token_reserve) =
+			Pallet::<T>::get_reserves(native_token_id, prev_token_id)?;
+
+		for next_token_id in hops {
+			let (prev_reserve, next_reserve) =
+				Pallet::<T>::get_reserves(prev_token_id, next_token_id)?;
+			ensure!(!prev_reserve.is_zero(), Error::<T>::DivisionByZero);
+			token_reserve = multiply_by_rational_with_rounding(
+				next_reserve.into(),
+				token_reserve.into(),
+				prev_reserve.into(),
+				Rounding::Down,
+			)
+			.map(SaturatedConversion::saturated_into)
+			.unwrap_or(BalanceOf::<T>::max_value());
+			prev_token_id = next_token_id;
+		}
+
+		Ok((native_reserve, token_reserve))
+	}
+
+	/// Worst case weight of valuating a token in native token through [`Valuate`], that is of
+	/// a liquidity token of a pool without native token whose tokens are valuated through
+	/// valuation paths of maximal length
+	pub fn valuation_weight() -> Weight {
+		// pool reserves with native token, valuation path and reserves of all pools on the path
+		let valuation_reserves_reads =
+			5_u64.saturating_add(2_u64.saturating_mul(T::MaxValuationPathLength::get().into()));
+		// liquidity token check, pooled tokens, pool reserves and liquidity token issuance
+		T::DbWeight::get().reads(5_u64.saturating_add(2 * valuation_reserves_reads))
+	}
+
+	/// Reserve of the native token in the pool of `liquidity_token_id` and total issuance of the
+	/// liquidity token. For pools without native token the reserve of one of the pooled tokens
+	/// is expressed in native token using [`Pallet::get_valuation_reserves`].
+	fn get_liquidity_token_valuation_state(
+		liquidity_token_id: CurrencyIdOf<T>,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		let (first_token_id, second_token_id) = LiquidityPools::<T>::get(liquidity_token_id)?;
+		let (first_reserve, second_reserve) =
+			Pallet::<T>::get_reserves(first_token_id, second_token_id).ok()?;
+
+		let native_token_id = Self::native_token_id();
+		let mga_token_reserve = if first_token_id == native_token_id {
+			first_reserve
+		} else if second_token_id == native_token_id {
+			second_reserve
+		} else {
+			[(first_token_id, first_reserve), (second_token_id, second_reserve)]
+				.iter()
+				.find_map(|&(token_id, token_reserve)| {
+					let (native_reserve, token_valuation_reserve) =
+						Pallet::<T>::get_valuation_reserves(token_id).ok()?;
+					if token_valuation_reserve.is_zero() {
+						return None
+					}
+					multiply_by_rational_with_rounding(
+						token_reserve.into(),
+						native_reserve.into(),
+						token_valuation_reserve.into(),
+						Rounding::Down,
+					)
+					.map(SaturatedConversion::saturated_into)
+				})?
+		};
+
+		let liquidity_token_reserve: BalanceOf<T> =
+			<T as Config>::Currency::total_issuance(liquidity_token_id.into());
+
+		if liquidity_token_reserve.is_zero() {
+			return None
+		}
+
+		Some((mga_token_reserve, liquidity_token_reserve))
+	}
+
 	/// worst case scenario
 	/// MAX: 2R 1W
 	pub fn set_reserves(
@@ -3538,24 +3702,12 @@ impl<T: Config> Valuate<BalanceOf<T>, CurrencyIdOf<T>> for Pallet<T> {
 		liquidity_token_id: CurrencyIdOf<T>,
 		liquidity_token_amount: BalanceOf<T>,
 	) -> BalanceOf<T> {
-		let (mga_token_id, other_token_id) =
-			match Self::get_liquidity_token_mga_pool(liquidity_token_id) {
-				Ok(pool) => pool,
-				Err(_) => return Default::default(),
+		let (mga_token_reserve, liquidity_token_reserve) =
+			match Self::get_liquidity_token_valuation_state(liquidity_token_id) {
+				Some(state) => state,
+				None => return Default::default(),
 			};
 
-		let mga_token_reserve = match Pallet::<T>::get_reserves(mga_token_id, other_token_id) {
-			Ok(reserves) => reserves.0,
-			Err(_) => return Default::default(),
-		};
-
-		let liquidity_token_reserve: BalanceOf<T> =
-			<T as Config>::Currency::total_issuance(liquidity_token_id.into());
-
-		if liquidity_token_reserve.is_zero() {
-			return Default::default()
-		}
-
 		multiply_by_rational_with_rounding(
 			mga_token_reserve.into(),
 			liquidity_token_amount.into(),
@@ -3570,10 +3722,9 @@ impl<T: Config> Valuate<BalanceOf<T>, CurrencyIdOf<T>> for Pallet<T> {
 		non_liquidity_token_id: CurrencyIdOf<T>,
 		amount: BalanceOf<T>,
 	) -> BalanceOf<T> {
-		let native_token_id = Pallet::<T>::native_token_id();
-
+		// falls back to the valuation path when there is no pool with native token
 		let (native_reserves, token_reserves) =
-			match Pallet::<T>::get_reserves(native_token_id, non_liquidity_token_id) {
+			match Pallet::<T>::get_valuation_reserves(non_liquidity_token_id) {
 				Ok(reserves) => reserves,
 				Err(_) => return Default::default(),
 			};
@@ -3601,25 +3752,7 @@ impl<T: Config> Valuate<BalanceOf<T>, CurrencyIdOf<T>> for Pallet<T> {
 	}
 
 	fn get_pool_state(liquidity_token_id: CurrencyIdOf<T>) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
-		let (mga_token_id, other_token_id) =
-			match Self::get_liquidity_token_mga_pool(liquidity_token_id) {
-				Ok(pool) => pool,
-				Err(_) => return None,
-			};
-
-		let mga_token_reserve = match Pallet::<T>::get_reserves(mga_token_id, other_token_id) {
-			Ok(reserves) => reserves.0,
-			Err(_) => return None,
-		};
-
-		let liquidity_token_reserve: BalanceOf<T> =
-			<T as Config>::Currency::total_issuance(liquidity_token_id.into());
-
-		if liquidity_token_reserve.is_zero() {
-			return None
-		}
-
-		Some((mga_token_reserve, liquidity_token_reserve))
+		Self::get_liquidity_token_valuation_state(liquidity_token_id)
 	}
 
 	fn get_reserves(
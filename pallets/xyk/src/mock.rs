@@ -291,6 +291,8 @@ impl Config for Test {
 	type DisallowedPools = DummyBlacklistedPool;
 	type DisabledTokens = Nothing;
 	type AssetMetadataMutation = MockAssetRegister;
+	type MaxValuationPathLength = ConstU32<3>;
+	type FeeLockWeight = ();
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -311,38 +313,50 @@ impl Config for Test {
 	type DisallowedPools = Nothing;
 	type DisabledTokens = Nothing;
 	type AssetMetadataMutation = MockAssetRegister;
+	type MaxValuationPathLength = ConstU32<3>;
+	type FeeLockWeight = ();
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
 impl pallet_proof_of_stake::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = TokensActivationPassthrough<Test>;
+	type ThirdPartyActivationReservesProvider = ();
 	type NativeCurrencyId = NativeCurrencyId;
 	type Currency = MultiTokenCurrencyAdapter<Test>;
 	type LiquidityMiningIssuanceVault = FakeLiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = ConstU32<10>;
 	type WeightInfo = ();
 	type RewardsSchedulesLimit = ConstU32<10>;
+	type MaxRewardTokensPerPool = ConstU32<10>;
+	type MaxSchedulesChangesPerSession = ConstU32<10>;
+	type MaxActivatedPoolsPerUser = ConstU32<10>;
 	type Min3rdPartyRewardValutationPerSession = ConstU128<10>;
 	type Min3rdPartyRewardVolume = ConstU128<10>;
+	type RewardsHistoryLength = ConstU32<10>;
+	type MaxClaimReceipts = ConstU32<5>;
 	type ValuationApi = XykStorage;
-	type SchedulesPerBlock = ConstU32<5>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
 impl pallet_proof_of_stake::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = TokensActivationPassthrough<Test>;
+	type ThirdPartyActivationReservesProvider = ();
 	type NativeCurrencyId = NativeCurrencyId;
 	type Currency = MultiTokenCurrencyAdapter<Test>;
 	type LiquidityMiningIssuanceVault = FakeLiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = ConstU32<1200>;
 	type WeightInfo = ();
 	type RewardsSchedulesLimit = ConstU32<10>;
+	type MaxRewardTokensPerPool = ConstU32<10>;
+	type MaxSchedulesChangesPerSession = ConstU32<10>;
+	type MaxActivatedPoolsPerUser = ConstU32<10>;
 	type Min3rdPartyRewardValutationPerSession = ConstU128<10>;
 	type Min3rdPartyRewardVolume = ConstU128<10>;
+	type RewardsHistoryLength = ConstU32<10>;
+	type MaxClaimReceipts = ConstU32<5>;
 	type ValuationApi = XykStorage;
-	type SchedulesPerBlock = ConstU32<5>;
 }
 
 pub struct TokensActivationPassthrough<T: Config>(PhantomData<T>);
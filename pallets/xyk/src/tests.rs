@@ -2655,3 +2655,132 @@ fn valuate_token_paired_with_mgx() {
 		);
 	});
 }
+
+#[test]
+#[serial]
+fn valuate_token_through_valuation_path() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let native_token_id = XykStorage::create_new_token(&DUMMY_USER_ID, 2_000_000_u128);
+		assert_eq!(native_token_id, XykStorage::native_token_id());
+
+		let hub_token = XykStorage::create_new_token(&DUMMY_USER_ID, 2_000_000_u128);
+		let token = XykStorage::create_new_token(&DUMMY_USER_ID, 4_000_000_u128);
+
+		XykStorage::create_pool(
+			RuntimeOrigin::signed(DUMMY_USER_ID),
+			native_token_id,
+			1_000_000_u128,
+			hub_token,
+			500_000_u128,
+		)
+		.unwrap();
+
+		XykStorage::create_pool(
+			RuntimeOrigin::signed(DUMMY_USER_ID),
+			hub_token,
+			1_000_000_u128,
+			token,
+			4_000_000_u128,
+		)
+		.unwrap();
+
+		assert_eq!(<Pallet<Test> as Valuate<_, _>>::valuate_non_liquidity_token(token, 100), 0);
+		assert!(<Pallet<Test> as Valuate<_, _>>::get_reserves(native_token_id, token).is_err());
+
+		assert_err_ignore_postinfo!(
+			XykStorage::set_valuation_path(
+				RuntimeOrigin::signed(DUMMY_USER_ID),
+				token,
+				Some(vec![hub_token])
+			),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert_err_ignore_postinfo!(
+			XykStorage::set_valuation_path(RuntimeOrigin::root(), hub_token, Some(vec![token])),
+			Error::<Test>::InvalidValuationPath,
+		);
+		assert_err_ignore_postinfo!(
+			XykStorage::set_valuation_path(
+				RuntimeOrigin::root(),
+				token,
+				Some(vec![hub_token, 10, 11, 12])
+			),
+			Error::<Test>::ValuationPathTooLong,
+		);
+
+		assert_ok!(XykStorage::set_valuation_path(
+			RuntimeOrigin::root(),
+			token,
+			Some(vec![hub_token])
+		));
+		System::assert_last_event(RuntimeEvent::XykStorage(Event::ValuationPathUpdated(
+			token,
+			Some(vec![hub_token]),
+		)));
+
+		// 4 tokens are worth a single hub token which is worth 2 native tokens
+		assert_eq!(XykStorage::get_valuation_reserves(token), Ok((1_000_000, 2_000_000)));
+
+		assert_eq!(<Pallet<Test> as Valuate<_, _>>::valuate_non_liquidity_token(token, 100), 49);
+
+		// real pool reserves exposed through Valuate are not affected
+		assert!(XykStorage::get_reserves(native_token_id, token).is_err());
+		assert!(<Pallet<Test> as Valuate<_, _>>::get_reserves(native_token_id, token).is_err());
+
+		assert_ok!(XykStorage::set_valuation_path(RuntimeOrigin::root(), token, None));
+		assert!(XykStorage::get_valuation_reserves(token).is_err());
+		assert_eq!(<Pallet<Test> as Valuate<_, _>>::valuate_non_liquidity_token(token, 100), 0);
+	});
+}
+
+#[test]
+#[serial]
+fn valuate_tokens_through_ksm_hop() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let native_token_id = XykStorage::create_new_token(&DUMMY_USER_ID, 2_000_000_u128);
+		assert_eq!(native_token_id, XykStorage::native_token_id());
+
+		let ksm_token = XykStorage::create_new_token(&DUMMY_USER_ID, 2_000_000_u128);
+		let token = XykStorage::create_new_token(&DUMMY_USER_ID, 4_000_000_u128);
+
+		XykStorage::create_pool(
+			RuntimeOrigin::signed(DUMMY_USER_ID),
+			native_token_id,
+			1_000_000_u128,
+			ksm_token,
+			500_000_u128,
+		)
+		.unwrap();
+		XykStorage::create_pool(
+			RuntimeOrigin::signed(DUMMY_USER_ID),
+			ksm_token,
+			1_000_000_u128,
+			token,
+			4_000_000_u128,
+		)
+		.unwrap();
+		let liquidity_token = XykStorage::get_liquidity_asset(ksm_token, token).unwrap();
+		assert_eq!(XykStorage::total_supply(liquidity_token), 2_500_000);
+
+		assert_ok!(XykStorage::set_valuation_path(
+			RuntimeOrigin::root(),
+			token,
+			Some(vec![ksm_token])
+		));
+
+		// 4 tokens are worth a single KSM which is worth 2 native tokens
+		assert_eq!(<Pallet<Test> as Valuate<_, _>>::valuate_non_liquidity_token(token, 100), 49);
+
+		// KSM reserve of the pool (1_000_000) is worth 2_000_000 native tokens
+		assert_eq!(
+			<Pallet<Test> as Valuate<_, _>>::valuate_liquidity_token(liquidity_token, 250_000),
+			200_000
+		);
+		assert_eq!(
+			<Pallet<Test> as Valuate<_, _>>::get_pool_state(liquidity_token),
+			Some((2_000_000, 2_500_000))
+		);
+	});
+}
@@ -25,7 +25,7 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
 };
-use mangata_support::traits::{AssetRegistryApi, FeeLockTriggerTrait, PreValidateSwaps};
+use mangata_support::traits::{AssetRegistryApi, FeeLockTriggerTrait, PreValidateSwaps, Valuate};
 pub use mangata_types::assets::{CustomMetadata, XcmMetadata, XykMetadata};
 pub use orml_tokens;
 use orml_tokens::MultiTokenCurrencyExtended;
@@ -343,6 +343,7 @@ pub mod config {
 		pub type PoolFeePercentage = frame_support::traits::ConstU128<20>;
 		pub type TreasuryFeePercentage = frame_support::traits::ConstU128<5>;
 		pub type BuyAndBurnFeePercentage = frame_support::traits::ConstU128<5>;
+		pub type MaxValuationPathLength = frame_support::traits::ConstU32<3>;
 
 		pub struct TestTokensFilter;
 		impl Contains<TokenId> for TestTokensFilter {
@@ -411,6 +412,8 @@ pub mod config {
 			pub const BootstrapUpdateBuffer: BlockNumber = 300;
 			pub const DefaultBootstrapPromotedPoolWeight: u8 = 0u8;
 			pub const ClearStorageLimit: u32 = 100u32;
+			pub const MaxWhitelistProofLength: u32 = 32u32;
+			pub const MaxConcurrentBootstraps: u32 = 10u32;
 		}
 
 		pub struct EnableAssetPoolApi<Runtime>(PhantomData<Runtime>);
@@ -450,6 +453,96 @@ pub mod config {
 				true
 			}
 		}
+
+		pub struct ReserveVestedLiquidityApi<Runtime>(PhantomData<Runtime>);
+		impl<T> pallet_bootstrap::VestedLiquidityReserveApi<T::AccountId, Balance, TokenId>
+			for ReserveVestedLiquidityApi<T>
+		where
+			T: ::pallet_multipurpose_liquidity::Config<
+				Tokens = ::orml_tokens::MultiTokenCurrencyAdapter<T>,
+			>,
+			T: ::orml_tokens::Config<Balance = Balance, CurrencyId = TokenId>,
+		{
+			fn reserve_vested_liquidity(
+				who: &T::AccountId,
+				liquidity_token_id: TokenId,
+				amount: Balance,
+			) -> DispatchResult {
+				::pallet_multipurpose_liquidity::Pallet::<T>::do_reserve_vesting_liquidity_tokens(
+					who.clone(),
+					liquidity_token_id,
+					amount,
+				)
+			}
+		}
+
+		/// Values tokens in native currency using reserves of their pools paired with it
+		pub struct NativeValuationPriceSource<Valuator, NativeTokenId, ValuationWeight>(
+			PhantomData<(Valuator, NativeTokenId, ValuationWeight)>,
+		);
+		impl<V, N, W> pallet_bootstrap::ProvisionPriceSource<TokenId, Balance>
+			for NativeValuationPriceSource<V, N, W>
+		where
+			V: Valuate<Balance, TokenId>,
+			N: Get<TokenId>,
+			W: Get<Weight>,
+		{
+			fn valuate(token_id: TokenId, amount: Balance) -> Option<Balance> {
+				if token_id == N::get() {
+					return Some(amount)
+				}
+				let valuation = V::valuate_non_liquidity_token(token_id, amount);
+				if valuation == 0 {
+					None
+				} else {
+					Some(valuation)
+				}
+			}
+
+			fn valuate_weight() -> Weight {
+				W::get()
+			}
+		}
+
+		/// Worst case weight of valuations done by [`pallet_xyk`]
+		pub struct XykValuationWeight<T>(PhantomData<T>);
+		impl<T: ::pallet_xyk::Config> Get<Weight> for XykValuationWeight<T> {
+			fn get() -> Weight {
+				::pallet_xyk::Pallet::<T>::valuation_weight()
+			}
+		}
+
+		pub struct ProofOfStakeThirdPartyRewardsApi<Runtime>(PhantomData<Runtime>);
+		impl<T> pallet_bootstrap::ThirdPartyRewardsApi<T::AccountId, Balance, TokenId>
+			for ProofOfStakeThirdPartyRewardsApi<T>
+		where
+			T: ::pallet_proof_of_stake::Config<
+				Currency = ::orml_tokens::MultiTokenCurrencyAdapter<T>,
+			>,
+			T: ::orml_tokens::Config<Balance = Balance, CurrencyId = TokenId>,
+		{
+			fn reward_pool(
+				sender: T::AccountId,
+				pool: (TokenId, TokenId),
+				token_id: TokenId,
+				amount: Balance,
+				sessions: u32,
+			) -> DispatchResult {
+				let schedule_end =
+					::pallet_proof_of_stake::Pallet::<T>::session_index().saturating_add(sessions);
+				::pallet_proof_of_stake::Pallet::<T>::reward_pool(
+					frame_system::RawOrigin::Signed(sender).into(),
+					pool,
+					token_id,
+					amount,
+					schedule_end,
+				)
+			}
+
+			fn reward_pool_weight() -> Weight {
+				<<T as ::pallet_proof_of_stake::Config>::WeightInfo as ::pallet_proof_of_stake::WeightInfo>::reward_pool()
+			}
+		}
 	}
 
 	pub mod pallet_transaction_payment_mangata {
@@ -557,6 +650,25 @@ pub mod config {
 				bought_asset_id: TokenId,
 				min_amount_out: Balance,
 			) -> Result<Option<LiquidityInfoEnum<C, T>>, TransactionValidityError> {
+				let action = Self::sell_asset_fee_lock_action(
+					who,
+					&fee_lock_metadata,
+					sold_asset_id,
+					sold_asset_amount,
+					bought_asset_id,
+					min_amount_out,
+				)?;
+				Self::apply_fee_lock_action(who, action)
+			}
+
+			pub fn sell_asset_fee_lock_action(
+				who: &T::AccountId,
+				fee_lock_metadata: &pallet_fee_lock::FeeLockMetadataInfo<T>,
+				sold_asset_id: TokenId,
+				sold_asset_amount: Balance,
+				bought_asset_id: TokenId,
+				min_amount_out: Balance,
+			) -> Result<FeeLockAction, TransactionValidityError> {
 				if fee_lock_metadata.is_whitelisted(sold_asset_id) ||
 					fee_lock_metadata.is_whitelisted(bought_asset_id)
 				{
@@ -577,29 +689,16 @@ pub mod config {
 								InvalidTransaction::SwapPrevalidation.into(),
 							)
 						})?;
-					if Self::is_high_value_swap(
-						&fee_lock_metadata,
-						sold_asset_id,
-						sold_asset_amount,
-					) || Self::is_high_value_swap(
-						&fee_lock_metadata,
-						bought_asset_id,
-						bought_asset_amount,
-					) {
-						let _ = OFLA::unlock_fee(who);
-					} else {
-						OFLA::process_fee_lock(who).map_err(|_| {
-							TransactionValidityError::Invalid(
-								InvalidTransaction::ProcessFeeLock.into(),
-							)
-						})?;
+					if Self::is_high_value_swap(fee_lock_metadata, sold_asset_id, sold_asset_amount) ||
+						Self::is_high_value_swap(
+							fee_lock_metadata,
+							bought_asset_id,
+							bought_asset_amount,
+						) {
+						return Ok(FeeLockAction::Unlock)
 					}
-				} else {
-					OFLA::process_fee_lock(who).map_err(|_| {
-						TransactionValidityError::Invalid(InvalidTransaction::ProcessFeeLock.into())
-					})?;
 				}
-				Ok(Some(LiquidityInfoEnum::FeeLock))
+				Ok(FeeLockAction::Lock)
 			}
 
 			pub fn is_high_value_swap(
@@ -607,11 +706,11 @@ pub mod config {
 				asset_id: u32,
 				asset_amount: u128,
 			) -> bool {
-				if let (true, Some(valuation)) = (
-					fee_lock_metadata.is_whitelisted(asset_id),
+				if let (Some(threshold), Some(valuation)) = (
+					fee_lock_metadata.swap_value_threshold_for(asset_id),
 					OFLA::get_swap_valuation_for_token(asset_id, asset_amount),
 				) {
-					valuation >= fee_lock_metadata.swap_value_threshold
+					valuation >= threshold
 				} else {
 					false
 				}
@@ -625,6 +724,25 @@ pub mod config {
 				bought_asset_id: TokenId,
 				max_amount_in: Balance,
 			) -> Result<Option<LiquidityInfoEnum<C, T>>, TransactionValidityError> {
+				let action = Self::buy_asset_fee_lock_action(
+					who,
+					&fee_lock_metadata,
+					sold_asset_id,
+					bought_asset_amount,
+					bought_asset_id,
+					max_amount_in,
+				)?;
+				Self::apply_fee_lock_action(who, action)
+			}
+
+			pub fn buy_asset_fee_lock_action(
+				who: &T::AccountId,
+				fee_lock_metadata: &pallet_fee_lock::FeeLockMetadataInfo<T>,
+				sold_asset_id: TokenId,
+				bought_asset_amount: Balance,
+				bought_asset_id: TokenId,
+				max_amount_in: Balance,
+			) -> Result<FeeLockAction, TransactionValidityError> {
 				if fee_lock_metadata.is_whitelisted(sold_asset_id) ||
 					fee_lock_metadata.is_whitelisted(bought_asset_id)
 				{
@@ -645,30 +763,17 @@ pub mod config {
 								InvalidTransaction::SwapPrevalidation.into(),
 							)
 						})?;
-					if Self::is_high_value_swap(
-						&fee_lock_metadata,
-						sold_asset_id,
-						sold_asset_amount,
-					) || Self::is_high_value_swap(
-						&fee_lock_metadata,
-						bought_asset_id,
-						bought_asset_amount,
-					) {
-						let _ = OFLA::unlock_fee(who);
-					} else {
-						OFLA::process_fee_lock(who).map_err(|_| {
-							TransactionValidityError::Invalid(
-								InvalidTransaction::ProcessFeeLock.into(),
-							)
-						})?;
+					if Self::is_high_value_swap(fee_lock_metadata, sold_asset_id, sold_asset_amount) ||
+						Self::is_high_value_swap(
+							fee_lock_metadata,
+							bought_asset_id,
+							bought_asset_amount,
+						) {
+						return Ok(FeeLockAction::Unlock)
 					}
-				} else {
-					// "swap on non-curated token" branch
-					OFLA::process_fee_lock(who).map_err(|_| {
-						TransactionValidityError::Invalid(InvalidTransaction::ProcessFeeLock.into())
-					})?;
 				}
-				Ok(Some(LiquidityInfoEnum::FeeLock))
+				// "swap on non-curated token" branch
+				Ok(FeeLockAction::Lock)
 			}
 
 			pub fn handle_multiswap_buy_asset(
@@ -678,6 +783,21 @@ pub mod config {
 				bought_asset_amount: Balance,
 				max_amount_in: Balance,
 			) -> Result<Option<LiquidityInfoEnum<C, T>>, TransactionValidityError> {
+				let action = Self::multiswap_buy_asset_fee_lock_action(
+					who,
+					swap_token_list,
+					bought_asset_amount,
+					max_amount_in,
+				)?;
+				Self::apply_fee_lock_action(who, action)
+			}
+
+			pub fn multiswap_buy_asset_fee_lock_action(
+				who: &T::AccountId,
+				swap_token_list: Vec<TokenId>,
+				bought_asset_amount: Balance,
+				max_amount_in: Balance,
+			) -> Result<FeeLockAction, TransactionValidityError> {
 				// ensure swap cannot fail
 				// This is to ensure that xyk swap fee is always charged
 				// We also ensure that the user has enough funds to transact
@@ -696,10 +816,7 @@ pub mod config {
 				})?;
 
 				// This is the "low value swap on curated token" branch
-				OFLA::process_fee_lock(who).map_err(|_| {
-					TransactionValidityError::Invalid(InvalidTransaction::ProcessFeeLock.into())
-				})?;
-				Ok(Some(LiquidityInfoEnum::FeeLock))
+				Ok(FeeLockAction::Lock)
 			}
 
 			pub fn handle_multiswap_sell_asset(
@@ -709,6 +826,21 @@ pub mod config {
 				sold_asset_amount: Balance,
 				min_amount_out: Balance,
 			) -> Result<Option<LiquidityInfoEnum<C, T>>, TransactionValidityError> {
+				let action = Self::multiswap_sell_asset_fee_lock_action(
+					who,
+					swap_token_list,
+					sold_asset_amount,
+					min_amount_out,
+				)?;
+				Self::apply_fee_lock_action(who, action)
+			}
+
+			pub fn multiswap_sell_asset_fee_lock_action(
+				who: &<T>::AccountId,
+				swap_token_list: Vec<TokenId>,
+				sold_asset_amount: Balance,
+				min_amount_out: Balance,
+			) -> Result<FeeLockAction, TransactionValidityError> {
 				// ensure swap cannot fail
 				// This is to ensure that xyk swap fee is always charged
 				// We also ensure that the user has enough funds to transact
@@ -727,11 +859,141 @@ pub mod config {
 				})?;
 
 				// This is the "low value swap on curated token" branch
-				OFLA::process_fee_lock(who).map_err(|_| {
-					TransactionValidityError::Invalid(InvalidTransaction::ProcessFeeLock.into())
-				})?;
+				Ok(FeeLockAction::Lock)
+			}
+
+			fn apply_fee_lock_action(
+				who: &T::AccountId,
+				action: FeeLockAction,
+			) -> Result<Option<LiquidityInfoEnum<C, T>>, TransactionValidityError> {
+				match action {
+					FeeLockAction::Unlock => {
+						let _ = OFLA::unlock_fee(who);
+					},
+					FeeLockAction::Lock => {
+						OFLA::process_fee_lock(who).map_err(|_| {
+							TransactionValidityError::Invalid(
+								InvalidTransaction::ProcessFeeLock.into(),
+							)
+						})?;
+					},
+				}
 				Ok(Some(LiquidityInfoEnum::FeeLock))
 			}
+
+			/// Previews what `sell_asset` (path of 2 tokens) or `multiswap_sell_asset` would do
+			/// to the fee lock of `who`, mirroring `OnChargeHandler::withdraw_fee`
+			pub fn preview_sell_asset(
+				who: &T::AccountId,
+				path: Vec<TokenId>,
+				sold_asset_amount: Balance,
+				min_amount_out: Balance,
+			) -> FeeLockPreview<Balance, TokenId> {
+				let action = match (path.len(), pallet_fee_lock::FeeLockMetadata::<T>::get()) {
+					(_, None) => return FeeLockPreview::FeeCharged,
+					(length, _) if length < SINGLE_HOP_MULTISWAP =>
+						Err(TransactionValidityError::Invalid(
+							InvalidTransaction::SwapPrevalidation.into(),
+						)),
+					(SINGLE_HOP_MULTISWAP, Some(fee_lock_metadata)) =>
+						Self::sell_asset_fee_lock_action(
+							who,
+							&fee_lock_metadata,
+							path[0],
+							sold_asset_amount,
+							path[1],
+							min_amount_out,
+						),
+					(_, Some(_)) => Self::multiswap_sell_asset_fee_lock_action(
+						who,
+						path,
+						sold_asset_amount,
+						min_amount_out,
+					),
+				};
+				Self::preview_fee_lock_action(who, action)
+			}
+
+			/// Previews what `buy_asset` (path of 2 tokens) or `multiswap_buy_asset` would do to
+			/// the fee lock of `who`, mirroring `OnChargeHandler::withdraw_fee`
+			pub fn preview_buy_asset(
+				who: &T::AccountId,
+				path: Vec<TokenId>,
+				bought_asset_amount: Balance,
+				max_amount_in: Balance,
+			) -> FeeLockPreview<Balance, TokenId> {
+				let action = match (path.len(), pallet_fee_lock::FeeLockMetadata::<T>::get()) {
+					(_, None) => return FeeLockPreview::FeeCharged,
+					(length, _) if length < SINGLE_HOP_MULTISWAP =>
+						Err(TransactionValidityError::Invalid(
+							InvalidTransaction::SwapPrevalidation.into(),
+						)),
+					(SINGLE_HOP_MULTISWAP, Some(fee_lock_metadata)) =>
+						Self::buy_asset_fee_lock_action(
+							who,
+							&fee_lock_metadata,
+							path[0],
+							bought_asset_amount,
+							path[1],
+							max_amount_in,
+						),
+					(_, Some(_)) => Self::multiswap_buy_asset_fee_lock_action(
+						who,
+						path,
+						bought_asset_amount,
+						max_amount_in,
+					),
+				};
+				Self::preview_fee_lock_action(who, action)
+			}
+
+			fn preview_fee_lock_action(
+				who: &T::AccountId,
+				action: Result<FeeLockAction, TransactionValidityError>,
+			) -> FeeLockPreview<Balance, TokenId> {
+				match action {
+					Ok(FeeLockAction::Lock) => pallet_fee_lock::Pallet::<T>::preview_fee_lock(who)
+						.map(|(lock_token, lock_amount, total_locked)| FeeLockPreview::Lock {
+							lock_token,
+							lock_amount,
+							total_locked,
+						})
+						.unwrap_or(FeeLockPreview::Rejected),
+					Ok(FeeLockAction::Unlock) => FeeLockPreview::Unlock {
+						lock_token: pallet_fee_lock::Pallet::<T>::get_account_fee_lock_data(who)
+							.lock_token,
+						unlocked_amount: pallet_fee_lock::Pallet::<T>::preview_unlock_fee(who),
+					},
+					Err(_) => FeeLockPreview::Rejected,
+				}
+			}
+		}
+
+		/// Effect of a swap on the fee lock of the swapping account
+		#[derive(Eq, PartialEq, Clone, Copy, RuntimeDebug)]
+		pub enum FeeLockAction {
+			/// Low value swap, native (or curated) tokens are locked
+			Lock,
+			/// High value swap, previous lock is unlocked if its period has passed
+			Unlock,
+		}
+
+		/// Outcome of a swap transaction fee handling previewed by [`FeeHelpers`]
+		#[derive(Eq, PartialEq, Clone, RuntimeDebug)]
+		pub enum FeeLockPreview<Balance, TokenId> {
+			/// Fee locks are not initialized, regular transaction fee is charged
+			FeeCharged,
+			Lock {
+				lock_token: TokenId,
+				lock_amount: Balance,
+				total_locked: Balance,
+			},
+			Unlock {
+				lock_token: TokenId,
+				unlocked_amount: Balance,
+			},
+			/// Transaction would be rejected by the fee handler
+			Rejected,
 		}
 
 		const SINGLE_HOP_MULTISWAP: usize = 2;
@@ -1130,6 +1392,81 @@ where
 		use crate::*;
 		parameter_types! {
 			pub const MaxCuratedTokens: u32 = 100;
+			pub const MaxFeeLockTiers: u32 = 10;
+			pub const MinUnlocksPerBlock: u32 = 10;
+		}
+
+		/// Valuates tokens through their pool with the native token or, when there is none,
+		/// through their xyk valuation path
+		pub struct XykValuationReserves<T>(PhantomData<T>);
+		impl<T> ::pallet_fee_lock::ValuationReservesProvider<TokenId, Balance> for XykValuationReserves<T>
+		where
+			T: ::pallet_xyk::Config<Currency = ::orml_tokens::MultiTokenCurrencyAdapter<T>>,
+			T: ::orml_tokens::Config<Balance = Balance, CurrencyId = TokenId>,
+		{
+			fn get_valuation_reserves(
+				token_id: TokenId,
+			) -> Result<(Balance, Balance), DispatchError> {
+				::pallet_xyk::Pallet::<T>::get_valuation_reserves(token_id)
+			}
+		}
+
+		/// Counts native rewards activations towards the fee lock exemption
+		pub struct ActivatedLiquidityExemption<T>(PhantomData<T>);
+		impl<T> ::pallet_fee_lock::FeeLockExemptionProvider<T::AccountId, Balance>
+			for ActivatedLiquidityExemption<T>
+		where
+			T: ::pallet_proof_of_stake::Config + ::pallet_xyk::Config,
+			<<T as ::pallet_proof_of_stake::Config>::Currency as MultiTokenCurrency<
+				T::AccountId,
+			>>::Balance: Into<Balance>,
+		{
+			fn positions_value(who: &T::AccountId) -> Balance {
+				::pallet_proof_of_stake::Pallet::<T>::get_activated_liquidity_value(who).into()
+			}
+
+			fn positions_value_weight() -> Weight {
+				// activated amount and valuation of every pool with activated liquidity
+				let pool_weight = T::DbWeight::get()
+					.reads(1)
+					.saturating_add(::pallet_xyk::Pallet::<T>::valuation_weight());
+				let max_pools: u32 =
+					<T as ::pallet_proof_of_stake::Config>::MaxActivatedPoolsPerUser::get();
+				T::DbWeight::get()
+					.reads(1)
+					.saturating_add(pool_weight.saturating_mul(max_pools.into()))
+			}
+		}
+
+		/// Counts collator and delegator bonds towards the fee lock exemption
+		pub struct StakingBondExemption<T>(PhantomData<T>);
+		impl<T> ::pallet_fee_lock::FeeLockExemptionProvider<T::AccountId, Balance>
+			for StakingBondExemption<T>
+		where
+			T: ::parachain_staking::Config + ::pallet_xyk::Config,
+			::parachain_staking::BalanceOf<T>: Into<Balance>,
+		{
+			fn positions_value(who: &T::AccountId) -> Balance {
+				::parachain_staking::Pallet::<T>::get_bonded_value(who).into()
+			}
+
+			fn positions_value_weight() -> Weight {
+				// candidate and delegator state, valuation of self bond and every delegation
+				let max_bonds: u32 =
+					<T as ::parachain_staking::Config>::MaxDelegationsPerDelegator::get()
+						.saturating_add(1);
+				T::DbWeight::get().reads(2).saturating_add(
+					::pallet_xyk::Pallet::<T>::valuation_weight().saturating_mul(max_bonds.into()),
+				)
+			}
+		}
+
+		/// Weight of the fee lock exemption checks done for swaps
+		pub struct FeeLockExemptionWeight<T>(PhantomData<T>);
+		impl<T: ::pallet_fee_lock::Config> Get<Weight> for FeeLockExemptionWeight<T> {
+			fn get() -> Weight {
+				::pallet_fee_lock::Pallet::<T>::exemption_weight()
+			}
 		}
 	}
 
@@ -1477,10 +1814,85 @@ where
 
 		parameter_types! {
 			pub const RewardsSchedulesLimit: u32 = 10_000u32;
+			pub const MaxRewardTokensPerPool: u32 = 10u32;
+			pub const MaxSchedulesChangesPerSession: u32 = 100u32;
+			pub const MaxActivatedPoolsPerUser: u32 = 50u32;
 			// NOTE: 1725 is how much USDT you get for one MGX as of 12.2023
 			pub const Min3rdPartyRewardValutationPerSession: u128 = 10 * 1725 * currency::DOLLARS;
 			pub const Min3rdPartyRewardVolume: u128 = 10_000 * 1725 * currency::DOLLARS;
-			pub const SchedulesPerBlock: u32 = 5;
+			// NOTE: ~30 days of sessions
+			pub const RewardsHistoryLength: u32 = 180;
+			pub const MaxClaimReceipts: u32 = 100;
+		}
+
+		/// Keeps liquidity activated for 3rdparty rewards in the dedicated bucket of
+		/// multipurpose liquidity reserves
+		pub struct MplThirdPartyActivationReserves<Runtime>(PhantomData<Runtime>);
+		impl<T>
+			pallet_proof_of_stake::ThirdPartyActivationReservesProviderTrait<
+				T::AccountId,
+				Balance,
+				TokenId,
+			> for MplThirdPartyActivationReserves<T>
+		where
+			T: ::pallet_multipurpose_liquidity::Config<
+				Tokens = ::orml_tokens::MultiTokenCurrencyAdapter<T>,
+			>,
+			T: ::orml_tokens::Config<Balance = Balance, CurrencyId = TokenId>,
+		{
+			fn can_activate_for_3rdparty_rewards(
+				token_id: TokenId,
+				account_id: &T::AccountId,
+				amount: Balance,
+				use_balance_from: Option<mangata_types::multipurpose_liquidity::ActivateKind>,
+			) -> bool {
+				::pallet_multipurpose_liquidity::Pallet::<T>::can_activate_for_3rdparty_rewards(
+					token_id,
+					account_id,
+					amount,
+					use_balance_from,
+				)
+			}
+
+			fn activate_for_3rdparty_rewards(
+				token_id: TokenId,
+				account_id: &T::AccountId,
+				amount: Balance,
+				use_balance_from: Option<mangata_types::multipurpose_liquidity::ActivateKind>,
+			) -> DispatchResult {
+				::pallet_multipurpose_liquidity::Pallet::<T>::activate_for_3rdparty_rewards(
+					token_id,
+					account_id,
+					amount,
+					use_balance_from,
+				)
+			}
+
+			fn deactivate_from_3rdparty_rewards(
+				token_id: TokenId,
+				account_id: &T::AccountId,
+				amount: Balance,
+			) -> Balance {
+				::pallet_multipurpose_liquidity::Pallet::<T>::deactivate_from_3rdparty_rewards(
+					token_id, account_id, amount,
+				)
+			}
+
+			fn transfer_activated_reserves(
+				token_id: TokenId,
+				from: &T::AccountId,
+				to: &T::AccountId,
+				amount: Balance,
+				amount_3rdparty: Balance,
+			) -> DispatchResult {
+				::pallet_multipurpose_liquidity::Pallet::<T>::transfer_activated_reserves(
+					token_id,
+					from,
+					to,
+					amount,
+					amount_3rdparty,
+				)
+			}
 		}
 	}
 }
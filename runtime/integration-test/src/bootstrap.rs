@@ -1,4 +1,4 @@
-use pallet_bootstrap::{BootstrapPhase, Phase};
+use pallet_bootstrap::{BootstrapPhase, Phase, DEFAULT_BOOTSTRAP_ID};
 
 use crate::setup::*;
 
@@ -52,7 +52,7 @@ fn bootstrap_updates_metadata_and_creates_pool_correctly() {
 		));
 
 		pallet_bootstrap::Pallet::<Runtime>::on_initialize(25_u32);
-		assert_eq!(BootstrapPhase::Public, Phase::<Runtime>::get());
+		assert_eq!(BootstrapPhase::Public, Phase::<Runtime>::get(DEFAULT_BOOTSTRAP_ID));
 
 		assert_ok!(pallet_bootstrap::Pallet::<Runtime>::provision(
 			RuntimeOrigin::signed(AccountId::from(ALICE)),
@@ -71,7 +71,7 @@ fn bootstrap_updates_metadata_and_creates_pool_correctly() {
 		);
 
 		pallet_bootstrap::Pallet::<Runtime>::on_initialize(40_u32);
-		assert_eq!(BootstrapPhase::Finished, Phase::<Runtime>::get());
+		assert_eq!(BootstrapPhase::Finished, Phase::<Runtime>::get(DEFAULT_BOOTSTRAP_ID));
 
 		assert_eq!(
 			pallet_xyk::LiquidityAssets::<Runtime>::get((NATIVE_ASSET_ID, ASSET_ID_1)),
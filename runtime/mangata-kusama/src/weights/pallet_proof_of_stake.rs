@@ -64,6 +64,8 @@ pub trait WeightInfo {
 	fn activate_liquidity_for_3rdparty_rewards() -> Weight;
 	fn deactivate_liquidity_for_3rdparty_rewards() -> Weight;
 	fn claim_3rdparty_rewards() -> Weight;
+	fn set_rewards_destination() -> Weight;
+	fn set_rewards_claimer() -> Weight;
 }
 
 /// Weights for pallet_proof_of_stake using the Mangata node and recommended hardware.
@@ -205,6 +207,18 @@ impl<T: frame_system::Config> pallet_proof_of_stake::WeightInfo for ModuleWeight
 			.saturating_add(T::DbWeight::get().reads(8 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
+	// Storage: `ProofOfStake::RewardsDestination` (r:0 w:1)
+	// Proof: `ProofOfStake::RewardsDestination` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_rewards_destination() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: `ProofOfStake::RewardsClaimer` (r:0 w:1)
+	// Proof: `ProofOfStake::RewardsClaimer` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_rewards_claimer() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -345,4 +359,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8 as u64))
 			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
+	// Storage: `ProofOfStake::RewardsDestination` (r:0 w:1)
+	// Proof: `ProofOfStake::RewardsDestination` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_rewards_destination() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: `ProofOfStake::RewardsClaimer` (r:0 w:1)
+	// Proof: `ProofOfStake::RewardsClaimer` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_rewards_claimer() -> Weight {
+		(Weight::from_parts(13_750_000, 0))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
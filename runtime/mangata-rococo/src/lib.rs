@@ -2,6 +2,9 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
 
+use bootstrap_runtime_api::{
+	RpcArchivedBootstrap, RpcBootstrapAccountInfo, RpcBootstrapPhase, RpcBootstrapState,
+};
 use codec::{
 	alloc::string::{String, ToString},
 	Decode, Encode,
@@ -9,6 +12,7 @@ use codec::{
 pub use common_runtime::{
 	consts::DAYS, currency::*, deposit, runtime_types, tokens, types::*, CallType,
 };
+use fee_lock_runtime_api::{RpcAccountFeeLockData, RpcFeeLockAction, RpcFeeLockPreview};
 use frame_support::{
 	construct_runtime,
 	dispatch::GetDispatchInfo,
@@ -21,6 +25,7 @@ pub use frame_system::Call as SystemCall;
 use frame_system::EnsureRoot;
 use mangata_support::traits::ProofOfStakeRewardsApi;
 pub use mangata_types::assets::{CustomMetadata, XcmMetadata, XykMetadata};
+use multipurpose_liquidity_runtime_api::{RpcRelockStatus, RpcReserveStatus};
 pub use orml_tokens;
 pub use pallet_issuance::IssuanceInfo;
 pub use pallet_sudo_mangata;
@@ -54,6 +59,10 @@ use sp_std::{
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use static_assertions::const_assert;
+use proof_of_stake_runtime_api::{
+	RpcClaimReceipt, RpcRewardAmount, RpcRewardsEstimation, RpcScheduleInfo, RpcScheduleRate,
+	RpcSessionRewards, RpcThirdPartyActivation, RpcUserActivations,
+};
 use xyk_runtime_api::RpcAssetMetadata;
 
 // Make the WASM binary available.
@@ -137,7 +146,15 @@ pub type Executive = frame_executive::Executive<
 	Migrations,
 >;
 
-type Migrations = ();
+type Migrations = (
+	pallet_proof_of_stake::migration::v1::MigrateToV1<Runtime>,
+	pallet_proof_of_stake::migration::v2::MigrateToV2<Runtime>,
+	pallet_fee_lock::migration::v1::MigrateToV1<Runtime>,
+	pallet_fee_lock::migration::v2::MigrateToV2<Runtime>,
+	pallet_fee_lock::migration::v3::MigrateToV3<Runtime>,
+	pallet_bootstrap::migration::v3::MigrateToV3<Runtime>,
+	pallet_multipurpose_liquidity::migration::v2::MigrateToV2<Runtime>,
+);
 
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
@@ -309,22 +326,30 @@ impl pallet_xyk::Config for Runtime {
 	type DisallowedPools = Bootstrap;
 	type DisabledTokens = cfg::pallet_xyk::AssetRegisterFilter<Runtime>;
 	type AssetMetadataMutation = cfg::pallet_xyk::AssetMetadataMutation<Runtime>;
+	type MaxValuationPathLength = cfg::pallet_xyk::MaxValuationPathLength;
+	type FeeLockWeight = cfg::pallet_fee_lock::FeeLockExemptionWeight<Runtime>;
 	type WeightInfo = weights::pallet_xyk_weights::ModuleWeight<Runtime>;
 }
 
 impl pallet_proof_of_stake::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ActivationReservesProvider = MultiPurposeLiquidity;
+	type ThirdPartyActivationReservesProvider =
+		cfg::pallet_proof_of_stake::MplThirdPartyActivationReserves<Runtime>;
 	type NativeCurrencyId = tokens::MgxTokenId;
 	type Currency = orml_tokens::MultiTokenCurrencyAdapter<Runtime>;
 	type LiquidityMiningIssuanceVault = cfg::pallet_issuance::LiquidityMiningIssuanceVault;
 	type RewardsDistributionPeriod = cfg::SessionLenghtOf<Runtime>;
 	type WeightInfo = weights::pallet_proof_of_stake_weights::ModuleWeight<Runtime>;
 	type RewardsSchedulesLimit = cfg::pallet_proof_of_stake::RewardsSchedulesLimit;
+	type MaxRewardTokensPerPool = cfg::pallet_proof_of_stake::MaxRewardTokensPerPool;
+	type MaxSchedulesChangesPerSession = cfg::pallet_proof_of_stake::MaxSchedulesChangesPerSession;
+	type MaxActivatedPoolsPerUser = cfg::pallet_proof_of_stake::MaxActivatedPoolsPerUser;
 	type Min3rdPartyRewardValutationPerSession =
 		cfg::pallet_proof_of_stake::Min3rdPartyRewardValutationPerSession;
 	type Min3rdPartyRewardVolume = cfg::pallet_proof_of_stake::Min3rdPartyRewardVolume;
-	type SchedulesPerBlock = cfg::pallet_proof_of_stake::SchedulesPerBlock;
+	type RewardsHistoryLength = cfg::pallet_proof_of_stake::RewardsHistoryLength;
+	type MaxClaimReceipts = cfg::pallet_proof_of_stake::MaxClaimReceipts;
 	type ValuationApi = Xyk;
 }
 
@@ -342,8 +367,17 @@ impl pallet_bootstrap::Config for Runtime {
 	type TreasuryPalletId = cfg::TreasuryPalletIdOf<Runtime>;
 	type RewardsApi = ProofOfStake;
 	type ClearStorageLimit = cfg::pallet_bootstrap::ClearStorageLimit;
+	type MaxWhitelistProofLength = cfg::pallet_bootstrap::MaxWhitelistProofLength;
+	type MaxConcurrentBootstraps = cfg::pallet_bootstrap::MaxConcurrentBootstraps;
 	type WeightInfo = weights::pallet_bootstrap_weights::ModuleWeight<Runtime>;
 	type AssetRegistryApi = cfg::pallet_bootstrap::EnableAssetPoolApi<Runtime>;
+	type VestedLiquidityReserveApi = cfg::pallet_bootstrap::ReserveVestedLiquidityApi<Runtime>;
+	type ExternalPriceSource = cfg::pallet_bootstrap::NativeValuationPriceSource<
+		Xyk,
+		tokens::MgxTokenId,
+		cfg::pallet_bootstrap::XykValuationWeight<Runtime>,
+	>;
+	type ThirdPartyRewardsApi = cfg::pallet_bootstrap::ProofOfStakeThirdPartyRewardsApi<Runtime>;
 }
 
 impl pallet_utility_mangata::Config for Runtime {
@@ -355,9 +389,62 @@ impl pallet_utility_mangata::Config for Runtime {
 }
 
 use cfg::pallet_transaction_payment_mangata::{
-	FeeHelpers, OnChargeHandler, ThreeCurrencyOnChargeAdapter, ToAuthor, TriggerEvent,
+	FeeHelpers, FeeLockPreview, OnChargeHandler, ThreeCurrencyOnChargeAdapter, ToAuthor,
+	TriggerEvent,
 };
 
+fn fee_lock_preview_into_rpc(
+	preview: FeeLockPreview<Balance, TokenId>,
+) -> RpcFeeLockPreview<Balance, TokenId> {
+	let (action, lock_token, lock_amount, total_locked, unlocked_amount) = match preview {
+		FeeLockPreview::FeeCharged => (RpcFeeLockAction::FeeCharged, None, 0, 0, 0),
+		FeeLockPreview::Lock { lock_token, lock_amount, total_locked } =>
+			(RpcFeeLockAction::Lock, Some(lock_token), lock_amount, total_locked, 0),
+		FeeLockPreview::Unlock { lock_token, unlocked_amount } =>
+			(RpcFeeLockAction::Unlock, Some(lock_token), 0, 0, unlocked_amount),
+		FeeLockPreview::Rejected => (RpcFeeLockAction::Rejected, None, 0, 0, 0),
+	};
+	RpcFeeLockPreview { action, lock_token, lock_amount, total_locked, unlocked_amount }
+}
+
+fn bootstrap_phase_into_rpc(phase: pallet_bootstrap::BootstrapPhase) -> RpcBootstrapPhase {
+	match phase {
+		pallet_bootstrap::BootstrapPhase::BeforeStart => RpcBootstrapPhase::BeforeStart,
+		pallet_bootstrap::BootstrapPhase::Whitelist => RpcBootstrapPhase::Whitelist,
+		pallet_bootstrap::BootstrapPhase::Public => RpcBootstrapPhase::Public,
+		pallet_bootstrap::BootstrapPhase::Finished => RpcBootstrapPhase::Finished,
+		pallet_bootstrap::BootstrapPhase::Failed => RpcBootstrapPhase::Failed,
+	}
+}
+
+fn reserve_status_into_rpc(
+	account: &AccountId,
+	token_id: TokenId,
+) -> RpcReserveStatus<Balance, TokenId, BlockNumber> {
+	use mangata_support::traits::ActivationReservesProviderTrait;
+	let status = MultiPurposeLiquidity::get_reserve_status(account, token_id);
+	let relocks = MultiPurposeLiquidity::get_relock_status(account, token_id)
+		.into_iter()
+		.map(|relock| RpcRelockStatus {
+			amount: relock.amount,
+			starting_block: relock.starting_block,
+			ending_block_as_balance: relock.ending_block_as_balance,
+		})
+		.collect();
+	RpcReserveStatus {
+		token_id,
+		staked_unactivated_reserves: status.staked_unactivated_reserves,
+		activated_unstaked_reserves: status.activated_unstaked_reserves,
+		staked_and_activated_reserves: status.staked_and_activated_reserves,
+		unspent_reserves: status.unspent_reserves,
+		relock_amount: status.relock_amount,
+		max_instant_unreserve_amount: MultiPurposeLiquidity::get_max_instant_unreserve_amount(
+			token_id, account,
+		),
+		relocks,
+	}
+}
+
 // TODO: renaming foo causes compiler error
 pub struct Foo<T>(PhantomData<T>);
 impl<T> TriggerEvent<T::AccountId> for Foo<T>
@@ -457,9 +544,15 @@ parameter_types! {
 impl pallet_fee_lock::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxCuratedTokens = cfg::pallet_fee_lock::MaxCuratedTokens;
+	type MaxFeeLockTiers = cfg::pallet_fee_lock::MaxFeeLockTiers;
 	type Tokens = orml_tokens::MultiTokenCurrencyAdapter<Runtime>;
-	type PoolReservesProvider = Xyk;
+	type PoolReservesProvider = cfg::pallet_fee_lock::XykValuationReserves<Runtime>;
 	type NativeTokenId = tokens::MgxTokenId;
+	type ExemptionProvider = (
+		cfg::pallet_fee_lock::ActivatedLiquidityExemption<Runtime>,
+		cfg::pallet_fee_lock::StakingBondExemption<Runtime>,
+	);
+	type MinUnlocksPerBlock = cfg::pallet_fee_lock::MinUnlocksPerBlock;
 	type WeightInfo = weights::pallet_fee_lock_weights::ModuleWeight<Runtime>;
 }
 
@@ -549,12 +642,6 @@ impl pallet_collective_mangata::Config<CouncilCollective> for Runtime {
 // Also since 1 block is used for session change, atleast 1 block more needed for extrinsics to work
 const_assert!(<Runtime as parachain_staking::Config>::BlocksPerRound::get() >= 2);
 
-const_assert!(
-	<Runtime as pallet_proof_of_stake::Config>::RewardsSchedulesLimit::get() >=
-		(<Runtime as pallet_proof_of_stake::Config>::SchedulesPerBlock::get() - 1) *
-			<Runtime as parachain_staking::Config>::BlocksPerRound::get()
-);
-
 impl parachain_staking::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type StakingReservesProvider = MultiPurposeLiquidity;
@@ -859,6 +946,161 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl fee_lock_runtime_api::FeeLockApi<Block, Balance, TokenId, AccountId, BlockNumber> for Runtime {
+		fn get_account_fee_lock_data(
+			user: AccountId,
+		) -> RpcAccountFeeLockData<Balance, TokenId, BlockNumber> {
+			let data = FeeLock::get_account_fee_lock_data(&user);
+			RpcAccountFeeLockData {
+				total_fee_lock_amount: data.total_fee_lock_amount,
+				lock_token: data.lock_token,
+				last_fee_lock_block: data.last_fee_lock_block,
+				swaps_in_period: FeeLock::get_account_swaps_in_period(&user),
+				unlockable_at: FeeLock::get_unlockable_at(&user),
+			}
+		}
+
+		fn get_unlockable_at(
+			user: AccountId,
+		) -> Option<BlockNumber> {
+			FeeLock::get_unlockable_at(&user)
+		}
+
+		fn preview_sell_asset(
+			user: AccountId,
+			path: Vec<TokenId>,
+			sold_asset_amount: Balance,
+			min_amount_out: Balance,
+		) -> RpcFeeLockPreview<Balance, TokenId> {
+			fee_lock_preview_into_rpc(
+				FeeHelpers::<
+					Runtime,
+					orml_tokens::MultiTokenCurrencyAdapter<Runtime>,
+					ToAuthor<Runtime>,
+					OnChargeTransactionHandler<Runtime>,
+					FeeLock,
+				>::preview_sell_asset(&user, path, sold_asset_amount, min_amount_out),
+			)
+		}
+
+		fn preview_buy_asset(
+			user: AccountId,
+			path: Vec<TokenId>,
+			bought_asset_amount: Balance,
+			max_amount_in: Balance,
+		) -> RpcFeeLockPreview<Balance, TokenId> {
+			fee_lock_preview_into_rpc(
+				FeeHelpers::<
+					Runtime,
+					orml_tokens::MultiTokenCurrencyAdapter<Runtime>,
+					ToAuthor<Runtime>,
+					OnChargeTransactionHandler<Runtime>,
+					FeeLock,
+				>::preview_buy_asset(&user, path, bought_asset_amount, max_amount_in),
+			)
+		}
+	}
+
+	impl bootstrap_runtime_api::BootstrapApi<Block, Balance, TokenId, AccountId, BlockNumber> for Runtime {
+		fn get_bootstrap_state(
+			bootstrap_id: u32,
+		) -> Option<RpcBootstrapState<Balance, TokenId, BlockNumber>> {
+			let schedule = Bootstrap::config(bootstrap_id)?;
+			let (first_token_id, second_token_id) = Bootstrap::pair(bootstrap_id)?;
+			let (whitelist_start, public_start, finish) = Bootstrap::phase_blocks(&schedule);
+			let (second_token_valuation, first_token_valuation) = Bootstrap::valuations(bootstrap_id);
+			Some(RpcBootstrapState {
+				phase: bootstrap_phase_into_rpc(Bootstrap::phase(bootstrap_id)),
+				first_token_id,
+				second_token_id,
+				whitelist_start,
+				public_start,
+				finish,
+				first_token_valuation,
+				second_token_valuation,
+				max_first_to_second_ratio: schedule.3,
+				price: Bootstrap::implied_price(bootstrap_id),
+				promote_bootstrap_pool: Bootstrap::get_promote_bootstrap_pool(bootstrap_id),
+				soft_cap: Bootstrap::soft_cap(bootstrap_id),
+				minted_liquidity: pallet_bootstrap::MintedLiquidity::<Runtime>::try_get(bootstrap_id).ok(),
+			})
+		}
+
+		fn get_account_info(
+			bootstrap_id: u32,
+			account: AccountId,
+		) -> RpcBootstrapAccountInfo<Balance> {
+			let (first_token_id, second_token_id) = Bootstrap::pair(bootstrap_id).unwrap_or_default();
+			let (claimable_liquidity, claimable_vested_liquidity) =
+				Bootstrap::get_claimable_liquidity(bootstrap_id, &account);
+			RpcBootstrapAccountInfo {
+				first_token_provision: Bootstrap::provisions((bootstrap_id, account.clone(), first_token_id)),
+				second_token_provision: Bootstrap::provisions((bootstrap_id, account.clone(), second_token_id)),
+				first_token_vested_provision: Bootstrap::vested_provisions((bootstrap_id, account.clone(), first_token_id)).0,
+				second_token_vested_provision: Bootstrap::vested_provisions((bootstrap_id, account.clone(), second_token_id)).0,
+				claimable_liquidity,
+				claimable_vested_liquidity,
+				claimed_liquidity: Bootstrap::get_claimed_liquidity(bootstrap_id, &account),
+				remaining_provision_allowance: Bootstrap::get_remaining_provision_allowance(bootstrap_id, &account),
+			}
+		}
+
+		fn get_archived_bootstraps() -> Vec<RpcArchivedBootstrap<Balance, BlockNumber>> {
+			Bootstrap::archived()
+				.iter()
+				.map(|schedule| {
+					let (whitelist_start, public_start, finish) = Bootstrap::phase_blocks(schedule);
+					RpcArchivedBootstrap {
+						whitelist_start,
+						public_start,
+						finish,
+						max_first_to_second_ratio: schedule.3,
+					}
+				})
+				.collect()
+		}
+
+		fn get_remaining_provision_allowance(
+			bootstrap_id: u32,
+			account: AccountId,
+		) -> Option<Balance> {
+			Bootstrap::get_remaining_provision_allowance(bootstrap_id, &account)
+		}
+	}
+
+	impl multipurpose_liquidity_runtime_api::MultipurposeLiquidityApi<Block, Balance, TokenId, AccountId, BlockNumber> for Runtime {
+		fn get_reserve_status(
+			account: AccountId,
+			token_id: TokenId,
+		) -> RpcReserveStatus<Balance, TokenId, BlockNumber> {
+			reserve_status_into_rpc(&account, token_id)
+		}
+
+		fn get_account_reserve_statuses(
+			account: AccountId,
+		) -> Vec<RpcReserveStatus<Balance, TokenId, BlockNumber>> {
+			let mut token_ids: Vec<TokenId> =
+				pallet_multipurpose_liquidity::ReserveStatus::<Runtime>::iter_key_prefix(&account)
+					.chain(pallet_multipurpose_liquidity::RelockStatus::<Runtime>::iter_key_prefix(&account))
+					.collect();
+			token_ids.sort();
+			token_ids.dedup();
+			token_ids
+				.into_iter()
+				.map(|token_id| reserve_status_into_rpc(&account, token_id))
+				.collect()
+		}
+
+		fn get_relock_unlocked_amount_at(
+			account: AccountId,
+			token_id: TokenId,
+			block: BlockNumber,
+		) -> Balance {
+			MultiPurposeLiquidity::get_relock_unlocked_amount_at(&account, token_id, block)
+		}
+	}
+
+	#[api_version(2)]
 	impl proof_of_stake_runtime_api::ProofOfStakeApi<Block, Balance , TokenId,  AccountId> for Runtime{
 		fn calculate_native_rewards_amount(
 			user: AccountId,
@@ -882,6 +1124,107 @@ impl_runtime_apis! {
 		) -> Vec<(TokenId, TokenId, Balance)>{
 			pallet_proof_of_stake::Pallet::<Runtime>::calculate_3rdparty_rewards_all(user)
 		}
+
+		fn get_rewards_schedules(
+			liquidity_asset_id: TokenId,
+		) -> Vec<RpcScheduleInfo<Balance, TokenId>>{
+			pallet_proof_of_stake::Pallet::<Runtime>::get_rewards_schedules(liquidity_asset_id)
+				.into_iter()
+				.map(|(reward_token, amount_per_session, remaining_sessions)| RpcScheduleInfo {
+					reward_token,
+					amount_per_session,
+					remaining_sessions,
+				})
+				.collect()
+		}
+
+		fn get_user_activations(
+			user: AccountId,
+		) -> Vec<RpcUserActivations<Balance, TokenId>>{
+			pallet_proof_of_stake::Pallet::<Runtime>::get_user_activations(user)
+				.into_iter()
+				.map(|activations| RpcUserActivations {
+					liquidity_token: activations.liquidity_token,
+					native_activated_amount: activations.native_activated,
+					native_reactivated_amount: activations.native_reactivated,
+					native_claimable_amount: activations.native_claimable,
+					third_party: activations
+						.third_party
+						.into_iter()
+						.map(|(reward_token, activated_amount, claimable_amount)| RpcThirdPartyActivation {
+							reward_token,
+							activated_amount,
+							claimable_amount,
+						})
+						.collect(),
+				})
+				.collect()
+		}
+
+		fn estimate_rewards(
+			liquidity_asset_id: TokenId,
+			amount: Balance,
+		) -> RpcRewardsEstimation<Balance, TokenId>{
+			let sessions_per_year = 365 * common_runtime::consts::DAYS /
+				cfg::SessionLenghtOf::<Runtime>::get();
+			let estimation = pallet_proof_of_stake::Pallet::<Runtime>::estimate_rewards(
+				liquidity_asset_id,
+				amount,
+				sessions_per_year,
+			);
+			RpcRewardsEstimation {
+				native_rewards_per_session: estimation.native_rewards_per_session,
+				third_party_rewards_per_session: estimation
+					.third_party_rewards_per_session
+					.into_iter()
+					.map(|(reward_token, amount_per_session, valuation_per_session)| RpcScheduleRate {
+						reward_token,
+						amount_per_session,
+						valuation_per_session,
+					})
+					.collect(),
+				activation_valuation: estimation.activation_valuation,
+				estimated_yearly_rewards: estimation.estimated_yearly_rewards,
+				estimated_apr_bps: estimation.estimated_apr_bps,
+			}
+		}
+
+		fn get_rewards_history(
+			liquidity_asset_id: TokenId,
+			from_session: u32,
+			to_session: u32,
+		) -> Vec<RpcSessionRewards<Balance, TokenId>>{
+			pallet_proof_of_stake::Pallet::<Runtime>::get_rewards_history(
+				liquidity_asset_id,
+				from_session,
+				to_session,
+			)
+			.into_iter()
+			.map(|rewards| RpcSessionRewards {
+				session: rewards.session,
+				native_rewards: rewards.native_rewards,
+				third_party_rewards: rewards
+					.third_party_rewards
+					.into_iter()
+					.map(|(reward_token, amount)| RpcRewardAmount { reward_token, amount })
+					.collect(),
+			})
+			.collect()
+		}
+
+		fn get_claim_receipts(
+			user: AccountId,
+		) -> Vec<RpcClaimReceipt<Balance, TokenId>>{
+			pallet_proof_of_stake::Pallet::<Runtime>::get_claim_receipts(user)
+				.into_iter()
+				.map(|receipt| RpcClaimReceipt {
+					session: receipt.session,
+					liquidity_token: receipt.liquidity_token,
+					reward_token: receipt.reward_token,
+					amount: receipt.amount,
+				})
+				.collect()
+		}
 	}
 
 
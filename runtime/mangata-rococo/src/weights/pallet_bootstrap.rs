@@ -58,6 +58,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn schedule_bootstrap() -> Weight;
 	fn provision() -> Weight;
+	fn provision_with_proof(p: u32, ) -> Weight;
 	fn claim_and_activate_liquidity_tokens() -> Weight;
 	fn finalize() -> Weight;
 }
@@ -82,6 +83,7 @@ impl<T: frame_system::Config> pallet_bootstrap::WeightInfo for ModuleWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
 	// Storage: `Maintenance::MaintenanceStatus` (r:1 w:0)
 	// Proof: `Maintenance::MaintenanceStatus` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	// Storage: `Bootstrap::ActivePair` (r:1 w:0)
@@ -100,12 +102,61 @@ impl<T: frame_system::Config> pallet_bootstrap::WeightInfo for ModuleWeight<T> {
 	// Proof: `Bootstrap::Provisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Bootstrap::Valuations` (r:1 w:1)
 	// Proof: `Bootstrap::Valuations` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Auction` (r:1 w:0)
+	// Proof: `Bootstrap::Auction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ValuationSource` (r:1 w:0)
+	// Proof: `Bootstrap::ValuationSource` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionLimit` (r:1 w:0)
+	// Proof: `Bootstrap::ProvisionLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::AccountProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::AccountProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::PhaseProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::PhaseProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Bootstrap::ProvisionAccounts` (r:0 w:1)
 	// Proof: `Bootstrap::ProvisionAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn provision() -> Weight {
-		(Weight::from_parts(95_910_000, 0))
-			.saturating_add(T::DbWeight::get().reads(10 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+		(Weight::from_parts(104_410_000, 0))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Estimate, not generated by the benchmark CLI yet: cost of `provision` with the
+	// whitelist root read and hashing of `p` proof nodes on top of it
+	// Storage: `Maintenance::MaintenanceStatus` (r:1 w:0)
+	// Proof: `Maintenance::MaintenanceStatus` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	// Storage: `Bootstrap::WhitelistRoot` (r:1 w:0)
+	// Proof: `Bootstrap::WhitelistRoot` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ActivePair` (r:1 w:0)
+	// Proof: `Bootstrap::ActivePair` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Phase` (r:1 w:0)
+	// Proof: `Bootstrap::Phase` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::BootstrapSchedule` (r:1 w:0)
+	// Proof: `Bootstrap::BootstrapSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Bootstrap::Provisions` (r:1 w:1)
+	// Proof: `Bootstrap::Provisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Valuations` (r:1 w:1)
+	// Proof: `Bootstrap::Valuations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Auction` (r:1 w:0)
+	// Proof: `Bootstrap::Auction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ValuationSource` (r:1 w:0)
+	// Proof: `Bootstrap::ValuationSource` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionLimit` (r:1 w:0)
+	// Proof: `Bootstrap::ProvisionLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::AccountProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::AccountProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::PhaseProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::PhaseProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionAccounts` (r:0 w:1)
+	// Proof: `Bootstrap::ProvisionAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 32]`.
+	fn provision_with_proof(p: u32, ) -> Weight {
+		(Weight::from_parts(105_764_000, 0))
+			.saturating_add((Weight::from_parts(1_482_611, 0)).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: `Bootstrap::Phase` (r:1 w:0)
 	// Proof: `Bootstrap::Phase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -192,6 +243,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
 	// Storage: `Maintenance::MaintenanceStatus` (r:1 w:0)
 	// Proof: `Maintenance::MaintenanceStatus` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	// Storage: `Bootstrap::ActivePair` (r:1 w:0)
@@ -210,12 +262,61 @@ impl WeightInfo for () {
 	// Proof: `Bootstrap::Provisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Bootstrap::Valuations` (r:1 w:1)
 	// Proof: `Bootstrap::Valuations` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Auction` (r:1 w:0)
+	// Proof: `Bootstrap::Auction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ValuationSource` (r:1 w:0)
+	// Proof: `Bootstrap::ValuationSource` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionLimit` (r:1 w:0)
+	// Proof: `Bootstrap::ProvisionLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::AccountProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::AccountProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::PhaseProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::PhaseProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Bootstrap::ProvisionAccounts` (r:0 w:1)
 	// Proof: `Bootstrap::ProvisionAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn provision() -> Weight {
-		(Weight::from_parts(95_910_000, 0))
-			.saturating_add(RocksDbWeight::get().reads(10 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+		(Weight::from_parts(104_410_000, 0))
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Estimate, not generated by the benchmark CLI yet: cost of `provision` with the
+	// whitelist root read and hashing of `p` proof nodes on top of it
+	// Storage: `Maintenance::MaintenanceStatus` (r:1 w:0)
+	// Proof: `Maintenance::MaintenanceStatus` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	// Storage: `Bootstrap::WhitelistRoot` (r:1 w:0)
+	// Proof: `Bootstrap::WhitelistRoot` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ActivePair` (r:1 w:0)
+	// Proof: `Bootstrap::ActivePair` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Phase` (r:1 w:0)
+	// Proof: `Bootstrap::Phase` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::BootstrapSchedule` (r:1 w:0)
+	// Proof: `Bootstrap::BootstrapSchedule` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	// Storage: `Bootstrap::Provisions` (r:1 w:1)
+	// Proof: `Bootstrap::Provisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Valuations` (r:1 w:1)
+	// Proof: `Bootstrap::Valuations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::Auction` (r:1 w:0)
+	// Proof: `Bootstrap::Auction` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ValuationSource` (r:1 w:0)
+	// Proof: `Bootstrap::ValuationSource` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionLimit` (r:1 w:0)
+	// Proof: `Bootstrap::ProvisionLimit` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::AccountProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::AccountProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::PhaseProvisionValuation` (r:1 w:1)
+	// Proof: `Bootstrap::PhaseProvisionValuation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Bootstrap::ProvisionAccounts` (r:0 w:1)
+	// Proof: `Bootstrap::ProvisionAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[0, 32]`.
+	fn provision_with_proof(p: u32, ) -> Weight {
+		(Weight::from_parts(105_764_000, 0))
+			.saturating_add((Weight::from_parts(1_482_611, 0)).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	// Storage: `Bootstrap::Phase` (r:1 w:0)
 	// Proof: `Bootstrap::Phase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
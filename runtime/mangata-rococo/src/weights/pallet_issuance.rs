@@ -59,6 +59,7 @@ pub trait WeightInfo {
 	fn init_issuance_config() -> Weight;
 	fn finalize_tge() -> Weight;
 	fn execute_tge(x: u32, ) -> Weight;
+	fn update_issuance_config() -> Weight;
 }
 
 /// Weights for pallet_issuance using the Mangata node and recommended hardware.
@@ -105,6 +106,16 @@ impl<T: frame_system::Config> pallet_issuance::WeightInfo for ModuleWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(x as u64)))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	// Storage: `Issuance::IssuanceConfigStore` (r:1 w:0)
+	// Proof: `Issuance::IssuanceConfigStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Issuance::PendingIssuanceConfigUpdate` (r:0 w:1)
+	// Proof: `Issuance::PendingIssuanceConfigUpdate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_issuance_config() -> Weight {
+		(Weight::from_parts(16_240_000, 0))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -150,4 +161,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(x as u64)))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	// Storage: `Issuance::IssuanceConfigStore` (r:1 w:0)
+	// Proof: `Issuance::IssuanceConfigStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `Issuance::PendingIssuanceConfigUpdate` (r:0 w:1)
+	// Proof: `Issuance::PendingIssuanceConfigUpdate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_issuance_config() -> Weight {
+		(Weight::from_parts(16_240_000, 0))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
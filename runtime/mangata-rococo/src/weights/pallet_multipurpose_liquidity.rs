@@ -58,6 +58,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn reserve_vesting_liquidity_tokens() -> Weight;
 	fn unreserve_and_relock_instance() -> Weight;
+	fn release_expired_relocks(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_multipurpose_liquidity using the Mangata node and recommended hardware.
@@ -95,6 +96,22 @@ impl<T: frame_system::Config> pallet_multipurpose_liquidity::WeightInfo for Modu
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	// Storage: `MultiPurposeLiquidity::RelockStatus` (r:1 w:1)
+	// Proof: `MultiPurposeLiquidity::RelockStatus` (`max_values`: None, `max_size`: Some(1845), added: 4320, mode: `MaxEncodedLen`)
+	// Storage: `MultiPurposeLiquidity::ReserveStatus` (r:1 w:1)
+	// Proof: `MultiPurposeLiquidity::ReserveStatus` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `MultiPurposeLiquidity::RelockedAccounts` (r:0 w:1)
+	// Proof: `MultiPurposeLiquidity::RelockedAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn release_expired_relocks(x: u32, ) -> Weight {
+		(Weight::from_parts(41_230_000, 12_029))
+			.saturating_add((Weight::from_parts(1_310_000, 0)).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -131,4 +148,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(5 as u64))
 	}
+	// Estimate, not generated by the benchmark CLI yet
+	// Storage: `MultiPurposeLiquidity::RelockStatus` (r:1 w:1)
+	// Proof: `MultiPurposeLiquidity::RelockStatus` (`max_values`: None, `max_size`: Some(1845), added: 4320, mode: `MaxEncodedLen`)
+	// Storage: `MultiPurposeLiquidity::ReserveStatus` (r:1 w:1)
+	// Proof: `MultiPurposeLiquidity::ReserveStatus` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	// Storage: `MultiPurposeLiquidity::RelockedAccounts` (r:0 w:1)
+	// Proof: `MultiPurposeLiquidity::RelockedAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn release_expired_relocks(x: u32, ) -> Weight {
+		(Weight::from_parts(41_230_000, 12_029))
+			.saturating_add((Weight::from_parts(1_310_000, 0)).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
 }